@@ -4,6 +4,7 @@
 
 use super::{CodeType, Config, potentially_add_external_package};
 use crate::ComponentInterface;
+use uniffi_bindgen::interface::{CallbackInterface, Method};
 
 #[derive(Debug)]
 pub struct CallbackInterfaceCodeType {
@@ -22,7 +23,7 @@ impl CodeType for CallbackInterfaceCodeType {
             config,
             ci,
             &self.id,
-            super::JavaCodeOracle.class_name(ci, &self.id),
+            super::JavaCodeOracle.type_class_name(ci, config, &self.id),
         )
     }
 
@@ -46,3 +47,27 @@ impl CodeType for CallbackInterfaceCodeType {
         )
     }
 }
+
+/// The method a `flow_publishers` adapter of `cbi` publishes the argument of, if it's listed.
+///
+/// Only callback interfaces shaped like an `on_next` can be adapted: a single synchronous method
+/// taking one argument and returning nothing.
+pub(super) fn flow_publisher_method<'a>(
+    cbi: &'a CallbackInterface,
+    config: &Config,
+) -> Result<Option<&'a Method>, String> {
+    if !config.flow_publishers.iter().any(|name| name == cbi.name()) {
+        return Ok(None);
+    }
+    match cbi.methods().as_slice() {
+        [meth]
+            if meth.arguments().len() == 1 && meth.return_type().is_none() && !meth.is_async() =>
+        {
+            Ok(Some(*meth))
+        }
+        _ => Err(format!(
+            "flow_publishers: {} needs a single method taking one argument and returning nothing",
+            cbi.name()
+        )),
+    }
+}
@@ -23,11 +23,21 @@ impl OptionalCodeType {
 }
 
 impl CodeType for OptionalCodeType {
+    // Optionals are nullable, except for nested ones, which are a nullable `java.util.Optional`
+    // so `None` and `Some(None)` can be told apart, and with `primitive_optionals` the ones of
+    // primitives a `java.util.OptionalInt` and the like. See `OptionalTemplate.java`.
     fn type_label(&self, ci: &ComponentInterface, config: &Config) -> String {
-        super::JavaCodeOracle
+        if let Some((class, _)) = super::primitive_optional(config, self.inner()) {
+            return class.to_string();
+        }
+        let inner_label = super::JavaCodeOracle
             .find(self.inner())
-            .type_label(ci, config)
-            .to_string()
+            .type_label(ci, config);
+        if matches!(self.inner(), Type::Optional { .. }) {
+            format!("java.util.Optional<{inner_label}>")
+        } else {
+            inner_label
+        }
     }
 
     fn canonical_name(&self) -> String {
@@ -38,12 +48,28 @@ impl CodeType for OptionalCodeType {
     }
 
     fn literal(&self, literal: &Literal, ci: &ComponentInterface, config: &Config) -> String {
-        match literal {
-            Literal::None => "null".into(),
+        let primitive_optional = super::primitive_optional(config, self.inner());
+        let inner_literal = match literal {
+            Literal::None => {
+                return match primitive_optional {
+                    Some((class, _)) => format!("{class}.empty()"),
+                    None => "null".into(),
+                };
+            }
             Literal::Some { inner } => super::JavaCodeOracle
                 .find(&self.inner)
                 .literal(inner, ci, config),
-            _ => panic!("Invalid literal for Optional type: {literal:?}"),
+            // Defaults of optional arguments can be a value of the inner type.
+            _ => super::JavaCodeOracle
+                .find(&self.inner)
+                .literal(literal, ci, config),
+        };
+        if let Some((class, _)) = primitive_optional {
+            format!("{class}.of({inner_literal})")
+        } else if matches!(self.inner(), Type::Optional { .. }) {
+            format!("java.util.Optional.ofNullable({inner_literal})")
+        } else {
+            inner_literal
         }
     }
 }
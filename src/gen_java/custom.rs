@@ -22,7 +22,7 @@ impl CodeType for CustomCodeType {
             config,
             ci,
             &self.name,
-            super::JavaCodeOracle.class_name(ci, &self.name),
+            super::JavaCodeOracle.type_class_name(ci, config, &self.name),
         )
     }
 
@@ -31,6 +31,16 @@ impl CodeType for CustomCodeType {
     }
 
     fn ffi_converter_instance(&self, config: &Config, ci: &ComponentInterface) -> String {
+        // A configured `converter_class` is referenced fully-qualified, so it doesn't need an
+        // import everywhere the type is lifted or lowered. It's validated in `generate_bindings`.
+        if let Some(converter_class) = config
+            .custom_types
+            .get(&self.name)
+            .and_then(|custom_type_config| custom_type_config.converter_class(&self.name).ok())
+            .flatten()
+        {
+            return format!("{converter_class}.INSTANCE");
+        }
         potentially_add_external_package(
             config,
             ci,
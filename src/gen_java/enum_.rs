@@ -2,9 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::{CodeType, Config, potentially_add_external_package};
+use heck::ToUpperCamelCase;
+
+use super::{CodeType, Config, JavaCodeOracle, potentially_add_external_package};
 use crate::ComponentInterface;
-use uniffi_bindgen::backend::Literal;
+use uniffi_bindgen::{backend::Literal, interface::Enum};
 
 #[derive(Debug)]
 pub struct EnumCodeType {
@@ -23,7 +25,7 @@ impl CodeType for EnumCodeType {
             config,
             ci,
             &self.id,
-            super::JavaCodeOracle.class_name(ci, &self.id),
+            JavaCodeOracle.type_class_name(ci, config, &self.id),
         )
     }
 
@@ -36,7 +38,7 @@ impl CodeType for EnumCodeType {
             format!(
                 "{}.{}",
                 self.type_label(ci, config),
-                super::JavaCodeOracle.enum_variant_name(v)
+                JavaCodeOracle.enum_variant_name(v)
             )
         } else {
             unreachable!();
@@ -52,3 +54,37 @@ impl CodeType for EnumCodeType {
         )
     }
 }
+
+/// The Java names of an enum's variants in declaration order: the constants of a flat enum, or the
+/// record classes of an enum with fields.
+pub(super) fn variant_names(e: &Enum, ci: &ComponentInterface) -> Vec<String> {
+    e.variants()
+        .iter()
+        .map(|v| {
+            if e.is_flat() {
+                JavaCodeOracle.enum_variant_name(v.name())
+            } else {
+                JavaCodeOracle.class_name(ci, v.name())
+            }
+        })
+        .collect()
+}
+
+/// The constants of a flat enum and the `Visitor` method each of them calls, generated with
+/// `enum_visitor`. `UNKNOWN` gets one too with `enum_forward_compat`.
+pub(super) fn visitor_methods(e: &Enum, config: &Config) -> Vec<(String, String)> {
+    let mut methods = e
+        .variants()
+        .iter()
+        .map(|v| {
+            (
+                JavaCodeOracle.enum_variant_name(v.name()),
+                format!("visit{}", v.name().to_upper_camel_case()),
+            )
+        })
+        .collect::<Vec<_>>();
+    if config.enum_forward_compat {
+        methods.push(("UNKNOWN".to_string(), "visitUnknown".to_string()));
+    }
+    methods
+}
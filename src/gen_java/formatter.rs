@@ -0,0 +1,112 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// Normalize a single generated Java file so it's closer to what google-java-format produces.
+///
+/// This isn't a full formatter. It only takes care of the things that cause the most churn when
+/// the generated code is checked by google-java-format in CI:
+///   - the `package` line is followed by a single sorted, de-duplicated import block, with static
+///     imports first
+///   - trailing whitespace is removed
+///   - runs of blank lines are collapsed, and blank lines directly inside braces are removed
+///   - the file ends with exactly one newline
+pub fn normalize_java_source(source: &str) -> String {
+    let mut package_line = None;
+    let mut imports = Vec::new();
+    let mut body: Vec<&str> = Vec::new();
+
+    for line in source.lines().map(str::trim_end) {
+        let trimmed = line.trim_start();
+        if body.is_empty() {
+            if trimmed.starts_with("package ") && package_line.is_none() {
+                package_line = Some(trimmed);
+                continue;
+            }
+            if trimmed.starts_with("import ") {
+                imports.push(trimmed);
+                continue;
+            }
+            if trimmed.is_empty() {
+                continue;
+            }
+        }
+        body.push(line);
+    }
+
+    imports.sort_by_key(|import| (!import.starts_with("import static "), *import));
+    imports.dedup();
+
+    let mut out = String::with_capacity(source.len());
+    if let Some(package_line) = package_line {
+        out.push_str(package_line);
+        out.push_str("\n\n");
+    }
+    if !imports.is_empty() {
+        for import in imports {
+            out.push_str(import);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    let mut previous: Option<&str> = None;
+    let mut pending_blank = false;
+    for line in body {
+        if line.is_empty() {
+            // Only emit a blank line once we know what follows it.
+            pending_blank = previous.is_some_and(|p| !p.ends_with('{'));
+            continue;
+        }
+        if pending_blank && !line.trim_start().starts_with('}') {
+            out.push('\n');
+        }
+        pending_blank = false;
+        out.push_str(line);
+        out.push('\n');
+        previous = Some(line);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_java_source;
+
+    #[test]
+    fn imports_are_sorted_and_deduped_with_static_imports_first() {
+        let source = "package foo;\nimport java.util.Map;\n\nimport static java.util.Objects.requireNonNull;\nimport java.util.List;\nimport java.util.Map;\n\npublic class Foo {}\n";
+        assert_eq!(
+            normalize_java_source(source),
+            "package foo;\n\nimport static java.util.Objects.requireNonNull;\nimport java.util.List;\nimport java.util.Map;\n\npublic class Foo {}\n"
+        );
+    }
+
+    #[test]
+    fn blank_lines_inside_braces_are_removed() {
+        let source = "class Foo {\n\n  void foo() {\n\n    bar();\n\n\n    baz();\n\n  }\n\n}\n";
+        assert_eq!(
+            normalize_java_source(source),
+            "class Foo {\n  void foo() {\n    bar();\n\n    baz();\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn trailing_whitespace_and_newlines_are_removed() {
+        let source = "class Foo {  \n  int foo;\t\n}\n\n\n";
+        assert_eq!(
+            normalize_java_source(source),
+            "class Foo {\n  int foo;\n}\n"
+        );
+        assert_eq!(normalize_java_source("class Foo {}"), "class Foo {}\n");
+    }
+
+    #[test]
+    fn files_without_package_or_imports_start_with_the_body() {
+        assert_eq!(
+            normalize_java_source("\n\npublic class Foo {}\n"),
+            "public class Foo {}\n"
+        );
+    }
+}
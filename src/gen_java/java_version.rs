@@ -0,0 +1,57 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// The language features generated code can use, with the Java version each is final in and a
+/// pattern finding it in the source.
+static FEATURES: Lazy<Vec<(&str, u32, Regex)>> = Lazy::new(|| {
+    [
+        ("local-variable-type-inference", 10, r"\bvar \w+ ="),
+        ("switch-expressions", 14, r"(?:=|\breturn) switch ?\("),
+        ("records", 16, r"(?m)^\s*(?:public )?(?:static )?record \w+"),
+        (
+            "instanceof-patterns",
+            16,
+            r"\binstanceof [\w.]+(?:<[\w, ]+>)? \w+\)",
+        ),
+        ("stream-to-list", 16, r"\.toList\(\)"),
+        ("sealed-classes", 17, r"\bsealed (?:interface|class)\b"),
+        ("switch-patterns", 21, r"\bcase [\w.]+(?:\(.*\)| \w+) ->"),
+    ]
+    .into_iter()
+    .map(|(name, version, pattern)| (name, version, Regex::new(pattern).unwrap()))
+    .collect()
+});
+
+/// The Java version generated code needs when it uses none of the `FEATURES`, for its lambdas.
+pub const BASELINE_JAVA_VERSION: u32 = 8;
+
+/// The language features `source` uses and the Java version each of them needs, oldest first.
+pub fn java_version_features(source: &str) -> Vec<(&'static str, u32)> {
+    FEATURES
+        .iter()
+        .filter(|(_, _, pattern)| pattern.is_match(source))
+        .map(|(name, version, _)| (*name, *version))
+        .collect()
+}
+
+/// The minimum Java version `source` needs, given its `java_version_features`.
+pub fn minimum_java_version(features: &[(&str, u32)]) -> u32 {
+    features
+        .iter()
+        .map(|(_, version)| *version)
+        .max()
+        .unwrap_or(BASELINE_JAVA_VERSION)
+}
+
+/// A `.properties` report of the minimum Java version and the features it comes from.
+pub fn java_version_report(features: &[(&str, u32)]) -> String {
+    let mut report = format!("minimum-java-version={}\n", minimum_java_version(features));
+    for (name, version) in features {
+        report.push_str(&format!("feature.{name}={version}\n"));
+    }
+    report
+}
@@ -15,12 +15,19 @@ mod callback_interface;
 mod compounds;
 mod custom;
 mod enum_;
+mod formatter;
+mod java_version;
 mod miscellany;
 mod object;
 mod primitives;
 mod record;
+mod scala;
 mod variant;
 
+pub use formatter::normalize_java_source;
+pub use java_version::{java_version_features, java_version_report, minimum_java_version};
+pub use scala::generate_scala_interop;
+
 pub fn potentially_add_external_package(
     config: &Config,
     ci: &ComponentInterface,
@@ -166,13 +173,168 @@ pub struct Config {
     #[serde(default)]
     android_cleaner: Option<bool>,
     #[serde(default)]
-    quarkus: bool
+    quarkus: bool,
+    #[serde(default)]
+    pub(super) format_output: bool,
+    #[serde(default)]
+    sequence_varargs: bool,
+    ffi_name_prefix: Option<String>,
+    runtime_package: Option<String>,
+    #[serde(default)]
+    implements: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    equals_ignore_fields: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    object_identity_equals: bool,
+    #[serde(default)]
+    concurrent_maps: bool,
+    #[serde(default)]
+    expose_raw_pointers: bool,
+    tostring_template: Option<String>,
+    #[serde(default)]
+    singleton_empty_records: bool,
+    #[serde(default)]
+    positional_accessors: bool,
+    #[serde(default)]
+    enum_forward_compat: bool,
+    #[serde(default)]
+    jna_options: JnaOptions,
+    #[serde(default)]
+    safe_buffer_reads: bool,
+    #[serde(default)]
+    emit_blocking_async: bool,
+    internal_prefix: Option<String>,
+    #[serde(default)]
+    contract_annotations: bool,
+    #[serde(default)]
+    record_comparators: bool,
+    #[serde(default)]
+    comparator_nulls: NullsOrder,
+    #[serde(default)]
+    results_as_values: bool,
+    #[serde(default)]
+    shutdown_hook: bool,
+    #[serde(default)]
+    record_conversions: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    naming_style: NamingStyle,
+    #[serde(default)]
+    quarkus_producers: bool,
+    #[serde(default)]
+    deep_equals: bool,
+    #[serde(default)]
+    deprecated: HashMap<String, Deprecation>,
+    panic_exception: Option<String>,
+    #[serde(default)]
+    pub(super) emit_osgi_manifest: bool,
+    #[serde(default)]
+    unmodifiable_collections: bool,
+    #[serde(default)]
+    listeners: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    record_hash: RecordHash,
+    #[serde(default)]
+    suppress_warnings: Vec<String>,
+    #[serde(default)]
+    on_unsupported: OnUnsupported,
+    pub(super) java_version: Option<u32>,
+    #[serde(default)]
+    pub(super) emit_java_version_report: bool,
+    #[serde(default)]
+    record_copy: bool,
+    #[serde(default)]
+    android_parcelable: bool,
+    #[serde(default)]
+    function_renames: HashMap<String, String>,
+    #[serde(default)]
+    type_renames: HashMap<String, String>,
+    #[serde(default)]
+    flow_publishers: Vec<String>,
+    #[serde(default)]
+    string_params_as_charsequence: bool,
+    bytes_type: Option<CustomTypeConfig>,
+    #[serde(default)]
+    enum_visitor: bool,
+    #[serde(default)]
+    pub(super) emit_value_tests: bool,
+    #[serde(default)]
+    emit_constants: bool,
+    #[serde(default)]
+    newtype_optimization: bool,
+    #[serde(default)]
+    api_since: HashMap<String, String>,
+    namespace_class: Option<String>,
+    #[serde(default)]
+    enforce_thread_affinity: Vec<String>,
+    include_items: Option<Vec<String>>,
+    #[serde(default)]
+    exclude_items: Vec<String>,
+    #[serde(default)]
+    comparable: HashMap<String, Vec<String>>,
+    constructor_properties: Option<PropertyNames>,
+    buffer_growth_factor: Option<f64>,
+    #[serde(default)]
+    pub(super) scala_interop: bool,
+    #[serde(default)]
+    callback_exception_mapping: Vec<CallbackExceptionMapping>,
+    #[serde(default)]
+    on_callback_exception: OnCallbackException,
+    #[serde(default)]
+    record_builders: bool,
+    #[serde(default)]
+    mapstruct: bool,
+    #[serde(default)]
+    enum_with_tag: bool,
+    initialization_entry_point: Option<String>,
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    instrumentable_ffi: bool,
+    #[serde(default)]
+    primitive_optionals: bool,
+    #[serde(default)]
+    record_map_conversion: bool,
+    string_charset: Option<String>,
 }
 
 impl Config {
     pub(crate) fn android_cleaner(&self) -> bool {
         self.android_cleaner.unwrap_or(self.android)
     }
+
+    /// The template for `toString()` of records and enum variants, see `tostring_expr`.
+    pub fn tostring_template(&self) -> &str {
+        self.tostring_template
+            .as_deref()
+            .unwrap_or("{typeName}[{fields}]")
+    }
+
+    /// The `@SuppressWarnings` annotation of generated types, `None` unless `suppress_warnings`
+    /// lists some.
+    pub fn suppress_warnings_annotation(&self) -> Option<String> {
+        let warnings = self
+            .suppress_warnings
+            .iter()
+            .map(|w| java_string_literal(w))
+            .collect::<Vec<_>>();
+        (!warnings.is_empty()).then(|| format!("@SuppressWarnings({{{}}})", warnings.join(", ")))
+    }
+
+    /// Whether mutable record classes get a deep `copy()`, records are immutable otherwise.
+    pub fn generates_record_copy(&self) -> bool {
+        self.record_copy && !self.generate_immutable_records()
+    }
+
+    /// Whether records hash with `UniffiStableHash` rather than `Objects.hash`.
+    pub fn stable_record_hash(&self) -> bool {
+        matches!(self.record_hash, RecordHash::Fnv)
+    }
+
+    /// Whether a `tostring_template` is configured. Java records only need their own `toString()`
+    /// then, the one the compiler generates already uses the default format.
+    pub fn has_tostring_template(&self) -> bool {
+        self.tostring_template.is_some()
+    }
 }
 
 impl Config {
@@ -192,6 +354,186 @@ impl Config {
         }
     }
 
+    /// The prefix for the FFI callback and struct names, `Uniffi` by default.
+    pub fn ffi_name_prefix(&self) -> String {
+        self.ffi_name_prefix
+            .clone()
+            .unwrap_or_else(|| "Uniffi".to_string())
+    }
+
+    /// The exception thrown for Rust panics, `InternalException` by default.
+    pub fn panic_exception(&self) -> &str {
+        self.panic_exception
+            .as_deref()
+            .unwrap_or("InternalException")
+    }
+
+    /// The `panic_exception` to generate as a subclass of `InternalException`, when it's a simple
+    /// name rather than a class from elsewhere.
+    pub fn generated_panic_exception(&self) -> Option<&str> {
+        self.panic_exception
+            .as_deref()
+            .filter(|name| !name.contains('.') && *name != "InternalException")
+    }
+
+    /// Whether the function, object, record or enum `name` is left out of the bindings: it isn't
+    /// matched by `include_items` when that's set, or it's matched by `exclude_items`.
+    pub fn excludes_item(&self, name: &str) -> bool {
+        let matches = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| wildcard_matches(pattern, name))
+        };
+        self.include_items
+            .as_deref()
+            .is_some_and(|include_items| !matches(include_items))
+            || matches(&self.exclude_items)
+    }
+
+    /// The fields records of `name` are ordered by, in order, when it's listed in `comparable`.
+    /// All fields in declaration order when the list is empty.
+    pub fn comparable_fields<'a>(&'a self, rec: &'a Record) -> Option<Vec<&'a str>> {
+        let fields = self.comparable.get(rec.name())?;
+        Some(if fields.is_empty() {
+            rec.fields().iter().map(|field| field.name()).collect()
+        } else {
+            fields.iter().map(String::as_str).collect()
+        })
+    }
+
+    /// Whether the record or flat enum `name` is listed in `comparable`.
+    pub fn is_comparable(&self, name: &str) -> bool {
+        self.comparable.contains_key(name)
+    }
+
+    /// The `buffer_growth_factor` as a Java `double` literal.
+    pub fn buffer_growth_factor_literal(&self) -> Option<String> {
+        self.buffer_growth_factor
+            .map(|growth_factor| format!("{growth_factor:?}"))
+    }
+
+    /// The Java `Charset` strings are encoded with, `string_charset` or UTF-8.
+    pub fn string_charset(&self) -> String {
+        match self.string_charset.as_deref() {
+            Some(name) => format!(
+                "java.nio.charset.Charset.forName({})",
+                java_string_literal(name)
+            ),
+            None => "StandardCharsets.UTF_8".to_string(),
+        }
+    }
+
+    /// Whether strings are encoded with another `Charset` than UTF-8, see `string_charset`.
+    pub fn has_string_charset(&self) -> bool {
+        self.string_charset.is_some()
+    }
+
+    /// Whether undeclared callback interface exceptions halt the JVM, see `on_callback_exception`.
+    pub fn aborts_on_callback_exception(&self) -> bool {
+        self.on_callback_exception == OnCallbackException::Abort
+    }
+
+    /// Whether the object `name` can only be used from the thread that created it, see
+    /// `enforce_thread_affinity`.
+    pub fn enforces_thread_affinity(&self, name: &str) -> bool {
+        self.enforce_thread_affinity
+            .iter()
+            .any(|object| object == name)
+    }
+
+    /// The class holding the top-level functions, `namespace_class` or the namespace in
+    /// `UpperCamelCase`.
+    pub fn namespace_class_name(&self, ci: &ComponentInterface) -> String {
+        self.namespace_class
+            .clone()
+            .unwrap_or_else(|| JavaCodeOracle.class_name(ci, ci.namespace()))
+    }
+
+    /// The method of the namespace class running the library initialization,
+    /// `initialization_entry_point` or `ensureInitialized`. An empty name leaves it out.
+    pub fn initialization_entry_point(&self) -> Option<&str> {
+        Some(
+            self.initialization_entry_point
+                .as_deref()
+                .unwrap_or("ensureInitialized"),
+        )
+        .filter(|name| !name.is_empty())
+    }
+
+    /// A generator-internal identifier: `name` with the `internal_prefix`, `uniffi` by default.
+    pub fn internal_name(&self, name: &str) -> String {
+        format!(
+            "{}{name}",
+            self.internal_prefix.as_deref().unwrap_or("uniffi")
+        )
+    }
+
+    /// The package for the shared JNA runtime types (`RustBuffer`, `UniffiRustCallStatus`, ...),
+    /// the bindings package by default.
+    pub fn runtime_package(&self) -> String {
+        self.runtime_package
+            .clone()
+            .unwrap_or_else(|| self.package_name())
+    }
+
+    /// Reference a runtime type, fully-qualified if it lives outside of the bindings package.
+    pub fn runtime_type(&self, name: &str) -> String {
+        let runtime_package = self.runtime_package();
+        if runtime_package == self.package_name() {
+            name.to_string()
+        } else {
+            format!("{runtime_package}.{name}")
+        }
+    }
+
+    // Get the `RustBuffer` used by an external type. Bindings sharing a runtime package share
+    // a single `RustBuffer`, otherwise it's the one in the external package.
+    fn external_rust_buffer(&self, module_path: &str, namespace: &str) -> String {
+        if self.runtime_package.is_some() {
+            self.runtime_type("RustBuffer")
+        } else {
+            format!(
+                "{}.RustBuffer",
+                self.external_type_package_name(module_path, namespace)
+            )
+        }
+    }
+
+    /// The imports needed for the extra interfaces the type `name` implements.
+    pub fn implements_imports(&self, name: &str, ci: &ComponentInterface) -> Vec<String> {
+        self.implements
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter(|interface| interface.contains('.') && !self.import_clashes(interface, ci))
+            .cloned()
+            .collect()
+    }
+
+    // Whether importing the class `fqn` would clash with a class generated in our package.
+    //
+    // Java can't import a class that has the same simple name as a class in the importing file's
+    // package, so such classes are referred to by their fully-qualified name instead.
+    fn import_clashes(&self, fqn: &str, ci: &ComponentInterface) -> bool {
+        match fqn.rsplit_once('.') {
+            Some((package, simple_name)) => {
+                package != self.package_name() && local_class_names(ci, self).contains(simple_name)
+            }
+            None => false,
+        }
+    }
+
+    /// The Java type of `bytes`, the `bytes_type` if there is one, fully-qualified through its
+    /// imports so it can be used in any file without them.
+    fn bytes_type_name(&self) -> Option<String> {
+        let bytes_type = self.bytes_type.as_ref()?;
+        let type_name = bytes_type.type_name.as_ref()?;
+        let qualified_name = bytes_type.imports.iter().flatten().find(|import| {
+            import.rsplit_once('.').map(|(_, simple_name)| simple_name) == Some(type_name.as_str())
+        });
+        Some(qualified_name.unwrap_or(type_name).clone())
+    }
+
     /// Whether to generate immutable records (`record` instead of `class`)
     pub fn generate_immutable_records(&self) -> bool {
         self.generate_immutable_records.unwrap_or(false)
@@ -209,6 +551,207 @@ impl Config {
     }
 }
 
+/// Where the generated record comparators sort empty optionals.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum NullsOrder {
+    First,
+    #[default]
+    Last,
+}
+
+/// How records compute `hashCode()`.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RecordHash {
+    /// `Objects.hash`, whose values can change between JVMs.
+    #[default]
+    Objects,
+    /// `UniffiStableHash`, FNV-1a over the field values.
+    Fnv,
+}
+
+/// The property names `@ConstructorProperties` lists, with `constructor_properties`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PropertyNames {
+    /// The Java field names, like `grosNombre`.
+    Java,
+    /// The UniFFI field names, like `gros_nombre`.
+    Rust,
+}
+
+/// What to generate for something the bindings can't express, a default without a Java literal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OnUnsupported {
+    /// Fail generation, naming what isn't supported.
+    Error,
+    /// Leave it out of the bindings.
+    #[default]
+    Skip,
+    /// Generate it anyway, throwing `UnsupportedOperationException` when used.
+    Stub,
+}
+
+/// An entry of `callback_exception_mapping`, the error variant a callback interface method throws
+/// a Java exception as.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CallbackExceptionMapping {
+    /// The exception class, like `java.io.IOException`.
+    exception: String,
+    /// The error variant, like `StorageError.Io`.
+    error: String,
+}
+
+/// What a callback interface method throwing an exception it doesn't declare does.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OnCallbackException {
+    /// Return it to Rust as an unexpected error, with the exception as the message.
+    #[default]
+    Unexpected,
+    /// Print the exception and halt the JVM.
+    Abort,
+}
+
+/// How an item listed in `deprecated` is deprecated.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Deprecation {
+    since: Option<String>,
+    /// What to use instead, as a javadoc `{@link}` reference like `#newName` or `OtherType`.
+    replacement: Option<String>,
+    for_removal: bool,
+}
+
+impl Deprecation {
+    /// The javadoc `@deprecated` tag.
+    fn javadoc_tag(&self) -> String {
+        match &self.replacement {
+            Some(replacement) => format!("@deprecated Use {{@link {replacement}}} instead."),
+            None if self.for_removal => "@deprecated This will be removed.".to_string(),
+            None => "@deprecated".to_string(),
+        }
+    }
+
+    /// The `@Deprecated` annotation, with `since` and `forRemoval` when they're set.
+    fn annotation(&self) -> String {
+        let mut elements = vec![];
+        if let Some(since) = &self.since {
+            elements.push(format!("since = {}", java_string_literal(since)));
+        }
+        if self.for_removal {
+            elements.push("forRemoval = true".to_string());
+        }
+        if elements.is_empty() {
+            "@Deprecated".to_string()
+        } else {
+            format!("@Deprecated({})", elements.join(", "))
+        }
+    }
+}
+
+/// Options passed to JNA when the native library is loaded.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JnaOptions {
+    string_encoding: Option<String>,
+    structure_alignment: StructureAlignment,
+}
+
+impl JnaOptions {
+    /// The `Library.OPTION_STRING_ENCODING` used for strings passed directly to native code.
+    fn string_encoding(&self) -> String {
+        java_string_literal(self.string_encoding.as_deref().unwrap_or("UTF-8"))
+    }
+
+    /// The `Library.OPTION_STRUCTURE_ALIGNMENT` used for JNA structures.
+    fn structure_alignment(&self) -> &'static str {
+        match self.structure_alignment {
+            StructureAlignment::Default => "Structure.ALIGN_DEFAULT",
+            StructureAlignment::None => "Structure.ALIGN_NONE",
+            StructureAlignment::Gnuc => "Structure.ALIGN_GNUC",
+            StructureAlignment::Msvc => "Structure.ALIGN_MSVC",
+        }
+    }
+}
+
+/// The JNA structure alignment, `default` being the platform's natural alignment.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum StructureAlignment {
+    #[default]
+    Default,
+    None,
+    Gnuc,
+    Msvc,
+}
+
+/// How snake_case Rust names of functions, methods, arguments and fields are converted to Java.
+///
+/// `heck` is whatever the `heck` crate does, which drops leading and trailing underscores and
+/// joins digits to the previous word, so `foo_2` and `foo2` both become `foo2`. `strict` only
+/// capitalizes the first letter of each word after the first and leaves everything else alone.
+/// It keeps leading and trailing underscores, and the underscore before a word starting with a
+/// digit, so `foo_2` stays `foo_2`. It doesn't depend on `heck`, so names don't change when it's
+/// updated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum NamingStyle {
+    #[default]
+    Heck,
+    Strict,
+}
+
+impl NamingStyle {
+    /// Convert a snake_case name to lowerCamelCase.
+    fn lower_camel_case(self, nm: &str) -> String {
+        match self {
+            NamingStyle::Heck => nm.to_lower_camel_case(),
+            NamingStyle::Strict => strict_camel_case(nm, false),
+        }
+    }
+
+    /// Convert a snake_case name to UpperCamelCase.
+    fn upper_camel_case(self, nm: &str) -> String {
+        match self {
+            NamingStyle::Heck => nm.to_upper_camel_case(),
+            NamingStyle::Strict => strict_camel_case(nm, true),
+        }
+    }
+}
+
+fn strict_camel_case(nm: &str, upper_first: bool) -> String {
+    let words = nm.trim_matches('_');
+    if words.is_empty() {
+        return nm.to_string();
+    }
+    let leading = &nm[..nm.len() - nm.trim_start_matches('_').len()];
+    let trailing = &nm[nm.trim_end_matches('_').len()..];
+
+    let mut name = leading.to_string();
+    for (i, word) in words.split('_').filter(|w| !w.is_empty()).enumerate() {
+        let mut chars = word.chars();
+        let first = chars.next().expect("empty words are skipped");
+        if first.is_ascii_digit() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.push_str(word);
+            continue;
+        }
+        if i == 0 && !upper_first {
+            name.extend(first.to_lowercase());
+        } else {
+            name.extend(first.to_uppercase());
+        }
+        name.push_str(chars.as_str());
+    }
+    name.push_str(trailing);
+    name
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct CustomTypeConfig {
@@ -218,6 +761,51 @@ pub struct CustomTypeConfig {
     lift: String,
     from_custom: String, // backcompat alias for lower
     lower: String,
+    // A user-authored `FfiConverter` with an `INSTANCE`, used instead of generating one.
+    converter_class: Option<String>,
+}
+
+impl CustomTypeConfig {
+    // The configured imports that can be imported without clashing with a generated class.
+    fn imports_for(&self, ci: &ComponentInterface, config: &Config) -> Vec<String> {
+        self.imports
+            .iter()
+            .flatten()
+            .filter(|import| !config.import_clashes(import, ci))
+            .cloned()
+            .collect()
+    }
+
+    // The configured `type_name`, fully-qualified if its import clashes with a generated class.
+    fn type_name_for(&self, ci: &ComponentInterface, config: &Config) -> Option<String> {
+        let type_name = self.type_name.as_ref()?;
+        let clashing_import = self.imports.iter().flatten().find(|import| {
+            import.rsplit_once('.').map(|(_, simple_name)| simple_name) == Some(type_name.as_str())
+                && config.import_clashes(import, ci)
+        });
+        Some(clashing_import.unwrap_or(type_name).clone())
+    }
+
+    // The configured `converter_class`, failing if it isn't a valid Java class name.
+    fn converter_class(&self, name: &str) -> Result<Option<&str>> {
+        let Some(converter_class) = self.converter_class.as_deref() else {
+            return Ok(None);
+        };
+        let is_identifier = |segment: &str| {
+            let mut chars = segment.chars();
+            chars
+                .next()
+                .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+                && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+                && !KEYWORDS.contains(segment)
+        };
+        if !converter_class.split('.').all(is_identifier) {
+            anyhow::bail!(
+                "custom_types.{name}.converter_class: `{converter_class}` isn't a valid Java class name"
+            );
+        }
+        Ok(Some(converter_class))
+    }
 }
 
 // functions replace literal "{}" in strings with a specified value.
@@ -242,11 +830,743 @@ impl CustomTypeConfig {
 
 // Generate Java bindings for the given ComponentInterface, as a string.
 pub fn generate_bindings(config: &Config, ci: &ComponentInterface) -> Result<String> {
+    for (name, custom_type_config) in &config.custom_types {
+        custom_type_config.converter_class(name)?;
+    }
+    if let Some(bytes_type) = &config.bytes_type {
+        if bytes_type.type_name.is_none() {
+            anyhow::bail!("bytes_type: `type_name` is required");
+        }
+        if bytes_type.converter_class.is_some() {
+            anyhow::bail!("bytes_type: `converter_class` isn't supported, use `lift` and `lower`");
+        }
+    }
+    if config
+        .buffer_growth_factor
+        .is_some_and(|factor| !factor.is_finite() || factor <= 1.0)
+    {
+        anyhow::bail!("buffer_growth_factor: has to be a number greater than 1");
+    }
+    // The charset names `Charset.forName` accepts.
+    if config.string_charset.as_deref().is_some_and(|name| {
+        !name.starts_with(|c: char| c.is_ascii_alphanumeric())
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-+:_.".contains(c))
+    }) {
+        anyhow::bail!("string_charset: isn't a valid charset name");
+    }
+    if config.package_name().is_empty() {
+        if config.emit_osgi_manifest {
+            anyhow::bail!(
+                "emit_osgi_manifest: the default package can't be exported, set package_name"
+            );
+        }
+        if config.scala_interop {
+            anyhow::bail!(
+                "scala_interop: Scala can't refer to classes in the default package, set package_name"
+            );
+        }
+    } else if config.runtime_package().is_empty() {
+        // Classes in a package can't refer to ones in the default package.
+        anyhow::bail!("runtime_package: can only be the default package if package_name is too");
+    }
+    check_ffi_types(config, ci)?;
+    check_excluded_items(config, ci)?;
+    check_generated_names(config, ci)?;
+    check_comparable(config, ci)?;
+    check_callback_exception_mapping(config, ci)?;
+    check_enum_tags(config, ci)?;
+    if config.strict {
+        check_strict(config, ci)?;
+    }
+    if config.on_unsupported == OnUnsupported::Error {
+        check_supported(ci)?;
+    }
     JavaWrapper::new(config.clone(), ci)
         .render()
         .context("failed to render java bindings")
 }
 
+// Fail on the first FFI type the `UniffiLib` declarations can't be written with, naming where it's
+// used: a reference to a type JNA has no by-reference form of, or an FFI struct field without a
+// default value, which JNA needs.
+fn check_ffi_types(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    let check_reference = |ffi_type: &FfiType, location: &str| match ffi_type {
+        FfiType::Reference(inner) | FfiType::MutReference(inner)
+            if JavaCodeOracle
+                .ffi_type_label_by_reference(inner, config, ci)
+                .is_none() =>
+        {
+            anyhow::bail!("the FFI type {inner:?} can't be passed by reference, in {location}")
+        }
+        _ => Ok(()),
+    };
+    for definition in ci.ffi_definitions() {
+        match definition {
+            FfiDefinition::Function(func) => {
+                let location = format!("the FFI function `{}`", func.name());
+                for arg in func.arguments() {
+                    check_reference(&arg.type_(), &location)?;
+                }
+                if let Some(return_type) = func.return_type() {
+                    check_reference(return_type, &location)?;
+                }
+            }
+            FfiDefinition::CallbackFunction(callback) => {
+                let location = format!("the FFI callback `{}`", callback.name());
+                for arg in callback.arguments() {
+                    check_reference(&arg.type_(), &location)?;
+                }
+                if let Some(return_type) = callback.return_type() {
+                    check_reference(return_type, &location)?;
+                }
+            }
+            FfiDefinition::Struct(ffi_struct) => {
+                for field in ffi_struct.fields() {
+                    let location = format!(
+                        "the field `{}` of the FFI struct `{}`",
+                        field.name(),
+                        ffi_struct.name()
+                    );
+                    let ffi_type = field.type_();
+                    check_reference(&ffi_type, &location)?;
+                    if JavaCodeOracle
+                        .ffi_default_value(&ffi_type, config)
+                        .is_none()
+                    {
+                        anyhow::bail!(
+                            "the FFI type {ffi_type:?} has no default value, in {location}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any number of characters.
+fn wildcard_matches(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => name.strip_prefix(prefix).is_some_and(|name| {
+            name.char_indices()
+                .map(|(i, _)| i)
+                .chain([name.len()])
+                .any(|i| wildcard_matches(rest, &name[i..]))
+        }),
+    }
+}
+
+/// The name of the item left out with `include_items` or `exclude_items` that `type_` is or holds.
+fn excluded_item(config: &Config, ci: &ComponentInterface, type_: &Type) -> Option<String> {
+    match type_ {
+        Type::Object { name, .. } | Type::Record { name, .. } | Type::Enum { name, .. }
+            if !ci.is_external(type_) && config.excludes_item(name) =>
+        {
+            Some(name.clone())
+        }
+        Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+            excluded_item(config, ci, inner_type)
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => excluded_item(config, ci, key_type).or_else(|| excluded_item(config, ci, value_type)),
+        _ => None,
+    }
+}
+
+/// Whether no class is generated for `type_`, because it is or holds an item left out with
+/// `include_items` or `exclude_items`.
+fn is_excluded_type(config: &Config, ci: &ComponentInterface, type_: &Type) -> bool {
+    excluded_item(config, ci, type_).is_some()
+}
+
+// Fail on the first item kept in the bindings that uses one left out with `include_items` or
+// `exclude_items`, it would refer to a class that isn't generated.
+fn check_excluded_items(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    fn callable_types(callable: &dyn Callable) -> Vec<Type> {
+        callable
+            .arguments()
+            .into_iter()
+            .map(|arg| arg.as_type())
+            .chain(callable.return_type().cloned())
+            .chain(callable.throws_type().cloned())
+            .collect()
+    }
+    let mut uses = vec![];
+    for func in ci.function_definitions() {
+        if !config.excludes_item(func.name()) {
+            uses.push((func.name().to_string(), callable_types(func)));
+        }
+    }
+    for obj in ci.object_definitions() {
+        if config.excludes_item(obj.name()) {
+            continue;
+        }
+        for cons in obj.constructors() {
+            uses.push((
+                format!("{}.{}", obj.name(), cons.name()),
+                callable_types(cons),
+            ));
+        }
+        for meth in obj.methods() {
+            uses.push((
+                format!("{}.{}", obj.name(), meth.name()),
+                callable_types(meth),
+            ));
+        }
+    }
+    for cbi in ci.callback_interface_definitions() {
+        for meth in cbi.methods() {
+            uses.push((
+                format!("{}.{}", cbi.name(), meth.name()),
+                callable_types(meth),
+            ));
+        }
+    }
+    for rec in ci.record_definitions() {
+        if !config.excludes_item(rec.name()) {
+            let types = rec.fields().iter().map(|field| field.as_type()).collect();
+            uses.push((rec.name().to_string(), types));
+        }
+    }
+    for e in ci.enum_definitions() {
+        if !config.excludes_item(e.name()) {
+            let types = e
+                .variants()
+                .iter()
+                .flat_map(|variant| variant.fields().iter().map(|field| field.as_type()))
+                .collect();
+            uses.push((e.name().to_string(), types));
+        }
+    }
+    for (user, types) in uses {
+        if let Some(item) = types
+            .iter()
+            .find_map(|type_| excluded_item(config, ci, type_))
+        {
+            anyhow::bail!(
+                "`{item}` is left out by include_items or exclude_items, but `{user}` uses it"
+            );
+        }
+    }
+    Ok(())
+}
+
+// Fail on the first function or type named like a method or class the bindings generate next to
+// them: the namespace class methods like `uniffiSelfTest()` and the entry point, and the
+// `<Namespace>Lib` and `<Namespace>Constants` classes.
+fn check_generated_names(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    let mut methods = vec![
+        config.internal_name("SelfTest"),
+        config.internal_name("Reinitialize"),
+    ];
+    methods.extend(config.initialization_entry_point().map(str::to_string));
+    if ci.contains_object_types() && !config.android_cleaner() {
+        methods.push(config.internal_name("SetCleaner"));
+    }
+    if config.instrumentable_ffi {
+        methods.push(config.internal_name("SetFfiInstrumentation"));
+    }
+    if config.shutdown_hook {
+        methods.push(config.internal_name("Shutdown"));
+    }
+    for func in ci.function_definitions() {
+        if config.excludes_item(func.name()) {
+            continue;
+        }
+        let name = JavaCodeOracle.callable_name("", func.name(), config);
+        if methods.contains(&name) {
+            anyhow::bail!(
+                "the function `{}` clashes with the generated `{}.{name}()`",
+                func.name(),
+                config.namespace_class_name(ci)
+            );
+        }
+    }
+    let namespace = JavaCodeOracle.class_name(ci, ci.namespace());
+    let mut classes = vec![format!("{namespace}Lib")];
+    if config.emit_constants {
+        classes.push(format!("{namespace}Constants"));
+    }
+    let local_classes = local_class_names(ci, config);
+    for class in classes {
+        if local_classes.contains(&class) {
+            anyhow::bail!("a type of the bindings clashes with the generated `{class}` class");
+        }
+    }
+    Ok(())
+}
+
+// Fail on the first type listed in `comparable` that isn't a record or flat enum, or whose ordering
+// fields don't exist or have no natural order.
+fn check_comparable(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    for name in config.comparable.keys() {
+        if let Some(rec) = ci.get_record_definition(name) {
+            let type_name = JavaCodeOracle.class_name(ci, name);
+            for field_name in config.comparable_fields(rec).into_iter().flatten() {
+                let Some(field) = rec.fields().iter().find(|f| f.name() == field_name) else {
+                    anyhow::bail!("comparable: `{name}` has no field `{field_name}`");
+                };
+                if field_comparator(config, ci, &type_name, field).is_none() {
+                    anyhow::bail!(
+                        "comparable: field `{field_name}` of `{name}` has no natural order"
+                    );
+                }
+            }
+        } else if ci.get_enum_definition(name).is_some_and(|e| e.is_flat()) {
+            if !config.comparable[name].is_empty() {
+                anyhow::bail!("comparable: enum `{name}` is ordered by its variants, not fields");
+            }
+        } else {
+            anyhow::bail!("comparable: `{name}` is not a record or an enum without fields");
+        }
+    }
+    Ok(())
+}
+
+// With `enum_with_tag`, fail when the `Kind` enum of an enum with fields is the name of another
+// class of the bindings.
+fn check_enum_tags(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    if !config.enum_with_tag {
+        return Ok(());
+    }
+    let class_names = local_class_names(ci, config);
+    for e in ci.enum_definitions() {
+        if e.is_flat() || ci.is_name_used_as_error(e.name()) {
+            continue;
+        }
+        let kind_name = format!(
+            "{}Kind",
+            JavaCodeOracle.type_class_name(ci, config, e.name())
+        );
+        if class_names.contains(&kind_name) {
+            anyhow::bail!(
+                "enum_with_tag: the tags of `{}` clash with `{kind_name}`",
+                e.name()
+            );
+        }
+    }
+    Ok(())
+}
+
+// Fail on the first `callback_exception_mapping` entry that isn't an error variant, or is a variant
+// with fields, which the exception has no values for.
+fn check_callback_exception_mapping(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    for CallbackExceptionMapping { exception, error } in &config.callback_exception_mapping {
+        let variant = error
+            .split_once('.')
+            .filter(|(error_name, _)| ci.is_name_used_as_error(error_name))
+            .and_then(|(error_name, variant_name)| {
+                let e = ci.get_enum_definition(error_name)?;
+                let variant = e
+                    .variants()
+                    .into_iter()
+                    .find(|v| v.name() == variant_name)?;
+                Some((e, variant))
+            });
+        match variant {
+            None => anyhow::bail!(
+                "callback_exception_mapping: `{exception}` maps to `{error}`, which isn't a variant of an error"
+            ),
+            Some((e, variant)) if !e.is_flat() && variant.has_fields() => anyhow::bail!(
+                "callback_exception_mapping: `{error}` has fields, `{exception}` can't be mapped to it"
+            ),
+            Some(_) => (),
+        }
+    }
+    Ok(())
+}
+
+/// The function mapping exceptions thrown by a callback interface method to its `error_type`, with
+/// `callback_exception_mapping`. Unmapped exceptions map to `null`.
+///
+/// Variants of flat errors get the exception as their message. Exceptions are checked in the order
+/// they're listed in, so a class has to be listed before the one it extends to be mapped.
+fn map_callback_exception(config: &Config, ci: &ComponentInterface, error_type: &Type) -> String {
+    let Type::Enum { name, .. } = error_type else {
+        return "e -> null".to_string();
+    };
+    let Some(e) = ci.get_enum_definition(name) else {
+        return "e -> null".to_string();
+    };
+    let type_name = JavaCodeOracle.find(error_type).type_label(ci, config);
+    let mut mappings = config
+        .callback_exception_mapping
+        .iter()
+        .filter_map(|CallbackExceptionMapping { exception, error }| {
+            let (error_name, variant_name) = error.split_once('.')?;
+            let variant = e
+                .variants()
+                .into_iter()
+                .find(|v| error_name == name && v.name() == variant_name)?;
+            let variant_class = filters::error_variant_name(variant).ok()?;
+            let message = if e.is_flat() { "e.toString()" } else { "" };
+            Some(format!(
+                "e instanceof {exception} ? new {type_name}.{variant_class}({message})"
+            ))
+        })
+        .collect::<Vec<_>>();
+    mappings.push("null".to_string());
+    format!("e -> {}", mappings.join(" : "))
+}
+
+// With `strict`, fail listing everything the bindings would leave out: the functions, constructors
+// and methods throwing an external error, and the defaults that can't be written as a Java literal,
+// unless `on_unsupported` stubs them.
+fn check_strict(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    let functions = ci
+        .function_definitions()
+        .iter()
+        .filter(|func| !config.excludes_item(func.name()))
+        .map(|func| (func.name().to_string(), func as &dyn Callable));
+    let objects = ci
+        .object_definitions()
+        .iter()
+        .filter(|obj| !config.excludes_item(obj.name()));
+    let constructors = objects.clone().flat_map(|obj| {
+        obj.constructors().into_iter().map(|cons| {
+            (
+                format!("{}.{}", obj.name(), cons.name()),
+                cons as &dyn Callable,
+            )
+        })
+    });
+    let methods = objects.flat_map(|obj| {
+        obj.methods().into_iter().map(|meth| {
+            (
+                format!("{}.{}", obj.name(), meth.name()),
+                meth as &dyn Callable,
+            )
+        })
+    });
+    let mut unsupported = Vec::new();
+    for (name, callable) in functions.chain(constructors).chain(methods) {
+        if !can_render_callable(callable, ci) {
+            unsupported.push(format!("`{name}` throws an error from another crate"));
+        } else if config.on_unsupported != OnUnsupported::Stub {
+            if let Some(arg) = unsupported_default(&callable.arguments()) {
+                unsupported.push(format!(
+                    "the default of `{}` in `{name}` can't be written in Java",
+                    arg.name()
+                ));
+            }
+        }
+    }
+    if !unsupported.is_empty() {
+        anyhow::bail!(
+            "strict: {} item(s) can't be generated:\n  {}",
+            unsupported.len(),
+            unsupported.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+// Fail on the first default argument that can't be written as a Java literal.
+fn check_supported(ci: &ComponentInterface) -> Result<()> {
+    let functions = ci
+        .function_definitions()
+        .iter()
+        .map(|func| (func.name().to_string(), func.arguments()));
+    let constructors = ci.object_definitions().iter().flat_map(|obj| {
+        obj.constructors()
+            .into_iter()
+            .map(|cons| (format!("{}.{}", obj.name(), cons.name()), cons.arguments()))
+    });
+    let methods = ci.object_definitions().iter().flat_map(|obj| {
+        obj.methods()
+            .into_iter()
+            .map(|meth| (format!("{}.{}", obj.name(), meth.name()), meth.arguments()))
+    });
+    for (name, arguments) in functions.chain(constructors).chain(methods) {
+        if let Some(arg) = unsupported_default(&arguments) {
+            anyhow::bail!(
+                "on_unsupported: the default of `{}` in `{name}` can't be written in Java",
+                arg.name()
+            );
+        }
+    }
+    Ok(())
+}
+
+// The JNA resource prefix, OSGi `osname` and `processor` of each platform we bundle the cdylib for.
+const OSGI_PLATFORMS: &[(&str, &str, &str)] = &[
+    ("linux-x86-64", "Linux", "x86-64"),
+    ("linux-aarch64", "Linux", "aarch64"),
+    ("darwin-x86-64", "MacOSX", "x86-64"),
+    ("darwin-aarch64", "MacOSX", "aarch64"),
+    ("win32-x86-64", "Win32", "x86-64"),
+    ("win32-aarch64", "Win32", "aarch64"),
+];
+
+/// Generate the OSGi headers for a jar holding these bindings, as a `MANIFEST.MF` fragment.
+///
+/// The bundle exports the bindings package, imports JNA and the packages of any external types,
+/// and requires `minimum_java_version`. The cdylib is expected at JNA's resource path for each
+/// platform, e.g. `linux-x86-64/libfoo.so`, the same layout `Native.load` finds it in outside of
+/// OSGi.
+pub fn generate_osgi_manifest(
+    config: &Config,
+    ci: &ComponentInterface,
+    minimum_java_version: u32,
+) -> String {
+    let package_name = config.package_name();
+    let mut exports = vec![package_name.clone()];
+    if config.runtime_package() != package_name {
+        exports.push(config.runtime_package());
+    }
+    let mut imports = vec!["com.sun.jna".to_string(), "com.sun.jna.ptr".to_string()];
+    if !config.android_cleaner() {
+        imports.push("com.sun.jna.internal".to_string());
+    }
+    let external_packages = ci
+        .iter_types()
+        .filter(|t| ci.is_external(t))
+        .filter_map(|t| t.module_path())
+        .filter_map(|module_path| {
+            let crate_name = module_path.split("::").next().unwrap();
+            config.external_packages.get(crate_name).cloned()
+        })
+        .filter(|package| !exports.contains(package))
+        .collect::<std::collections::BTreeSet<_>>();
+    imports.extend(external_packages);
+    let cdylib_name = config.cdylib_name();
+    let native_code = OSGI_PLATFORMS
+        .iter()
+        .map(|(resource_prefix, osname, processor)| {
+            let library = match *osname {
+                "Linux" => format!("lib{cdylib_name}.so"),
+                "MacOSX" => format!("lib{cdylib_name}.dylib"),
+                _ => format!("{cdylib_name}.dll"),
+            };
+            format!("{resource_prefix}/{library};osname={osname};processor={processor}")
+        })
+        .collect::<Vec<_>>();
+
+    [
+        ("Manifest-Version", "1.0".to_string()),
+        ("Bundle-ManifestVersion", "2".to_string()),
+        ("Bundle-SymbolicName", package_name.clone()),
+        ("Bundle-Name", ci.namespace().to_string()),
+        ("Export-Package", exports.join(",")),
+        ("Import-Package", imports.join(",")),
+        ("Bundle-NativeCode", native_code.join(",")),
+        (
+            "Require-Capability",
+            format!("osgi.ee;filter:=\"(&(osgi.ee=JavaSE)(version>={minimum_java_version}))\""),
+        ),
+    ]
+    .iter()
+    .map(|(name, value)| manifest_header(name, value))
+    .collect()
+}
+
+// A manifest header line, wrapped into continuation lines as manifests allow at most 72 bytes per
+// line. Lines are only broken between characters, a UTF-8 sequence can't span two lines.
+fn manifest_header(name: &str, value: &str) -> String {
+    let line = format!("{name}: {value}");
+    let mut out = String::new();
+    let mut chunk_start = 0;
+    let mut chunk_len = 72;
+    while chunk_start < line.len() {
+        let mut chunk_end = (chunk_start + chunk_len).min(line.len());
+        while !line.is_char_boundary(chunk_end) {
+            chunk_end -= 1;
+        }
+        if chunk_start > 0 {
+            out.push(' ');
+        }
+        out.push_str(&line[chunk_start..chunk_end]);
+        out.push('\n');
+        chunk_start = chunk_end;
+        // Continuation lines spend a byte on the leading space.
+        chunk_len = 71;
+    }
+    out
+}
+
+/// A report of every type in `ci` and the names the generated Java uses for it.
+///
+/// This is for whoever is writing the bindings rather than their users: when the bindings don't
+/// compile, it tells which UniFFI type a Java name came from, and makes for a good bug report.
+pub fn generate_type_report(config: &Config, ci: &ComponentInterface) -> String {
+    let mut report = format!(
+        "# Types of the {} bindings in {}\n",
+        ci.namespace(),
+        config.package_name()
+    );
+    for type_ in ci.iter_local_types() {
+        let code_type = JavaCodeOracle.find(type_);
+        let mut imports = code_type.imports().unwrap_or_default();
+        if let Type::Custom { name, .. } = type_ {
+            imports.extend(
+                config
+                    .custom_types
+                    .get(name)
+                    .map(|custom_type_config| custom_type_config.imports_for(ci, config))
+                    .unwrap_or_default(),
+            );
+        }
+        report.push_str(&format!("\n{}\n", code_type.canonical_name()));
+        report.push_str(&format!("  uniffi_type: {type_:?}\n"));
+        report.push_str(&format!(
+            "  type_label: {}\n",
+            code_type.type_label(ci, config)
+        ));
+        report.push_str(&format!(
+            "  ffi_converter_name: {}\n",
+            code_type.ffi_converter_name()
+        ));
+        report.push_str(&format!(
+            "  ffi_converter_instance: {}\n",
+            code_type.ffi_converter_instance(config, ci)
+        ));
+        report.push_str(&format!("  imports: {}\n", imports.join(", ")));
+    }
+    report
+}
+
+/// Every native symbol the generated `UniffiLib` binds to, in declaration order.
+///
+/// These all have to be exported by the cdylib, which makes this list handy when debugging
+/// linkage errors.
+pub fn required_ffi_symbols(ci: &ComponentInterface) -> Vec<String> {
+    ci.iter_ffi_function_definitions()
+        .map(|func| func.name().to_string())
+        .collect()
+}
+
+/// A JUnit 5 test class checking the value semantics of the records and enums in `ci`, the
+/// `<Namespace>ValueTest` written with `emit_value_tests`.
+///
+/// Each type gets a test making two instances out of the same field values, so only types a value
+/// can be made up for are covered, see `sample_value`.
+pub fn generate_value_tests(config: &Config, ci: &ComponentInterface) -> Result<String> {
+    let mut samples = vec![];
+    let mut skipped = vec![];
+    for type_ in ci.iter_local_types() {
+        let is_value = match type_ {
+            Type::Record { .. } => true,
+            Type::Enum { name, .. } => !ci.is_name_used_as_error(name),
+            _ => false,
+        };
+        if !is_value {
+            continue;
+        }
+        let type_name = JavaCodeOracle.find(type_).type_label(ci, config);
+        match sample_value(type_, ci, config) {
+            Some(sample) => {
+                // Byte arrays print by identity, also with `deep_equals`, so two samples holding
+                // them print differently.
+                let prints_same = !sample.contains("new byte[");
+                samples.push((
+                    format!("{}HasValueSemantics", type_name.to_lower_camel_case()),
+                    sample,
+                    prints_same,
+                ))
+            }
+            None => skipped.push(type_name),
+        }
+    }
+    ValueTests {
+        config,
+        class_name: format!("{}ValueTest", JavaCodeOracle.class_name(ci, ci.namespace())),
+        namespace: ci.namespace(),
+        samples,
+        skipped,
+    }
+    .render()
+    .context("failed to render java value tests")
+}
+
+/// A Java expression for a value of `type_`, for `generate_value_tests`. Records and enum variants
+/// get the zero value of each field, optionals are `null` and collections empty.
+///
+/// `None` for types no value can be made up for: objects, callback interfaces, custom and external
+/// types, and byte arrays unless `deep_equals` compares them by content.
+fn sample_value(type_: &Type, ci: &ComponentInterface, config: &Config) -> Option<String> {
+    let sample = match type_ {
+        Type::Int8 | Type::UInt8 => "(byte) 0".to_string(),
+        Type::Int16 | Type::UInt16 => "(short) 0".to_string(),
+        Type::Int32 | Type::UInt32 => "0".to_string(),
+        Type::Int64 | Type::UInt64 => "0L".to_string(),
+        Type::Float32 => "0.0f".to_string(),
+        Type::Float64 => "0.0".to_string(),
+        Type::Boolean => "false".to_string(),
+        Type::String => "\"\"".to_string(),
+        Type::Bytes if config.deep_equals && config.bytes_type.is_none() => {
+            "new byte[0]".to_string()
+        }
+        Type::Timestamp => "java.time.Instant.EPOCH".to_string(),
+        Type::Duration => "java.time.Duration.ZERO".to_string(),
+        Type::Optional { inner_type } => match primitive_optional(config, inner_type) {
+            Some((class, _)) => format!("{class}.empty()"),
+            None => "null".to_string(),
+        },
+        Type::Sequence { .. } => "java.util.List.of()".to_string(),
+        Type::Map { .. } => "java.util.Map.of()".to_string(),
+        Type::Record { name, .. } if !ci.is_external(type_) => {
+            let rec = ci.get_record_definition(name)?;
+            let type_name = JavaCodeOracle.find(type_).type_label(ci, config);
+            if !rec.has_fields() && config.singleton_empty_records {
+                format!("{type_name}.INSTANCE")
+            } else {
+                format!(
+                    "new {type_name}({})",
+                    sample_fields(rec.fields(), ci, config)?
+                )
+            }
+        }
+        Type::Enum { name, .. } if !ci.is_external(type_) => {
+            let e = ci.get_enum_definition(name)?;
+            let type_name = JavaCodeOracle.find(type_).type_label(ci, config);
+            if e.is_flat() {
+                let variant = e.variants().first()?;
+                format!(
+                    "{type_name}.{}",
+                    JavaCodeOracle.enum_variant_name(variant.name())
+                )
+            } else {
+                e.variants().iter().find_map(|variant| {
+                    Some(format!(
+                        "new {type_name}.{}({})",
+                        variant.as_codetype().type_label(ci, config),
+                        sample_fields(variant.fields(), ci, config)?
+                    ))
+                })?
+            }
+        }
+        _ => return None,
+    };
+    Some(sample)
+}
+
+fn sample_fields(fields: &[Field], ci: &ComponentInterface, config: &Config) -> Option<String> {
+    Some(
+        fields
+            .iter()
+            .map(|field| sample_value(&field.as_type(), ci, config))
+            .collect::<Option<Vec<_>>>()?
+            .join(", "),
+    )
+}
+
+#[derive(Template)]
+#[template(syntax = "java", escape = "none", path = "ValueTestTemplate.java")]
+struct ValueTests<'a> {
+    config: &'a Config,
+    class_name: String,
+    namespace: &'a str,
+    // The name of each test, the value it checks and whether equal values print the same
+    samples: Vec<(String, String, bool)>,
+    // The types no value could be made up for
+    skipped: Vec<String>,
+}
+
 #[derive(Template)]
 #[template(syntax = "java", escape = "none", path = "wrapper.java")]
 pub struct JavaWrapper<'a> {
@@ -269,6 +1589,7 @@ impl<'a> JavaWrapper<'a> {
     pub fn initialization_fns(&self) -> Vec<String> {
         self.ci
             .iter_local_types()
+            .filter(|t| !is_excluded_type(&self.config, self.ci, t))
             .map(|t| JavaCodeOracle.find(t))
             .filter_map(|ct| ct.initialization_fn())
             .collect()
@@ -337,6 +1658,14 @@ impl JavaCodeOracle {
         )
     }
 
+    /// The class name of the type `nm`, its `type_renames` entry if it has one.
+    fn type_class_name(&self, ci: &ComponentInterface, config: &Config, nm: &str) -> String {
+        match config.type_renames.get(nm) {
+            Some(renamed) => fixup_keyword(renamed.clone()),
+            None => self.class_name(ci, nm),
+        }
+    }
+
     fn convert_error_suffix(&self, nm: &str) -> String {
         match nm.strip_suffix("Error") {
             None => nm.to_string(),
@@ -345,23 +1674,41 @@ impl JavaCodeOracle {
     }
 
     /// Get the idiomatic Java rendering of a function name.
-    fn fn_name(&self, nm: &str) -> String {
-        fixup_keyword(nm.to_string().to_lower_camel_case())
+    fn fn_name(&self, nm: &str, config: &Config) -> String {
+        fixup_keyword(config.naming_style.lower_camel_case(nm))
+    }
+
+    /// The method name of the function or method `nm`, its `function_renames` entry if it has one.
+    /// Methods and constructors are listed as `Type.method`, `owner` being the type, like in
+    /// `deprecated`.
+    fn callable_name(&self, owner: &str, nm: &str, config: &Config) -> String {
+        let renamed = if owner.is_empty() {
+            config.function_renames.get(nm)
+        } else {
+            config.function_renames.get(&format!("{owner}.{nm}"))
+        };
+        match renamed {
+            Some(renamed) => fixup_keyword(renamed.clone()),
+            None => self.fn_name(nm, config),
+        }
     }
 
     /// Get the idiomatic Java rendering of a variable name.
-    pub fn var_name(&self, nm: &str) -> String {
-        fixup_keyword(self.var_name_raw(nm))
+    pub fn var_name(&self, nm: &str, config: &Config) -> String {
+        fixup_keyword(self.var_name_raw(nm, config))
     }
 
     /// `var_name` without the reserved word alteration.  Useful for using in `@Structure.FieldOrder`.
-    pub fn var_name_raw(&self, nm: &str) -> String {
-        nm.to_string().to_lower_camel_case()
+    pub fn var_name_raw(&self, nm: &str, config: &Config) -> String {
+        config.naming_style.lower_camel_case(nm)
     }
 
     /// Get the idiomatic setter name for a variable.
-    pub fn setter(&self, nm: &str) -> String {
-        format!("set{}", fixup_keyword(nm.to_string().to_upper_camel_case()))
+    pub fn setter(&self, nm: &str, config: &Config) -> String {
+        format!(
+            "set{}",
+            fixup_keyword(config.naming_style.upper_camel_case(nm))
+        )
     }
 
     /// Get the idiomatic Java rendering of an individual enum variant.
@@ -370,13 +1717,13 @@ impl JavaCodeOracle {
     }
 
     /// Get the idiomatic Java rendering of an FFI callback function name
-    fn ffi_callback_name(&self, nm: &str) -> String {
-        format!("Uniffi{}", nm.to_upper_camel_case())
+    fn ffi_callback_name(&self, nm: &str, config: &Config) -> String {
+        format!("{}{}", config.ffi_name_prefix(), nm.to_upper_camel_case())
     }
 
     /// Get the idiomatic Java rendering of an FFI struct name
-    fn ffi_struct_name(&self, nm: &str) -> String {
-        format!("Uniffi{}", nm.to_upper_camel_case())
+    fn ffi_struct_name(&self, nm: &str, config: &Config) -> String {
+        format!("{}{}", config.ffi_name_prefix(), nm.to_upper_camel_case())
     }
 
     fn ffi_type_label_by_value(
@@ -390,7 +1737,9 @@ impl JavaCodeOracle {
             FfiType::RustBuffer(_) => {
                 format!("{}.ByValue", self.ffi_type_label(ffi_type, config, ci))
             }
-            FfiType::Struct(name) => format!("{}.UniffiByValue", self.ffi_struct_name(name)),
+            FfiType::Struct(name) => {
+                format!("{}.UniffiByValue", self.ffi_struct_name(name, config))
+            }
             _ if prefer_primitive => self.ffi_type_primitive(ffi_type, config, ci),
             _ => self.ffi_type_label(ffi_type, config, ci),
         }
@@ -410,7 +1759,7 @@ impl JavaCodeOracle {
             // Make callbacks function pointers nullable. This matches the semantics of a C
             // function pointer better and allows for `null` as a default value.
             // Everything is nullable in Java by default.
-            FfiType::Callback(name) => self.ffi_callback_name(name).to_string(),
+            FfiType::Callback(name) => self.ffi_callback_name(name, config).to_string(),
             _ => self.ffi_type_label_by_value(ffi_type, true, config, ci),
         }
     }
@@ -420,8 +1769,10 @@ impl JavaCodeOracle {
     /// This is used to:
     ///   - Set a default return value for error results
     ///   - Set a default for structs, which JNA sometimes requires
-    fn ffi_default_value(&self, ffi_type: &FfiType) -> String {
-        match ffi_type {
+    ///
+    /// `None` for types without one, which `check_ffi_types` rejects in FFI structs.
+    fn ffi_default_value(&self, ffi_type: &FfiType, config: &Config) -> Option<String> {
+        let default = match ffi_type {
             FfiType::UInt8 | FfiType::Int8 => "(byte)0".to_owned(),
             FfiType::UInt16 | FfiType::Int16 => "(short)0".to_owned(),
             FfiType::UInt32 | FfiType::Int32 => "0".to_owned(),
@@ -429,20 +1780,29 @@ impl JavaCodeOracle {
             FfiType::Float32 => "0.0f".to_owned(),
             FfiType::Float64 => "0.0".to_owned(),
             FfiType::RustArcPtr(_) => "Pointer.NULL".to_owned(),
-            FfiType::RustBuffer(_) => "new RustBuffer.ByValue()".to_owned(),
+            FfiType::RustBuffer(_) => {
+                format!("new {}.ByValue()", config.runtime_type("RustBuffer"))
+            }
             FfiType::Callback(_) => "null".to_owned(),
-            FfiType::RustCallStatus => "new UniffiRustCallStatus.ByValue()".to_owned(),
-            _ => unimplemented!("ffi_default_value: {ffi_type:?}"),
-        }
+            FfiType::RustCallStatus => {
+                format!(
+                    "new {}.ByValue()",
+                    config.runtime_type("UniffiRustCallStatus")
+                )
+            }
+            _ => return None,
+        };
+        Some(default)
     }
 
+    /// `None` for types that can't be passed by reference, which `check_ffi_types` rejects.
     fn ffi_type_label_by_reference(
         &self,
         ffi_type: &FfiType,
         config: &Config,
         ci: &ComponentInterface,
-    ) -> String {
-        match ffi_type {
+    ) -> Option<String> {
+        let label = match ffi_type {
             FfiType::Int32 | FfiType::UInt32 => "IntByReference".to_string(),
             FfiType::Int8
             | FfiType::UInt8
@@ -459,8 +1819,9 @@ impl JavaCodeOracle {
             FfiType::RustBuffer(_) | FfiType::Struct(_) => {
                 self.ffi_type_label(ffi_type, config, ci)
             }
-            _ => panic!("{ffi_type:?} by reference is not implemented"),
-        }
+            _ => return None,
+        };
+        Some(label)
     }
 
     fn ffi_type_label(
@@ -484,23 +1845,19 @@ impl JavaCodeOracle {
             FfiType::RustArcPtr(_) => "Pointer".to_string(),
             FfiType::RustBuffer(maybe_external) => match maybe_external {
                 Some(external_meta) if external_meta.module_path != ci.crate_name() => {
-                    format!(
-                        "{}.RustBuffer",
-                        config.external_type_package_name(
-                            &external_meta.module_path,
-                            &external_meta.name
-                        )
-                    )
+                    config.external_rust_buffer(&external_meta.module_path, &external_meta.name)
                 }
-                _ => "RustBuffer".to_string(),
+                _ => config.runtime_type("RustBuffer"),
             },
-            FfiType::RustCallStatus => "UniffiRustCallStatus.ByValue".to_string(),
-            FfiType::ForeignBytes => "ForeignBytes.ByValue".to_string(),
-            FfiType::Callback(name) => self.ffi_callback_name(name),
-            FfiType::Struct(name) => self.ffi_struct_name(name),
-            FfiType::Reference(inner) | FfiType::MutReference(inner) => {
-                self.ffi_type_label_by_reference(inner, config, ci)
+            FfiType::RustCallStatus => {
+                format!("{}.ByValue", config.runtime_type("UniffiRustCallStatus"))
             }
+            FfiType::ForeignBytes => format!("{}.ByValue", config.runtime_type("ForeignBytes")),
+            FfiType::Callback(name) => self.ffi_callback_name(name, config),
+            FfiType::Struct(name) => self.ffi_struct_name(name, config),
+            FfiType::Reference(inner) | FfiType::MutReference(inner) => self
+                .ffi_type_label_by_reference(inner, config, ci)
+                .expect("references are checked by check_ffi_types"),
             FfiType::VoidPointer => "Pointer".to_string(),
         }
     }
@@ -526,153 +1883,1153 @@ impl JavaCodeOracle {
             FfiType::RustArcPtr(_) => "Pointer".to_string(),
             FfiType::RustBuffer(maybe_external) => match maybe_external {
                 Some(external_meta) => {
-                    format!(
-                        "{}.RustBuffer",
-                        config.external_type_package_name(
-                            &external_meta.module_path,
-                            &external_meta.name
-                        )
-                    )
+                    config.external_rust_buffer(&external_meta.module_path, &external_meta.name)
                 }
-                None => "RustBuffer".to_string(),
+                None => config.runtime_type("RustBuffer"),
             },
-            FfiType::RustCallStatus => "UniffiRustCallStatus.ByValue".to_string(),
-            FfiType::ForeignBytes => "ForeignBytes.ByValue".to_string(),
-            FfiType::Callback(name) => self.ffi_callback_name(name),
-            FfiType::Struct(name) => self.ffi_struct_name(name),
-            FfiType::Reference(inner) | FfiType::MutReference(inner) => {
-                self.ffi_type_label_by_reference(inner, config, ci)
+            FfiType::RustCallStatus => {
+                format!("{}.ByValue", config.runtime_type("UniffiRustCallStatus"))
+            }
+            FfiType::ForeignBytes => format!("{}.ByValue", config.runtime_type("ForeignBytes")),
+            FfiType::Callback(name) => self.ffi_callback_name(name, config),
+            FfiType::Struct(name) => self.ffi_struct_name(name, config),
+            FfiType::Reference(inner) | FfiType::MutReference(inner) => self
+                .ffi_type_label_by_reference(inner, config, ci)
+                .expect("references are checked by check_ffi_types"),
+            FfiType::VoidPointer => "Pointer".to_string(),
+        }
+    }
+
+    /// Get the name of the interface and class name for an object.
+    ///
+    /// If we support callback interfaces, the interface name is the object name, and the class name is derived from that.
+    /// Otherwise, the class name is the object name and the interface name is derived from that.
+    ///
+    /// This split determines what types `FfiConverter.lower()` inputs.  If we support callback
+    /// interfaces, `lower` must lower anything that implements the interface.  If not, then lower
+    /// only lowers the concrete class.
+    ///
+    /// Trait objects (`Arc<dyn Trait>`) follow the same rules. Without foreign implementations
+    /// every value comes from Rust, so the class dispatches to whichever implementation Rust
+    /// returned and callers can program against the interface.
+    fn object_names(
+        &self,
+        ci: &ComponentInterface,
+        config: &Config,
+        obj: &Object,
+    ) -> (String, String) {
+        let class_name = self.type_class_name(ci, config, obj.name());
+        if obj.has_callback_interface() {
+            let impl_name = format!("{class_name}Impl");
+            (class_name, impl_name)
+        } else {
+            (format!("{class_name}Interface"), class_name)
+        }
+    }
+}
+
+trait AsCodeType {
+    fn as_codetype(&self) -> Box<dyn CodeType>;
+}
+
+// Workaround for the possibility of upstream additions of AsType breaking compilation
+// Downside to this is new types need to be manually added
+impl AsCodeType for Type {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        // Map `Type` instances to a `Box<dyn CodeType>` for that type.
+        //
+        // There is a companion match in `templates/Types.java` which performs a similar function for the
+        // template code.
+        //
+        //   - When adding additional types here, make sure to also add a match arm to the `Types.java` template.
+        //   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
+        match self.as_type() {
+            Type::UInt8 | Type::Int8 => Box::new(primitives::Int8CodeType),
+            Type::UInt16 | Type::Int16 => Box::new(primitives::Int16CodeType),
+            Type::UInt32 | Type::Int32 => Box::new(primitives::Int32CodeType),
+            Type::UInt64 | Type::Int64 => Box::new(primitives::Int64CodeType),
+            Type::Float32 => Box::new(primitives::Float32CodeType),
+            Type::Float64 => Box::new(primitives::Float64CodeType),
+            Type::Boolean => Box::new(primitives::BooleanCodeType),
+            Type::String => Box::new(primitives::StringCodeType),
+            Type::Bytes => Box::new(primitives::BytesCodeType),
+
+            Type::Timestamp => Box::new(miscellany::TimestampCodeType),
+            Type::Duration => Box::new(miscellany::DurationCodeType),
+
+            Type::Enum { name, .. } => Box::new(enum_::EnumCodeType::new(name.clone())),
+            Type::Object { name, imp, .. } => {
+                Box::new(object::ObjectCodeType::new(name.clone(), imp))
+            }
+            Type::Record { name, .. } => Box::new(record::RecordCodeType::new(name.clone())),
+            Type::CallbackInterface { name, .. } => Box::new(
+                callback_interface::CallbackInterfaceCodeType::new(name.clone()),
+            ),
+            Type::Optional { inner_type } => {
+                Box::new(compounds::OptionalCodeType::new((*inner_type).clone()))
+            }
+            Type::Sequence { inner_type } => {
+                Box::new(compounds::SequenceCodeType::new((*inner_type).clone()))
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => Box::new(compounds::MapCodeType::new(
+                (*key_type).clone(),
+                (*value_type).clone(),
+            )),
+            Type::Custom { name, .. } => Box::new(custom::CustomCodeType::new(name.clone())),
+        }
+    }
+}
+impl AsCodeType for &'_ Type {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        (*self).as_codetype()
+    }
+}
+impl AsCodeType for &&'_ Type {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        (**self).as_codetype()
+    }
+}
+impl AsCodeType for &'_ Field {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        self.as_type().as_codetype()
+    }
+}
+impl AsCodeType for &'_ uniffi_bindgen::interface::Enum {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        self.as_type().as_codetype()
+    }
+}
+impl AsCodeType for &'_ uniffi_bindgen::interface::Object {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        self.as_type().as_codetype()
+    }
+}
+impl AsCodeType for &'_ Box<uniffi_meta::Type> {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        self.as_type().as_codetype()
+    }
+}
+impl AsCodeType for &'_ Argument {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        self.as_type().as_codetype()
+    }
+}
+impl AsCodeType for &'_ uniffi_bindgen::interface::Record {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        self.as_type().as_codetype()
+    }
+}
+impl AsCodeType for &'_ uniffi_bindgen::interface::CallbackInterface {
+    fn as_codetype(&self) -> Box<dyn CodeType> {
+        self.as_type().as_codetype()
+    }
+}
+
+// A work around for #2392 - we can't handle functions with external errors.
+fn can_render_callable(callable: &dyn Callable, ci: &ComponentInterface) -> bool {
+    // can't handle external errors.
+    callable
+        .throws_type()
+        .map(|t| !ci.is_external(t))
+        .unwrap_or(true)
+}
+
+/// The fields of the `<Namespace>Constants` class written with `emit_constants`, as the Java type,
+/// name, literal and the enum constant they're the value of: the discriminant of each variant of
+/// the flat enums declaring a repr, e.g. `COLOR_RED` for `Color::Red`.
+fn enum_discriminant_constants(
+    config: &Config,
+    ci: &ComponentInterface,
+) -> Vec<(&'static str, String, String, String)> {
+    let mut constants = vec![];
+    for e in ci.enum_definitions() {
+        if !e.is_flat() || ci.is_name_used_as_error(e.name()) || config.excludes_item(e.name()) {
+            continue;
+        }
+        let java_type = match e.variant_discr_type() {
+            Some(Type::Int8 | Type::UInt8) => "byte",
+            Some(Type::Int16 | Type::UInt16) => "short",
+            Some(Type::Int32 | Type::UInt32) => "int",
+            Some(Type::Int64 | Type::UInt64) => "long",
+            _ => continue,
+        };
+        let type_name = JavaCodeOracle.class_name(ci, e.name());
+        for (index, variant) in e.variants().iter().enumerate() {
+            let variant_name = JavaCodeOracle.enum_variant_name(variant.name());
+            constants.push((
+                java_type,
+                format!("{}_{variant_name}", type_name.to_shouty_snake_case()),
+                filters::variant_discr_literal(e, &index)
+                    .expect("repr enums have int discriminants"),
+                format!("{type_name}#{variant_name}"),
+            ));
+        }
+    }
+    constants
+}
+
+/// The objects `quarkus_producers` generates CDI producers for, those with a synchronous primary
+/// constructor taking no arguments.
+fn cdi_producible_objects<'a>(config: &Config, ci: &'a ComponentInterface) -> Vec<&'a Object> {
+    ci.object_definitions()
+        .iter()
+        .filter(|obj| !config.excludes_item(obj.name()))
+        .filter(|obj| {
+            obj.primary_constructor().is_some_and(|cons| {
+                cons.arguments().is_empty() && !cons.is_async() && can_render_callable(&cons, ci)
+            })
+        })
+        .collect()
+}
+
+/// How the item `name` is deprecated, if it's listed in `deprecated`.
+///
+/// Methods and constructors are listed as `Type.method`, `owner` being the type, and everything
+/// else by its name, `owner` being empty.
+fn deprecation<'a>(config: &'a Config, owner: &str, name: &str) -> Option<&'a Deprecation> {
+    if owner.is_empty() {
+        config.deprecated.get(name)
+    } else {
+        config.deprecated.get(&format!("{owner}.{name}"))
+    }
+}
+
+/// The version an item was added in, for its javadoc `@since` tag: its `api_since` entry, the one
+/// of its type for methods and constructors, or the `"*"` one.
+fn api_since<'a>(config: &'a Config, owner: &str, name: &str) -> Option<&'a str> {
+    let own = if owner.is_empty() {
+        config.api_since.get(name)
+    } else {
+        config
+            .api_since
+            .get(&format!("{owner}.{name}"))
+            .or_else(|| config.api_since.get(owner))
+    };
+    own.or_else(|| config.api_since.get("*"))
+        .map(String::as_str)
+}
+
+/// The docstring of an item with the javadoc tags from `api_since` and `deprecated` appended,
+/// `None` when it has neither a docstring nor tags.
+fn tagged_docstring(
+    docstring: Option<&str>,
+    config: &Config,
+    owner: &str,
+    name: &str,
+) -> Option<String> {
+    let tags = api_since(config, owner, name)
+        .map(|since| format!("@since {since}"))
+        .into_iter()
+        .chain(deprecation(config, owner, name).map(Deprecation::javadoc_tag))
+        .collect::<Vec<_>>();
+    match (docstring, tags.is_empty()) {
+        (docstring, true) => docstring.map(str::to_string),
+        (Some(docstring), false) => {
+            Some(format!("{}\n\n{}", docstring.trim_end(), tags.join("\n")))
+        }
+        (None, false) => Some(tags.join("\n")),
+    }
+}
+
+/// `docstring` with a javadoc `@param` for each documented field, how the components of a Java
+/// `record` and the parameters of a constructor are documented. `None` when there is neither.
+fn params_docstring(docstring: Option<&str>, fields: &[Field], config: &Config) -> Option<String> {
+    let params = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(index, field)| {
+            let name = if field.name().is_empty() {
+                format!("v{}", index + 1)
+            } else {
+                JavaCodeOracle.var_name(field.name(), config)
+            };
+            let field_docstring = textwrap::dedent(field.docstring()?);
+            Some(format!("@param {name} {}", field_docstring.trim()))
+        })
+        .collect::<Vec<_>>();
+    match (docstring, params.is_empty()) {
+        (docstring, true) => docstring.map(str::to_string),
+        (Some(docstring), false) => {
+            Some(format!("{}\n\n{}", docstring.trim_end(), params.join("\n")))
+        }
+        (None, false) => Some(params.join("\n")),
+    }
+}
+
+/// The docstring of a record. Rendered as a Java `record` its components are documented on the
+/// record itself, with the `@param`s of the fields.
+fn record_docstring(config: &Config, rec: &Record) -> Option<String> {
+    if config.generate_immutable_records() || is_primitive_newtype(config, rec) {
+        params_docstring(rec.docstring(), rec.fields(), config)
+    } else {
+        rec.docstring().map(str::to_string)
+    }
+}
+
+// A UniFFI doc link, like `[Foo]`, `[`Foo`]` or `[Foo::bar]`: the optional backticks, and the path.
+static DOC_LINK: Lazy<regex::Regex> =
+    Lazy::new(|| regex::Regex::new(r"\[(`?)([A-Za-z_]\w*(?:::[A-Za-z_]\w*)?)`?\]").unwrap());
+
+/// `docstring` with its doc links turned into javadoc `{@link}`s to the Java names of what they
+/// link to, see `resolve_doc_link`. Links that don't resolve are left as they are.
+///
+/// Brackets right after a word, like `values[i]`, and markdown links, `[text](url)` or
+/// `[text][ref]`, aren't doc links.
+fn javadoc_links(docstring: &str, ci: &ComponentInterface, config: &Config) -> String {
+    let mut result = String::with_capacity(docstring.len());
+    let mut last = 0;
+    for captures in DOC_LINK.captures_iter(docstring) {
+        let link = captures.get(0).unwrap();
+        let after_word = docstring[..link.start()]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == ']');
+        let markdown_link = docstring[link.end()..].starts_with(['(', '[']);
+        let backticks = link.as_str().ends_with("`]") == !captures[1].is_empty();
+        if after_word || markdown_link || !backticks {
+            continue;
+        }
+        if let Some(target) = resolve_doc_link(&captures[2], ci, config) {
+            result.push_str(&docstring[last..link.start()]);
+            result.push_str(&format!("{{@link {target}}}"));
+            last = link.end();
+        }
+    }
+    result.push_str(&docstring[last..]);
+    result
+}
+
+/// The javadoc reference for the doc link `path`: a record, enum, object or callback interface,
+/// a function, which is a method of the namespace class, an object's method or constructor, or an
+/// enum variant.
+fn resolve_doc_link(path: &str, ci: &ComponentInterface, config: &Config) -> Option<String> {
+    let type_label = |name: &str| {
+        let type_ = ci.get_type(name)?;
+        matches!(
+            type_,
+            Type::Record { .. }
+                | Type::Enum { .. }
+                | Type::Object { .. }
+                | Type::CallbackInterface { .. }
+        )
+        .then(|| JavaCodeOracle.find(&type_).type_label(ci, config))
+    };
+    if config.excludes_item(path.split("::").next().unwrap()) {
+        return None;
+    }
+    match path.split_once("::") {
+        None => type_label(path).or_else(|| {
+            ci.get_function_definition(path)?;
+            Some(format!(
+                "{}#{}",
+                config.namespace_class_name(ci),
+                JavaCodeOracle.callable_name("", path, config)
+            ))
+        }),
+        Some((owner, member)) => {
+            let class_name = type_label(owner)?;
+            if let Some(obj) = ci.get_object_definition(owner) {
+                let is_member = obj.methods().iter().any(|meth| meth.name() == member)
+                    || obj
+                        .constructors()
+                        .iter()
+                        .any(|cons| cons.name() == member && !cons.is_primary_constructor());
+                is_member.then(|| {
+                    format!(
+                        "{class_name}#{}",
+                        JavaCodeOracle.callable_name(owner, member, config)
+                    )
+                })
+            } else {
+                let e = ci.get_enum_definition(owner)?;
+                let variant = e.variants().iter().find(|v| v.name() == member)?;
+                Some(if e.is_flat() {
+                    format!(
+                        "{class_name}#{}",
+                        JavaCodeOracle.enum_variant_name(variant.name())
+                    )
+                } else {
+                    format!(
+                        "{class_name}.{}",
+                        variant.as_codetype().type_label(ci, config)
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// The element type of a callable's trailing sequence argument, if it has one.
+///
+/// Only a trailing sequence can be turned into a varargs parameter without making overloads
+/// ambiguous.
+fn varargs_element_type(callable: &dyn Callable) -> Option<Type> {
+    match callable.arguments().last()?.as_type() {
+        Type::Sequence { inner_type } => Some(*inner_type),
+        _ => None,
+    }
+}
+
+/// The overloads leaving out trailing arguments with defaults, as the arguments each of them takes
+/// and the ones it passes the default of, longest first.
+///
+/// Only defaults that can be written as a Java literal can be left out, an argument with another
+/// default ends the trailing run. With `on_unsupported = "stub"` those can be left out too, see
+/// `unsupported_default`.
+fn default_overload_args<'a>(
+    callable: &'a dyn Callable,
+    config: &Config,
+) -> Vec<(Vec<&'a Argument>, Vec<&'a Argument>)> {
+    let arguments = callable.arguments();
+    let required = arguments
+        .iter()
+        .rposition(|arg| {
+            !arg.default_value().is_some_and(|default| {
+                config.on_unsupported == OnUnsupported::Stub
+                    || has_java_literal(&arg.as_type(), default)
+            })
+        })
+        .map_or(0, |last_required| last_required + 1);
+    (required..arguments.len())
+        .rev()
+        .map(|kept| (arguments[..kept].to_vec(), arguments[kept..].to_vec()))
+        .collect()
+}
+
+/// The first of `arguments` with a default that can't be written as a Java literal. Overloads
+/// leaving it out throw `UnsupportedOperationException` instead of delegating.
+fn unsupported_default<'a>(arguments: &[&'a Argument]) -> Option<&'a Argument> {
+    arguments.iter().copied().find(|arg| {
+        arg.default_value()
+            .is_some_and(|default| !has_java_literal(&arg.as_type(), default))
+    })
+}
+
+// Whether `literal` can be rendered for `type_` by `CodeType::literal`.
+fn has_java_literal(type_: &Type, literal: &Literal) -> bool {
+    match (type_, literal) {
+        (Type::Optional { .. }, Literal::None) => true,
+        (Type::Optional { inner_type }, Literal::Some { inner }) => {
+            has_java_literal(inner_type, inner)
+        }
+        (Type::Optional { inner_type }, _) => has_java_literal(inner_type, literal),
+        (Type::Boolean, Literal::Boolean(_)) | (Type::String, Literal::String(_)) => true,
+        (_, Literal::Int(..) | Literal::UInt(..) | Literal::Float(..)) => matches!(
+            type_,
+            Type::Int8
+                | Type::UInt8
+                | Type::Int16
+                | Type::UInt16
+                | Type::Int32
+                | Type::UInt32
+                | Type::Int64
+                | Type::UInt64
+                | Type::Float32
+                | Type::Float64
+        ),
+        (Type::Enum { .. }, Literal::Enum(..))
+        | (Type::Sequence { .. }, Literal::EmptySequence)
+        | (Type::Map { .. }, Literal::EmptyMap) => true,
+        _ => false,
+    }
+}
+
+/// Whether `arg` is declared as a `CharSequence` rather than a `String`, with
+/// `string_params_as_charsequence`, and so is lowered through `toString()`.
+fn is_char_sequence_param(arg: &Argument, config: &Config) -> bool {
+    config.string_params_as_charsequence && arg.as_type() == Type::String
+}
+
+/// Whether `type_` is an optional, for nested optionals.
+fn is_optional(type_: &Type) -> bool {
+    matches!(type_, Type::Optional { .. })
+}
+
+/// The `java.util` class of an optional of `inner_type` with `primitive_optionals`, and the method
+/// getting its value: `OptionalInt` for 32-bit integers, `OptionalLong` for 64-bit ones and
+/// `OptionalDouble` for `f64`. There are none for smaller integers, `f32` and booleans, those stay
+/// nullable.
+fn primitive_optional(config: &Config, inner_type: &Type) -> Option<(&'static str, &'static str)> {
+    if !config.primitive_optionals {
+        return None;
+    }
+    match inner_type {
+        Type::Int32 | Type::UInt32 => Some(("java.util.OptionalInt", "getAsInt")),
+        Type::Int64 | Type::UInt64 => Some(("java.util.OptionalLong", "getAsLong")),
+        Type::Float64 => Some(("java.util.OptionalDouble", "getAsDouble")),
+        _ => None,
+    }
+}
+
+/// Whether a varargs overload with elements of `element_type` needs `@SafeVarargs`, because the
+/// element type is generic and so the varargs array could be polluted.
+///
+/// `@SafeVarargs` is only allowed on methods that can't be overridden, so instance overloads are
+/// made `final` along with it.
+fn needs_safe_varargs(element_type: &Type, ci: &ComponentInterface, config: &Config) -> bool {
+    JavaCodeOracle
+        .find(element_type)
+        .type_label(ci, config)
+        .contains('<')
+}
+
+/// The `ByteBuffer` method writing a sequence element directly, for element types that don't need
+/// their `FfiConverter`.
+///
+/// These are the fixed size numbers, which are written as is. Unsigned types share the Java type
+/// and the bytes of their signed counterpart.
+fn bulk_put_method(type_: &Type) -> Option<&'static str> {
+    match type_ {
+        Type::Int8 | Type::UInt8 => Some("put"),
+        Type::Int16 | Type::UInt16 => Some("putShort"),
+        Type::Int32 | Type::UInt32 => Some("putInt"),
+        Type::Int64 | Type::UInt64 => Some("putLong"),
+        Type::Float32 => Some("putFloat"),
+        Type::Float64 => Some("putDouble"),
+        _ => None,
+    }
+}
+
+/// The JetBrains `@Contract` of a callable that never returns null, e.g. `"_, _ -> !null"`.
+///
+/// Async callables always return a future. Callables without a return value or returning an
+/// optional have no contract. UniFFI has no purity information, so `pure` is never set.
+fn contract_annotation(callable: &dyn Callable) -> Option<String> {
+    let returns_non_null = callable.is_async()
+        || callable
+            .return_type()
+            .is_some_and(|t| !matches!(t, Type::Optional { .. }));
+    returns_non_null.then(|| {
+        let args = vec!["_"; callable.arguments().len()].join(", ");
+        if args.is_empty() {
+            "-> !null".to_string()
+        } else {
+            format!("{args} -> !null")
+        }
+    })
+}
+
+/// Whether an object exports the `Hash` trait, which is used for its `hashCode`.
+///
+/// Value based `equals`/`hashCode` are only generated from the `Eq` and `Hash` traits, everything
+/// else keeps identity equality.
+fn exports_uniffi_hash(obj: &Object) -> bool {
+    obj.uniffi_traits()
+        .iter()
+        .any(|t| matches!(t, UniffiTrait::Hash { .. }))
+}
+
+/// Whether an object gets `equals`/`hashCode` based on the Rust instance it points to.
+///
+/// Objects exporting `Eq` or `Hash` keep using those instead.
+fn has_identity_equals(config: &Config, obj: &Object) -> bool {
+    config.object_identity_equals
+        && !obj
+            .uniffi_traits()
+            .iter()
+            .any(|t| matches!(t, UniffiTrait::Eq { .. } | UniffiTrait::Hash { .. }))
+}
+
+/// The `@ConstructorProperties` annotation of the record constructor, naming its parameters for
+/// bean frameworks with `constructor_properties`.
+fn constructor_properties(config: &Config, rec: &Record) -> Option<String> {
+    // MapStruct reads the constructor parameter names from it, they may not be in the class file.
+    let names = config
+        .constructor_properties
+        .or(config.mapstruct.then_some(PropertyNames::Java))?;
+    let properties = rec
+        .fields()
+        .iter()
+        .map(|field| match names {
+            PropertyNames::Java => {
+                java_string_literal(&JavaCodeOracle.var_name(field.name(), config))
             }
-            FfiType::VoidPointer => "Pointer".to_string(),
+            PropertyNames::Rust => java_string_literal(field.name()),
+        })
+        .collect::<Vec<_>>();
+    Some(format!(
+        "@java.beans.ConstructorProperties({{{}}})",
+        properties.join(", ")
+    ))
+}
+
+/// The Java expression returned by `toString()` of a record or enum variant.
+///
+/// `{typeName}` is replaced by the type name and `{fields}` by `name=value` pairs separated by
+/// `, `, the same way Java records print their components. Everything else is kept as is.
+fn tostring_expr(config: &Config, type_name: &str, fields: &[Field]) -> String {
+    let template = config.tostring_template();
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let name = if field.name().is_empty() {
+                format!("v{}", i + 1)
+            } else {
+                JavaCodeOracle.var_name(field.name(), config)
+            };
+            format!("\"{name}=\" + {name}")
+        })
+        .collect::<Vec<_>>()
+        .join(" + \", \" + ");
+    let fields = if fields.is_empty() {
+        "\"\"".to_string()
+    } else {
+        fields
+    };
+
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix("{typeName}") {
+            parts.push(java_string_literal(type_name));
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("{fields}") {
+            parts.push(format!("({fields})"));
+            rest = tail;
+        } else {
+            // Literal text runs up to the next `{` that could start a placeholder.
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|(_, c)| *c == '{')
+                .map_or(rest.len(), |(i, _)| i);
+            parts.push(java_string_literal(&rest[..end]));
+            rest = &rest[end..];
         }
     }
+    if parts.is_empty() {
+        parts.push("\"\"".to_string());
+    }
+    parts.join(" + ")
+}
 
-    /// Get the name of the interface and class name for an object.
-    ///
-    /// If we support callback interfaces, the interface name is the object name, and the class name is derived from that.
-    /// Otherwise, the class name is the object name and the interface name is derived from that.
-    ///
-    /// This split determines what types `FfiConverter.lower()` inputs.  If we support callback
-    /// interfaces, `lower` must lower anything that implements the interface.  If not, then lower
-    /// only lowers the concrete class.
-    fn object_names(&self, ci: &ComponentInterface, obj: &Object) -> (String, String) {
-        let class_name = self.class_name(ci, obj.name());
-        if obj.has_callback_interface() {
-            let impl_name = format!("{class_name}Impl");
-            (class_name, impl_name)
-        } else {
-            (format!("{class_name}Interface"), class_name)
+/// The version of this crate, which generated the bindings.
+fn bindgen_version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+/// Quote a string as a Java string literal.
+fn java_string_literal(s: &str) -> String {
+    let mut literal = String::with_capacity(s.len() + 2);
+    literal.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c => literal.push(c),
         }
     }
+    literal.push('"');
+    literal
 }
 
-trait AsCodeType {
-    fn as_codetype(&self) -> Box<dyn CodeType>;
+/// The fields of a record its `equals`/`hashCode` use, leaving out the ones configured in
+/// `equals_ignore_fields`.
+fn equality_fields(config: &Config, rec: &Record) -> Vec<Field> {
+    let ignored = config.equals_ignore_fields.get(rec.name());
+    rec.fields()
+        .iter()
+        .filter(|field| ignored.is_none_or(|ignored| !ignored.iter().any(|f| f == field.name())))
+        .cloned()
+        .collect()
 }
 
-// Workaround for the possibility of upstream additions of AsType breaking compilation
-// Downside to this is new types need to be manually added
-impl AsCodeType for Type {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        // Map `Type` instances to a `Box<dyn CodeType>` for that type.
-        //
-        // There is a companion match in `templates/Types.java` which performs a similar function for the
-        // template code.
-        //
-        //   - When adding additional types here, make sure to also add a match arm to the `Types.java` template.
-        //   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
-        match self.as_type() {
-            Type::UInt8 | Type::Int8 => Box::new(primitives::Int8CodeType),
-            Type::UInt16 | Type::Int16 => Box::new(primitives::Int16CodeType),
-            Type::UInt32 | Type::Int32 => Box::new(primitives::Int32CodeType),
-            Type::UInt64 | Type::Int64 => Box::new(primitives::Int64CodeType),
-            Type::Float32 => Box::new(primitives::Float32CodeType),
-            Type::Float64 => Box::new(primitives::Float64CodeType),
-            Type::Boolean => Box::new(primitives::BooleanCodeType),
-            Type::String => Box::new(primitives::StringCodeType),
-            Type::Bytes => Box::new(primitives::BytesCodeType),
+/// Whether `rec` is generated as a `record` holding a Java primitive, with `newtype_optimization`:
+/// it has a single field, of a number or boolean type.
+fn is_primitive_newtype(config: &Config, rec: &Record) -> bool {
+    config.newtype_optimization
+        && matches!(rec.fields(), [field] if primitive_type_name(&field.as_type()).is_some())
+}
 
-            Type::Timestamp => Box::new(miscellany::TimestampCodeType),
-            Type::Duration => Box::new(miscellany::DurationCodeType),
+/// The Java primitive for a number or boolean type, its type label being the boxed class.
+fn primitive_type_name(type_: &Type) -> Option<&'static str> {
+    match type_ {
+        Type::Int8 | Type::UInt8 => Some("byte"),
+        Type::Int16 | Type::UInt16 => Some("short"),
+        Type::Int32 | Type::UInt32 => Some("int"),
+        Type::Int64 | Type::UInt64 => Some("long"),
+        Type::Float32 => Some("float"),
+        Type::Float64 => Some("double"),
+        Type::Boolean => Some("boolean"),
+        _ => None,
+    }
+}
 
-            Type::Enum { name, .. } => Box::new(enum_::EnumCodeType::new(name.clone())),
-            Type::Object { name, imp, .. } => {
-                Box::new(object::ObjectCodeType::new(name.clone(), imp))
-            }
-            Type::Record { name, .. } => Box::new(record::RecordCodeType::new(name.clone())),
-            Type::CallbackInterface { name, .. } => Box::new(
-                callback_interface::CallbackInterfaceCodeType::new(name.clone()),
-            ),
-            Type::Optional { inner_type } => {
-                Box::new(compounds::OptionalCodeType::new((*inner_type).clone()))
-            }
-            Type::Sequence { inner_type } => {
-                Box::new(compounds::SequenceCodeType::new((*inner_type).clone()))
+/// The Java type of a record field, a primitive for the field of a `is_primitive_newtype` record.
+fn record_field_type(
+    ci: &ComponentInterface,
+    config: &Config,
+    rec: &Record,
+    field: &Field,
+) -> String {
+    primitive_type_name(&field.as_type())
+        .filter(|_| is_primitive_newtype(config, rec))
+        .map(str::to_string)
+        .unwrap_or_else(|| field.as_codetype().type_label(ci, config))
+}
+
+/// The value the `Builder` of a record starts a field with, the default of the Rust field when it
+/// can be written as a Java literal, see `record_builders`.
+fn builder_default(ci: &ComponentInterface, config: &Config, field: &Field) -> Option<String> {
+    field
+        .default_value()
+        .filter(|default| has_java_literal(&field.as_type(), default))
+        .map(|default| field.as_codetype().literal(default, ci, config))
+}
+
+/// What the getter of a record field returns with `unmodifiable_collections`: the list or map
+/// wrapped in an unmodifiable view, so callers can't change the record through it. `None` for
+/// fields that aren't collections.
+fn unmodifiable_getter_value(config: &Config, field: &Field) -> Option<String> {
+    if !config.unmodifiable_collections {
+        return None;
+    }
+    let field_var_name = JavaCodeOracle.var_name(field.name(), config);
+    let (wrapper, nullable) = match field.as_type() {
+        Type::Sequence { .. } => ("unmodifiableList", false),
+        Type::Map { .. } => ("unmodifiableMap", false),
+        Type::Optional { inner_type } => match *inner_type {
+            Type::Sequence { .. } => ("unmodifiableList", true),
+            Type::Map { .. } => ("unmodifiableMap", true),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let wrapped = format!("Collections.{wrapper}(this.{field_var_name})");
+    Some(if nullable {
+        format!("this.{field_var_name} == null ? null : {wrapped}")
+    } else {
+        wrapped
+    })
+}
+
+/// The value of a record field in its `copy()` with `record_copy`.
+fn field_copy_value(ci: &ComponentInterface, config: &Config, field: &Field) -> String {
+    let field_var_name = format!("this.{}", JavaCodeOracle.var_name(field.name(), config));
+    deep_copy_value(ci, config, &field.as_type(), &field_var_name, 0).unwrap_or(field_var_name)
+}
+
+/// An expression deep copying `value` of `type_`, `None` if the value can be shared because it's
+/// immutable, or a handle like an object. Lists, maps and byte arrays are copied, and records with
+/// their own `copy()`. Every copy keeps `null`, for optionals.
+fn deep_copy_value(
+    ci: &ComponentInterface,
+    config: &Config,
+    type_: &Type,
+    value: &str,
+    depth: usize,
+) -> Option<String> {
+    // Lambda parameters can't shadow the ones of enclosing lambdas.
+    let item = format!("v{depth}");
+    match type_ {
+        // A configured `bytes_type` isn't an array, and is treated as immutable.
+        Type::Bytes if config.bytes_type.is_none() => {
+            Some(format!("{value} == null ? null : {value}.clone()"))
+        }
+        Type::Record { name, .. }
+            if !ci.is_external(type_)
+                && ci
+                    .get_record_definition(name)
+                    .is_some_and(|rec| rec.has_fields()) =>
+        {
+            Some(format!("{value} == null ? null : {value}.copy()"))
+        }
+        Type::Sequence { inner_type } => {
+            let item_copy = deep_copy_value(ci, config, inner_type, &item, depth + 1);
+            Some(format!(
+                "UniffiDeepCopy.list({value}, {item} -> {})",
+                item_copy.as_deref().unwrap_or(&item)
+            ))
+        }
+        Type::Map { value_type, .. } => {
+            let item_copy = deep_copy_value(ci, config, value_type, &item, depth + 1);
+            Some(format!(
+                "UniffiDeepCopy.map({value}, {item} -> {})",
+                item_copy.as_deref().unwrap_or(&item)
+            ))
+        }
+        // Nested optionals are a `java.util.Optional` inside, see `OptionalTemplate.java`.
+        Type::Optional { inner_type } if is_optional(inner_type) => {
+            let item_copy = deep_copy_value(ci, config, inner_type, &item, depth + 1)?;
+            Some(format!(
+                "{value} == null ? null : {value}.map({item} -> {item_copy})"
+            ))
+        }
+        Type::Optional { inner_type } => deep_copy_value(ci, config, inner_type, value, depth),
+        _ => None,
+    }
+}
+
+/// Whether a record field is compared with `UniffiDeepEquality` in `equals` and `hashCode`, because
+/// `deep_equals` is set and it holds byte arrays, which Java compares by identity.
+fn needs_deep_equality(config: &Config, field: &Field) -> bool {
+    config.deep_equals && config.bytes_type.is_none() && contains_bytes(&field.as_type())
+}
+
+/// Whether any of the fields are compared with `UniffiDeepEquality`, see `needs_deep_equality`.
+fn uses_deep_equality(config: &Config, fields: &[Field]) -> bool {
+    fields
+        .iter()
+        .any(|field| needs_deep_equality(config, field))
+}
+
+/// Whether converters of `type_` write into a growing buffer with `buffer_growth_factor`. Writing
+/// starts over in a bigger buffer when it runs out of space, so types that lower object or callback
+/// interface handles keep precomputing their size, lowering a handle twice would leak it.
+fn grows_write_buffer(config: &Config, ci: &ComponentInterface, type_: &Type) -> bool {
+    fn writes_handles(ci: &ComponentInterface, type_: &Type, seen: &mut HashSet<String>) -> bool {
+        match type_ {
+            Type::Object { .. } | Type::CallbackInterface { .. } => true,
+            Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+                writes_handles(ci, inner_type, seen)
             }
             Type::Map {
                 key_type,
                 value_type,
-            } => Box::new(compounds::MapCodeType::new(
-                (*key_type).clone(),
-                (*value_type).clone(),
-            )),
-            Type::Custom { name, .. } => Box::new(custom::CustomCodeType::new(name.clone())),
+            } => writes_handles(ci, key_type, seen) || writes_handles(ci, value_type, seen),
+            Type::Custom { builtin, .. } => writes_handles(ci, builtin, seen),
+            Type::Record { name, .. } => match ci.get_record_definition(name) {
+                Some(rec) => {
+                    seen.insert(name.clone())
+                        && rec
+                            .fields()
+                            .iter()
+                            .any(|field| writes_handles(ci, &field.as_type(), seen))
+                }
+                // External records come from another crate, assume the worst.
+                None => true,
+            },
+            Type::Enum { name, .. } => match ci.get_enum_definition(name) {
+                Some(e) => {
+                    seen.insert(name.clone())
+                        && e.variants()
+                            .iter()
+                            .flat_map(|variant| variant.fields())
+                            .any(|field| writes_handles(ci, &field.as_type(), seen))
+                }
+                None => true,
+            },
+            _ => false,
         }
     }
+    config.buffer_growth_factor.is_some() && !writes_handles(ci, type_, &mut HashSet::new())
 }
-impl AsCodeType for &'_ Type {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        (*self).as_codetype()
+
+fn contains_bytes(type_: &Type) -> bool {
+    match type_ {
+        Type::Bytes => true,
+        Type::Optional { inner_type } | Type::Sequence { inner_type } => contains_bytes(inner_type),
+        Type::Map {
+            key_type,
+            value_type,
+        } => contains_bytes(key_type) || contains_bytes(value_type),
+        _ => false,
     }
 }
-impl AsCodeType for &&'_ Type {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        (**self).as_codetype()
-    }
+
+/// The name of the static comparator factory for a record field, e.g. `comparingByName`.
+fn comparator_method_name(config: &Config, field: &Field) -> String {
+    format!(
+        "comparingBy{}",
+        config.naming_style.upper_camel_case(field.name())
+    )
 }
-impl AsCodeType for &'_ Field {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        self.as_type().as_codetype()
+
+/// The name of the `Builder` method setting `field`, e.g. `withName`. With `mapstruct` it's the
+/// property name, like `name`, MapStruct takes the name of other fluent setters as the property.
+fn builder_method_name(config: &Config, field: &Field) -> String {
+    if config.mapstruct {
+        JavaCodeOracle.var_name(field.name(), config)
+    } else {
+        format!("with{}", config.naming_style.upper_camel_case(field.name()))
     }
 }
-impl AsCodeType for &'_ uniffi_bindgen::interface::Enum {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        self.as_type().as_codetype()
-    }
+
+/// The constants of the property names of a record with `mapstruct`, e.g. `FIRST_NAME` for
+/// `firstName`.
+fn property_name_constants(config: &Config, rec: &Record) -> Vec<(String, String)> {
+    rec.fields()
+        .iter()
+        .map(|field| {
+            (
+                field.name().to_shouty_snake_case(),
+                JavaCodeOracle.var_name_raw(field.name(), config),
+            )
+        })
+        .collect()
 }
-impl AsCodeType for &'_ uniffi_bindgen::interface::Object {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        self.as_type().as_codetype()
-    }
+
+/// The key of `field` in the maps of `record_map_conversion`, its Java name.
+fn map_key(config: &Config, field: &Field) -> String {
+    JavaCodeOracle.var_name_raw(field.name(), config)
 }
-impl AsCodeType for &'_ Box<uniffi_meta::Type> {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        self.as_type().as_codetype()
+
+/// The records `record_map_conversion` converts to maps, those that have `toMap()` and `fromMap`.
+fn is_map_record(ci: &ComponentInterface, type_: &Type) -> bool {
+    match type_ {
+        Type::Record { name, .. } => {
+            !ci.is_external(type_)
+                && ci
+                    .get_record_definition(name)
+                    .is_some_and(|rec| rec.has_fields())
+        }
+        _ => false,
     }
 }
-impl AsCodeType for &'_ Argument {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        self.as_type().as_codetype()
+
+/// The value `toMap()` puts for `field` with `record_map_conversion`. Nested records are maps too,
+/// optional ones and the ones in lists included, everything else is put as it is.
+fn to_map_value(ci: &ComponentInterface, config: &Config, field: &Field) -> String {
+    fn map_value(ci: &ComponentInterface, type_: &Type, value: &str) -> String {
+        match type_ {
+            _ if is_map_record(ci, type_) => format!("{value}.toMap()"),
+            Type::Optional { inner_type } if is_map_record(ci, inner_type) => {
+                format!("{value} == null ? null : {value}.toMap()")
+            }
+            Type::Sequence { inner_type } if map_record_list(ci, inner_type) => format!(
+                "{value}.stream().map(v -> {}).toList()",
+                map_value(ci, inner_type, "v")
+            ),
+            _ => value.to_string(),
+        }
     }
+    let field_var_name = JavaCodeOracle.var_name(field.name(), config);
+    map_value(ci, &field.as_type(), &format!("this.{field_var_name}"))
 }
-impl AsCodeType for &'_ uniffi_bindgen::interface::Record {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        self.as_type().as_codetype()
+
+// Whether a list of `element_type` is a list of maps in `toMap()`, for records and optional ones.
+fn map_record_list(ci: &ComponentInterface, element_type: &Type) -> bool {
+    match element_type {
+        Type::Optional { inner_type } => is_map_record(ci, inner_type),
+        _ => is_map_record(ci, element_type),
     }
 }
-impl AsCodeType for &'_ uniffi_bindgen::interface::CallbackInterface {
-    fn as_codetype(&self) -> Box<dyn CodeType> {
-        self.as_type().as_codetype()
+
+/// How `fromMap` of the record `type_name` reads `field` with `record_map_conversion`, checking
+/// that it's there unless it's optional, and that it has the type of the field.
+fn from_map_value(
+    ci: &ComponentInterface,
+    config: &Config,
+    type_name: &str,
+    field: &Field,
+) -> String {
+    let key = map_key(config, field);
+    let type_ = field.as_type();
+    let (inner, optional) = match &type_ {
+        Type::Optional { inner_type } => (&**inner_type, true),
+        _ => (&type_, false),
+    };
+    let record_type_name = |type_: &Type| JavaCodeOracle.find(type_).type_label(ci, config);
+    match inner {
+        _ if is_map_record(ci, inner) => format!(
+            "UniffiRecordMaps.record(map, \"{type_name}\", \"{key}\", {}::fromMap, {optional})",
+            record_type_name(inner)
+        ),
+        Type::Sequence { inner_type } if !optional && map_record_list(ci, inner_type) => {
+            let element = match &**inner_type {
+                Type::Optional { inner_type } => &**inner_type,
+                element => element,
+            };
+            format!(
+                "UniffiRecordMaps.records(map, \"{type_name}\", \"{key}\", {}::fromMap)",
+                record_type_name(element)
+            )
+        }
+        _ => {
+            let label = record_type_name(&type_);
+            let class = label.split('<').next().unwrap_or(&label);
+            let value = format!(
+                "UniffiRecordMaps.get(map, \"{type_name}\", \"{key}\", {class}.class, {optional})"
+            );
+            match primitive_optional(config, inner).filter(|_| optional) {
+                // A missing primitive optional is empty rather than `null`.
+                Some((class, _)) => format!("Objects.requireNonNullElse({value}, {class}.empty())"),
+                None => value,
+            }
+        }
     }
 }
 
-// A work around for #2392 - we can't handle functions with external errors.
-fn can_render_callable(callable: &dyn Callable, ci: &ComponentInterface) -> bool {
-    // can't handle external errors.
-    callable
-        .throws_type()
-        .map(|t| !ci.is_external(t))
-        .unwrap_or(true)
+/// The name of the bean getter of `field` generated with `mapstruct`, e.g. `getName`.
+fn bean_getter_name(config: &Config, field: &Field) -> String {
+    format!("get{}", config.naming_style.upper_camel_case(field.name()))
+}
+
+/// The `Comparator` ordering records of `type_name` by `field`, for fields with a natural order.
+///
+/// Unsigned numbers are compared unsigned, and empty optionals sort according to
+/// `comparator_nulls`. Fields without a natural order, like records or bytes, get no comparator.
+fn field_comparator(
+    config: &Config,
+    ci: &ComponentInterface,
+    type_name: &str,
+    field: &Field,
+) -> Option<String> {
+    let (inner, optional) = match field.as_type() {
+        // `OptionalInt` and the like aren't `Comparable`.
+        Type::Optional { inner_type } if primitive_optional(config, &inner_type).is_some() => {
+            return None;
+        }
+        Type::Optional { inner_type } => (*inner_type, true),
+        type_ => (type_, false),
+    };
+    let inner_name = JavaCodeOracle.find(&inner).type_label(ci, config);
+    let compare = match &inner {
+        Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64 => {
+            Some(format!("{inner_name}::compareUnsigned"))
+        }
+        Type::Int8
+        | Type::Int16
+        | Type::Int32
+        | Type::Int64
+        | Type::Float32
+        | Type::Float64
+        | Type::Boolean
+        | Type::String
+        | Type::Timestamp
+        | Type::Duration => None,
+        Type::Enum { name, .. } if ci.get_enum_definition(name).is_some_and(|e| e.is_flat()) => {
+            None
+        }
+        _ => return None,
+    };
+    let accessor = format!(
+        "{type_name}::{}",
+        JavaCodeOracle.var_name(field.name(), config)
+    );
+    Some(if optional {
+        let nulls = match config.comparator_nulls {
+            NullsOrder::First => "nullsFirst",
+            NullsOrder::Last => "nullsLast",
+        };
+        let compare = compare.unwrap_or_else(|| format!("Comparator.<{inner_name}>naturalOrder()"));
+        format!("Comparator.comparing({accessor}, Comparator.<{inner_name}>{nulls}({compare}))")
+    } else {
+        match compare {
+            Some(compare) => format!("Comparator.comparing({accessor}, {compare})"),
+            None => format!("Comparator.comparing({accessor})"),
+        }
+    })
+}
+
+/// The interfaces a record or enum declares, `AutoCloseable` first if it holds object references,
+/// then `android.os.Parcelable` with `android_parcelable`, followed by the ones configured in
+/// `implements`.
+fn implemented_interfaces(config: &Config, ci: &ComponentInterface, type_: &Type) -> Vec<String> {
+    let configured = match type_ {
+        Type::Record { name, .. } | Type::Enum { name, .. } => config.implements.get(name),
+        _ => None,
+    };
+    ci.item_contains_object_references(type_)
+        .then(|| "AutoCloseable".to_string())
+        .into_iter()
+        .chain(is_parcelable(config, ci, type_).then(|| "android.os.Parcelable".to_string()))
+        .chain(match type_ {
+            // Java enums are already `Comparable`.
+            Type::Record { name, .. } if config.is_comparable(name) => Some(format!(
+                "Comparable<{}>",
+                JavaCodeOracle.find(type_).type_label(ci, config)
+            )),
+            _ => None,
+        })
+        .chain(
+            configured
+                .into_iter()
+                .flatten()
+                .map(|interface| match interface.rsplit_once('.') {
+                    Some((_, simple_name)) if !config.import_clashes(interface, ci) => {
+                        simple_name.to_string()
+                    }
+                    _ => interface.clone(),
+                }),
+        )
+        .collect()
+}
+
+/// The `Comparator` behind `compareTo` of records listed in `comparable`, chaining the comparators
+/// of their ordering fields. `None` for other records.
+fn natural_order(
+    config: &Config,
+    ci: &ComponentInterface,
+    type_name: &str,
+    rec: &Record,
+) -> Option<String> {
+    let comparators = config
+        .comparable_fields(rec)?
+        .into_iter()
+        .filter_map(|field_name| rec.fields().iter().find(|f| f.name() == field_name))
+        .filter_map(|field| field_comparator(config, ci, type_name, field))
+        .collect::<Vec<_>>();
+    let (first, rest) = comparators.split_first()?;
+    Some(rest.iter().fold(first.clone(), |order, comparator| {
+        format!("{order}\n            .thenComparing({comparator})")
+    }))
+}
+
+/// The `Comparator` of a flat enum listed in `comparable` ordering its variants by discriminant,
+/// like Rust's derived `Ord`. `UNKNOWN` sorts last.
+fn discriminant_order(config: &Config, ci: &ComponentInterface, e: &Enum) -> Option<String> {
+    if !config.is_comparable(e.name()) {
+        return None;
+    }
+    let type_name = JavaCodeOracle.class_name(ci, e.name());
+    Some(match e.variant_discr_type() {
+        // Variants are declared in discriminant order, which `compareTo` already follows.
+        None => format!("Comparator.<{type_name}>naturalOrder()"),
+        Some(discr_type) => {
+            let discr_name = JavaCodeOracle.find(discr_type).type_label(ci, config);
+            let compare = match discr_type {
+                Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64 => {
+                    format!("{discr_name}::compareUnsigned")
+                }
+                _ => format!("Comparator.<{discr_name}>naturalOrder()"),
+            };
+            format!(
+                "Comparator.comparing(({type_name} variant) -> variant.value, Comparator.nullsLast({compare}))"
+            )
+        }
+    })
+}
+
+/// Whether a record or enum implements `android.os.Parcelable`, with `android_parcelable`. Ones
+/// holding objects don't, a native handle can't be parceled.
+fn is_parcelable(config: &Config, ci: &ComponentInterface, type_: &Type) -> bool {
+    let is_record_or_enum = match type_ {
+        Type::Record { .. } => true,
+        Type::Enum { name, .. } => !ci.is_name_used_as_error(name),
+        _ => false,
+    };
+    config.android
+        && config.android_parcelable
+        && is_record_or_enum
+        && !ci.item_contains_object_references(type_)
+}
+
+/// The simple names of the classes generated for the local types of `ci`.
+fn local_class_names(ci: &ComponentInterface, config: &Config) -> HashSet<String> {
+    ci.iter_local_types()
+        .flat_map(|type_| match type_ {
+            Type::Object { name, .. } => ci
+                .get_object_definition(name)
+                .map(|obj| {
+                    let (interface_name, impl_name) = JavaCodeOracle.object_names(ci, config, obj);
+                    vec![interface_name, impl_name]
+                })
+                .unwrap_or_default(),
+            Type::Record { name, .. }
+            | Type::Enum { name, .. }
+            | Type::CallbackInterface { name, .. }
+            | Type::Custom { name, .. } => vec![JavaCodeOracle.type_class_name(ci, config, name)],
+            _ => vec![],
+        })
+        .collect()
 }
 
 mod filters {
@@ -689,6 +3046,19 @@ mod filters {
         Ok(as_ct.as_codetype().type_label(ci, config))
     }
 
+    /// The Java type of a parameter, which is `type_name` except for the `String`s taken as a
+    /// `CharSequence` with `string_params_as_charsequence`.
+    pub(super) fn param_type_name(
+        arg: &(impl AsCodeType + AsType),
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        if config.string_params_as_charsequence && arg.as_type() == Type::String {
+            return Ok("CharSequence".to_string());
+        }
+        Ok(arg.as_codetype().type_label(ci, config))
+    }
+
     pub(super) fn canonical_name(as_ct: &impl AsCodeType) -> Result<String, askama::Error> {
         Ok(as_ct.as_codetype().canonical_name())
     }
@@ -760,12 +3130,18 @@ mod filters {
         ))
     }
 
-    // Get the idiomatic Java rendering of an integer.
+    // Get the idiomatic Java rendering of an integer, of exactly the Java type of `t`.
     fn int_literal(t: &Option<Type>, base10: String) -> Result<String, askama::Error> {
         if let Some(t) = t {
             match t {
-                Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64 => Ok(base10),
-                Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64 => Ok(base10 + "u"),
+                Type::Int8
+                | Type::Int16
+                | Type::Int32
+                | Type::Int64
+                | Type::UInt8
+                | Type::UInt16
+                | Type::UInt32
+                | Type::UInt64 => Ok(primitives::typed_number(t, base10)),
                 _ => Err(to_askama_error("Only ints are supported.")),
             }
         } else {
@@ -801,8 +3177,10 @@ mod filters {
         Ok(JavaCodeOracle.ffi_type_label_for_ffi_struct(type_, config, ci))
     }
 
-    pub fn ffi_default_value(type_: FfiType) -> Result<String, askama::Error> {
-        Ok(JavaCodeOracle.ffi_default_value(&type_))
+    pub fn ffi_default_value(type_: FfiType, config: &Config) -> Result<String, askama::Error> {
+        JavaCodeOracle
+            .ffi_default_value(&type_, config)
+            .ok_or_else(|| to_askama_error(&format!("{type_:?} has no default value in Java")))
     }
 
     /// Get the idiomatic Java rendering of a class name.
@@ -813,24 +3191,29 @@ mod filters {
         Ok(JavaCodeOracle.class_name(ci, nm.as_ref()))
     }
 
-    /// Get the idiomatic Java rendering of a function name.
-    pub fn fn_name<S: AsRef<str>>(nm: S) -> Result<String, askama::Error> {
-        Ok(JavaCodeOracle.fn_name(nm.as_ref()))
+    /// The method name of a function, or of a method or constructor of `owner`, see
+    /// `function_renames`.
+    pub fn callable_name<S: AsRef<str>>(
+        nm: S,
+        owner: &str,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(JavaCodeOracle.callable_name(owner, nm.as_ref(), config))
     }
 
     /// Get the idiomatic Java rendering of a variable name.
-    pub fn var_name<S: AsRef<str>>(nm: S) -> Result<String, askama::Error> {
-        Ok(JavaCodeOracle.var_name(nm.as_ref()))
+    pub fn var_name<S: AsRef<str>>(nm: S, config: &Config) -> Result<String, askama::Error> {
+        Ok(JavaCodeOracle.var_name(nm.as_ref(), config))
     }
 
     /// Get the idiomatic Java rendering of a variable name, without altering reserved words.
-    pub fn var_name_raw<S: AsRef<str>>(nm: S) -> Result<String, askama::Error> {
-        Ok(JavaCodeOracle.var_name_raw(nm.as_ref()))
+    pub fn var_name_raw<S: AsRef<str>>(nm: S, config: &Config) -> Result<String, askama::Error> {
+        Ok(JavaCodeOracle.var_name_raw(nm.as_ref(), config))
     }
 
     /// Get the idiomatic Java setter method name.
-    pub fn setter<S: AsRef<str>>(nm: S) -> Result<String, askama::Error> {
-        Ok(JavaCodeOracle.setter(nm.as_ref()))
+    pub fn setter<S: AsRef<str>>(nm: S, config: &Config) -> Result<String, askama::Error> {
+        Ok(JavaCodeOracle.setter(nm.as_ref(), config))
     }
 
     /// Get a String representing the name used for an individual enum variant.
@@ -844,20 +3227,150 @@ mod filters {
     }
 
     /// Get the idiomatic Java rendering of an FFI callback function name
-    pub fn ffi_callback_name<S: AsRef<str>>(nm: S) -> Result<String, askama::Error> {
-        Ok(JavaCodeOracle.ffi_callback_name(nm.as_ref()))
+    pub fn ffi_callback_name<S: AsRef<str>>(
+        nm: S,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        Ok(JavaCodeOracle.ffi_callback_name(nm.as_ref(), config))
+    }
+
+    /// Reference a shared runtime type, see `Config::runtime_type`
+    pub fn runtime_type<S: AsRef<str>>(nm: S, config: &Config) -> Result<String, askama::Error> {
+        Ok(config.runtime_type(nm.as_ref()))
+    }
+
+    /// A generator-internal identifier, see `Config::internal_name`
+    pub fn internal<S: AsRef<str>>(nm: S, config: &Config) -> Result<String, askama::Error> {
+        Ok(config.internal_name(nm.as_ref()))
     }
 
     /// Get the idiomatic Java rendering of an FFI struct name
-    pub fn ffi_struct_name<S: AsRef<str>>(nm: S) -> Result<String, askama::Error> {
-        Ok(JavaCodeOracle.ffi_struct_name(nm.as_ref()))
+    pub fn ffi_struct_name<S: AsRef<str>>(nm: S, config: &Config) -> Result<String, askama::Error> {
+        Ok(JavaCodeOracle.ffi_struct_name(nm.as_ref(), config))
+    }
+
+    /// The Java names of the records `rec` gets a `from(Other)` for, see `record_conversions`.
+    ///
+    /// The other record must have the same field names with the same types, in any order.
+    pub fn conversion_sources(
+        rec: &Record,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<Vec<String>, askama::Error> {
+        let Some(sources) = config.record_conversions.get(rec.name()) else {
+            return Ok(vec![]);
+        };
+        sources
+            .iter()
+            .map(|source| {
+                let other = ci.get_record_definition(source).ok_or_else(|| {
+                    to_askama_error(&format!(
+                        "record_conversions: {source} isn't a record defined by this crate"
+                    ))
+                })?;
+                let same_shape = rec.fields().len() == other.fields().len()
+                    && rec.fields().iter().all(|field| {
+                        other.fields().iter().any(|other_field| {
+                            other_field.name() == field.name()
+                                && other_field.as_type() == field.as_type()
+                        })
+                    });
+                if !same_shape {
+                    return Err(to_askama_error(&format!(
+                        "record_conversions: {source} doesn't have the same fields as {}",
+                        rec.name()
+                    )));
+                }
+                type_name(&other.as_type(), ci, config)
+            })
+            .collect()
+    }
+
+    /// The default value of an argument as a Java literal, see `default_overload_args`.
+    pub fn default_literal(
+        arg: &Argument,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        let default = arg
+            .default_value()
+            .ok_or_else(|| to_askama_error(&format!("{} has no default", arg.name())))?;
+        Ok(JavaCodeOracle
+            .find(&arg.as_type())
+            .literal(default, ci, config))
+    }
+
+    /// The Java name of the method a callback interface listed in `flow_publishers` publishes the
+    /// argument of, and the type of that argument, see `callback_interface::flow_publisher_method`.
+    pub fn flow_publisher(
+        cbi: &CallbackInterface,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<Option<(String, String)>, askama::Error> {
+        let Some(meth) = callback_interface::flow_publisher_method(cbi, config)
+            .map_err(|e| to_askama_error(&e))?
+        else {
+            return Ok(None);
+        };
+        let item_type = param_type_name(&meth.arguments()[0], ci, config)?;
+        Ok(Some((
+            JavaCodeOracle.callable_name(cbi.name(), meth.name(), config),
+            item_type,
+        )))
+    }
+
+    /// The aggregate `listeners` to generate, sorted by name, with the Java names of the
+    /// interfaces each of them extends.
+    pub fn listeners(
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<Vec<(String, Vec<String>)>, askama::Error> {
+        let mut listeners = config.listeners.iter().collect::<Vec<_>>();
+        listeners.sort();
+        listeners
+            .into_iter()
+            .map(|(listener, names)| {
+                let mut method_names = HashSet::new();
+                let interfaces = names
+                    .iter()
+                    .map(|name| {
+                        let (interface_name, methods) =
+                            if let Some(cbi) = ci.get_callback_interface_definition(name) {
+                                (type_name(cbi, ci, config)?, cbi.methods())
+                            } else if let Some(obj) = ci
+                                .get_object_definition(name)
+                                .filter(|obj| obj.has_callback_interface())
+                            {
+                                (JavaCodeOracle.object_names(ci, config, obj).0, obj.methods())
+                            } else {
+                                return Err(to_askama_error(&format!(
+                                    "listeners: {name} isn't a callback interface or a trait with foreign implementations"
+                                )));
+                            };
+                        // One implementation can't tell the callbacks apart if they share a name.
+                        if let Some(method) = methods
+                            .iter()
+                            .find(|meth| !method_names.insert(meth.name().to_string()))
+                        {
+                            return Err(to_askama_error(&format!(
+                                "listeners: {listener} gets `{}` from more than one interface",
+                                method.name()
+                            )));
+                        }
+                        Ok(interface_name)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((listener.clone(), interfaces))
+            })
+            .collect()
     }
 
     pub fn object_names(
         obj: &Object,
         ci: &ComponentInterface,
+        config: &Config,
     ) -> Result<(String, String), askama::Error> {
-        Ok(JavaCodeOracle.object_names(ci, obj))
+        Ok(JavaCodeOracle.object_names(ci, config, obj))
     }
 
     pub fn async_inner_return_type(
@@ -870,6 +3383,23 @@ mod filters {
             .map_or(Ok("Void".to_string()), |t| type_name(t, ci, config))
     }
 
+    /// The `UniffiResult` a fallible callable returns instead of throwing, see `results_as_values`
+    pub fn result_type(
+        callable: impl Callable,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        let error_type = callable
+            .throws_type()
+            .cloned()
+            .expect("only fallible callables return a UniffiResult");
+        let value_type = async_inner_return_type(callable, ci, config)?;
+        Ok(format!(
+            "UniffiResult<{value_type}, {}>",
+            type_name(&error_type, ci, config)?
+        ))
+    }
+
     pub fn async_return_type(
         callable: impl Callable,
         ci: &ComponentInterface,
@@ -902,15 +3432,15 @@ mod filters {
         let ffi_func = callable.ffi_rust_future_complete(ci);
         let call = format!("UniffiLib.getInstance().{ffi_func}(future, continuation)");
         let call = match callable.return_type() {
-            Some(return_type) if ci.is_external(return_type) => {
+            // With a shared runtime package both sides already use the same RustBuffer
+            Some(return_type)
+                if ci.is_external(return_type) && config.runtime_package.is_none() =>
+            {
                 let ffi_type = FfiType::from(return_type);
                 match ffi_type {
                     FfiType::RustBuffer(Some(ExternalFfiMetadata { name, module_path })) => {
                         // Need to convert the RustBuffer from our package to the RustBuffer of the external package
-                        let rust_buffer = format!(
-                            "{}.RustBuffer",
-                            config.external_type_package_name(&module_path, &name)
-                        );
+                        let rust_buffer = config.external_rust_buffer(&module_path, &name);
                         format!(
                             "(future, continuation) -> {{
                     var result = {call};
@@ -943,12 +3473,99 @@ mod filters {
         Ok(nm.as_ref().trim_matches('`').to_string())
     }
 
+    /// The `@Deprecated` annotation of an item listed in `deprecated`, indented by `spaces`.
+    pub fn deprecated_annotation(
+        deprecation: &Deprecation,
+        spaces: &i32,
+    ) -> Result<String, askama::Error> {
+        let spaces = usize::try_from(*spaces).unwrap_or_default();
+        Ok(format!(
+            "{}{}",
+            " ".repeat(spaces),
+            deprecation.annotation()
+        ))
+    }
+
     /// Get the idiomatic Java rendering of docstring
-    pub fn docstring<S: AsRef<str>>(docstring: S, spaces: &i32) -> Result<String, askama::Error> {
-        let middle = textwrap::indent(&textwrap::dedent(docstring.as_ref()), " * ");
+    ///
+    /// A javadoc comment, with its doc links turned into `{@link}`s, see `javadoc_links`.
+    pub fn docstring<S: AsRef<str>>(
+        docstring: S,
+        spaces: &i32,
+        ci: &ComponentInterface,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
+        let docstring = super::javadoc_links(&textwrap::dedent(docstring.as_ref()), ci, config);
+        let middle = textwrap::indent(&docstring, " * ");
         let wrapped = format!("/**\n{middle}\n */");
 
         let spaces = usize::try_from(*spaces).unwrap_or_default();
         Ok(textwrap::indent(&wrapped, &" ".repeat(spaces)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{NamingStyle, manifest_header, primitives::typed_number};
+    use uniffi_bindgen::interface::Type;
+
+    /// Pin the names `naming_style` produces, `strict` ones mustn't change when `heck` is updated.
+    #[test]
+    fn naming_style() {
+        for (name, lower, upper) in [
+            ("foo", "foo", "Foo"),
+            ("get_http_url", "getHttpUrl", "GetHttpUrl"),
+            ("get_HTTP_url", "getHTTPUrl", "GetHTTPUrl"),
+            ("foo2", "foo2", "Foo2"),
+            ("foo_2", "foo_2", "Foo_2"),
+            ("sha_256_hash", "sha_256Hash", "Sha_256Hash"),
+            ("_private", "_private", "_Private"),
+            ("trailing_", "trailing_", "Trailing_"),
+            ("double__underscore", "doubleUnderscore", "DoubleUnderscore"),
+            ("__", "__", "__"),
+        ] {
+            assert_eq!(NamingStyle::Strict.lower_camel_case(name), lower, "{name}");
+            assert_eq!(NamingStyle::Strict.upper_camel_case(name), upper, "{name}");
+        }
+
+        // `heck` loses the difference between these
+        assert_eq!(NamingStyle::Heck.lower_camel_case("foo_2"), "foo2");
+        assert_eq!(NamingStyle::Heck.lower_camel_case("foo2"), "foo2");
+        assert_eq!(NamingStyle::Heck.lower_camel_case("_private"), "private");
+        assert_eq!(
+            NamingStyle::Heck.lower_camel_case("get_http_url"),
+            "getHttpUrl"
+        );
+    }
+
+    /// Enum discriminants are passed to constructors taking the boxed type, and assigned to the
+    /// `emit_constants` fields, so they need the exact type without relying on widening.
+    #[test]
+    fn typed_number_has_the_exact_type() {
+        for (type_, literal) in [
+            (Type::Int8, "(byte) 1"),
+            (Type::UInt8, "(byte) 1"),
+            (Type::Int16, "(short) 1"),
+            (Type::UInt16, "(short) 1"),
+            (Type::Int32, "1"),
+            (Type::UInt32, "(int) 1L"),
+            (Type::Int64, "1L"),
+            (Type::UInt64, "Long.parseUnsignedLong(\"1\")"),
+        ] {
+            assert_eq!(typed_number(&type_, "1".to_string()), literal, "{type_:?}");
+        }
+    }
+
+    #[test]
+    fn manifest_header_wraps_between_characters() {
+        let value = "é".repeat(40);
+        let header = manifest_header("Bundle-Name", &value);
+        assert!(header.lines().count() > 1);
+        assert!(header.lines().all(|line| line.len() <= 72));
+        // The first line has the 13 bytes of the name, then 29 two byte characters, one byte
+        // short of the limit.
+        assert_eq!(header.lines().next().unwrap().len(), 71);
+        let unwrapped = header.strip_suffix('\n').unwrap().replace("\n ", "");
+        assert_eq!(unwrapped, format!("Bundle-Name: {value}"));
+    }
+}
@@ -23,7 +23,7 @@ impl CodeType for ObjectCodeType {
             config,
             ci,
             &self.name,
-            super::JavaCodeOracle.class_name(ci, &self.name),
+            super::JavaCodeOracle.type_class_name(ci, config, &self.name),
         )
     }
 
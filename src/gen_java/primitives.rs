@@ -1,51 +1,41 @@
 use super::{CodeType, Config};
 use paste::paste;
 use uniffi_bindgen::backend::Literal;
-use uniffi_bindgen::interface::{ComponentInterface, Radix, Type};
+use uniffi_bindgen::interface::{ComponentInterface, Type};
 
-fn render_literal(literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
-    fn typed_number(type_: &Type, num_str: String) -> String {
-        let unwrapped_type = match type_ {
-            Type::Optional { inner_type } => inner_type,
-            t => t,
-        };
-        match unwrapped_type {
-            // Bytes, Shorts and Ints can all be inferred from the type.
-            Type::Int8 | Type::Int16 | Type::Int32 => num_str,
-            Type::Int64 => format!("{num_str}L"),
-
-            // Java has pretty hacky unsigned support, see https://docs.oracle.com/javase/8/docs/api/java/lang/Long.html (search for unsigned)
-            // and https://docs.oracle.com/javase/8/docs/api/java/lang/Integer.html search for unsigned.
-            Type::UInt8 | Type::UInt16 | Type::UInt32 => {
-                format!("Integer.parseUnsignedInt({num_str})")
-            }
-            Type::UInt64 => format!("Long.parseUnsignedLong({num_str})"),
+/// A number literal of exactly `type_`, or the type an optional of it holds.
+///
+/// Literals are passed as arguments, where Java doesn't narrow or widen them before boxing, so
+/// they have to have exactly the type of the boxed parameter.
+pub(super) fn typed_number(type_: &Type, num_str: String) -> String {
+    let mut unwrapped_type = type_;
+    while let Type::Optional { inner_type } = unwrapped_type {
+        unwrapped_type = inner_type;
+    }
+    match unwrapped_type {
+        // Unsigned values are kept in signed types of the same size, see
+        // https://docs.oracle.com/javase/8/docs/api/java/lang/Long.html (search for unsigned)
+        Type::Int8 | Type::UInt8 => format!("(byte) {num_str}"),
+        Type::Int16 | Type::UInt16 => format!("(short) {num_str}"),
+        Type::Int32 => num_str,
+        Type::UInt32 => format!("(int) {num_str}L"),
+        Type::Int64 => format!("{num_str}L"),
+        Type::UInt64 => format!("Long.parseUnsignedLong(\"{num_str}\")"),
 
-            Type::Float32 => format!("{num_str}f"),
-            Type::Float64 => num_str,
-            _ => panic!("Unexpected literal: {num_str} for type: {type_:?}"),
-        }
+        Type::Float32 => format!("{num_str}f"),
+        Type::Float64 => format!("{num_str}d"),
+        _ => panic!("Unexpected literal: {num_str} for type: {type_:?}"),
     }
+}
 
+fn render_literal(literal: &Literal, _ci: &ComponentInterface, _config: &Config) -> String {
     match literal {
         Literal::Boolean(v) => format!("{v}"),
-        Literal::String(s) => format!("\"{s}\""),
-        Literal::Int(i, radix, type_) => typed_number(
-            type_,
-            match radix {
-                Radix::Octal => format!("{i:#x}"),
-                Radix::Decimal => format!("{i}"),
-                Radix::Hexadecimal => format!("{i:#x}"),
-            },
-        ),
-        Literal::UInt(i, radix, type_) => typed_number(
-            type_,
-            match radix {
-                Radix::Octal => format!("{i:#x}"),
-                Radix::Decimal => format!("{i}"),
-                Radix::Hexadecimal => format!("{i:#x}"),
-            },
-        ),
+        Literal::String(s) => super::java_string_literal(s),
+        // Rendered in decimal whatever the radix, Java's hex literals of negative numbers don't
+        // look like Rust's.
+        Literal::Int(i, _, type_) => typed_number(type_, i.to_string()),
+        Literal::UInt(i, _, type_) => typed_number(type_, i.to_string()),
         Literal::Float(string, type_) => typed_number(type_, string.clone()),
 
         _ => unreachable!("Literal"),
@@ -78,8 +68,10 @@ macro_rules! impl_code_type_for_primitive {
 #[derive(Debug)]
 pub struct BytesCodeType;
 impl CodeType for BytesCodeType {
-    fn type_label(&self, _ci: &ComponentInterface, _config: &Config) -> String {
-        "byte[]".to_string()
+    fn type_label(&self, _ci: &ComponentInterface, config: &Config) -> String {
+        config
+            .bytes_type_name()
+            .unwrap_or_else(|| "byte[]".to_string())
     }
 
     fn canonical_name(&self) -> String {
@@ -22,7 +22,7 @@ impl CodeType for RecordCodeType {
             config,
             ci,
             &self.id,
-            super::JavaCodeOracle.class_name(ci, &self.id),
+            super::JavaCodeOracle.type_class_name(ci, config, &self.id),
         )
     }
 
@@ -0,0 +1,448 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Scala wrappers of the generated records and enums, written with `scala_interop`.
+//!
+//! Records become case classes and enums with fields sealed traits, each converting to the Java
+//! type with `asJava` and back with `fromJava`. Numbers are Scala's, optionals `Option`, sequences
+//! `Seq` and maps `Map`. Everything without a Scala counterpart, like objects, callback interfaces
+//! and enums without fields, is used as the Java type.
+
+use super::{AsCodeType, CodeType, Config, JavaCodeOracle, primitive_optional};
+use crate::ComponentInterface;
+use anyhow::{Context, Result};
+use askama::Template;
+use std::collections::HashSet;
+use uniffi_bindgen::interface::{AsType, Field, Type};
+
+// Scala 2 and 3 reserved words, which have to be backquoted to be used as names.
+static SCALA_KEYWORDS: &[&str] = &[
+    "abstract",
+    "case",
+    "catch",
+    "class",
+    "def",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "for",
+    "forSome",
+    "given",
+    "if",
+    "implicit",
+    "import",
+    "lazy",
+    "macro",
+    "match",
+    "new",
+    "null",
+    "object",
+    "override",
+    "package",
+    "private",
+    "protected",
+    "return",
+    "sealed",
+    "super",
+    "then",
+    "this",
+    "throw",
+    "trait",
+    "true",
+    "try",
+    "type",
+    "val",
+    "var",
+    "while",
+    "with",
+    "yield",
+];
+
+/// The Scala source of the wrappers, the `<namespace>.scala` in the `scaladsl` sub-package of the
+/// bindings package.
+pub fn generate_scala_interop(config: &Config, ci: &ComponentInterface) -> Result<String> {
+    let mut scala = Scala {
+        config,
+        ci,
+        wrapped: HashSet::new(),
+    };
+    // Types that can't be wrapped are left out, fields of their type then hold the Java one.
+    let mut skipped = vec![];
+    for type_ in ci.iter_local_types() {
+        let (name, fields): (&str, Vec<&Field>) = match type_ {
+            Type::Record { name, .. } => match ci.get_record_definition(name) {
+                Some(rec) => (name.as_str(), rec.fields().iter().collect()),
+                None => continue,
+            },
+            Type::Enum { name, .. } if !ci.is_name_used_as_error(name) => {
+                match ci.get_enum_definition(name).filter(|e| !e.is_flat()) {
+                    Some(e) => (
+                        name.as_str(),
+                        e.variants().iter().flat_map(|v| v.fields()).collect(),
+                    ),
+                    None => continue,
+                }
+            }
+            _ => continue,
+        };
+        if config.excludes_item(name) {
+            continue;
+        }
+        if fields
+            .iter()
+            .all(|field| scala.is_supported(&field.as_type()))
+        {
+            scala.wrapped.insert(name.to_string());
+        } else {
+            skipped.push(JavaCodeOracle.class_name(ci, name));
+        }
+    }
+    let mut records = vec![];
+    let mut enums = vec![];
+    for type_ in ci.iter_local_types() {
+        match type_ {
+            Type::Record { name, .. } if scala.wrapped.contains(name) => {
+                records.push(scala.record(name));
+            }
+            Type::Enum { name, .. } if scala.wrapped.contains(name) => {
+                enums.push(scala.enum_(name));
+            }
+            _ => (),
+        }
+    }
+    ScalaInterop {
+        package_name: format!("{}.scaladsl", config.package_name()),
+        namespace: ci.namespace(),
+        records,
+        enums,
+        skipped,
+    }
+    .render()
+    .context("failed to render scala interop")
+}
+
+struct Scala<'a> {
+    config: &'a Config,
+    ci: &'a ComponentInterface,
+    // The records and enums that get a Scala wrapper
+    wrapped: HashSet<String>,
+}
+
+impl Scala<'_> {
+    // Whether a field of `type_` can be held by a wrapper: custom types may not have a name Scala
+    // can refer to, and nested optionals are a `java.util.Optional` without a Scala counterpart,
+    // like the `java.util.OptionalInt` and such of `primitive_optionals`.
+    fn is_supported(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Custom { .. } => false,
+            Type::Bytes => self.config.bytes_type.is_none(),
+            Type::Optional { inner_type } => {
+                !matches!(**inner_type, Type::Optional { .. })
+                    && primitive_optional(self.config, inner_type).is_none()
+                    && self.is_supported(inner_type)
+            }
+            Type::Sequence { inner_type } => self.is_supported(inner_type),
+            Type::Map {
+                key_type,
+                value_type,
+            } => self.is_supported(key_type) && self.is_supported(value_type),
+            _ => true,
+        }
+    }
+
+    // The Java type as Scala refers to it, boxed like in the generated classes.
+    fn java_type(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Int8 | Type::UInt8 => "java.lang.Byte".to_string(),
+            Type::Int16 | Type::UInt16 => "java.lang.Short".to_string(),
+            Type::Int32 | Type::UInt32 => "java.lang.Integer".to_string(),
+            Type::Int64 | Type::UInt64 => "java.lang.Long".to_string(),
+            Type::Float32 => "java.lang.Float".to_string(),
+            Type::Float64 => "java.lang.Double".to_string(),
+            Type::Boolean => "java.lang.Boolean".to_string(),
+            Type::String => "String".to_string(),
+            Type::Bytes => "Array[Byte]".to_string(),
+            Type::Timestamp => "java.time.Instant".to_string(),
+            Type::Duration => "java.time.Duration".to_string(),
+            Type::Optional { inner_type } => self.java_type(inner_type),
+            Type::Sequence { inner_type } => {
+                format!("java.util.List[{}]", self.java_type(inner_type))
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "java.util.Map[{}, {}]",
+                self.java_type(key_type),
+                self.java_type(value_type)
+            ),
+            _ => {
+                let type_label = JavaCodeOracle.find(type_).type_label(self.ci, self.config);
+                // External types are already qualified with their package.
+                if type_label.contains('.') {
+                    format!("_root_.{type_label}")
+                } else {
+                    format!("_root_.{}.{type_label}", self.config.package_name())
+                }
+            }
+        }
+    }
+
+    fn scala_type(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Int8 | Type::UInt8 => "Byte".to_string(),
+            Type::Int16 | Type::UInt16 => "Short".to_string(),
+            Type::Int32 | Type::UInt32 => "Int".to_string(),
+            Type::Int64 | Type::UInt64 => "Long".to_string(),
+            Type::Float32 => "Float".to_string(),
+            Type::Float64 => "Double".to_string(),
+            Type::Boolean => "Boolean".to_string(),
+            Type::Optional { inner_type } => format!("Option[{}]", self.scala_type(inner_type)),
+            Type::Sequence { inner_type } => format!("Seq[{}]", self.scala_type(inner_type)),
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "Map[{}, {}]",
+                self.scala_type(key_type),
+                self.scala_type(value_type)
+            ),
+            Type::Record { name, .. } | Type::Enum { name, .. } if self.wrapped.contains(name) => {
+                JavaCodeOracle.class_name(self.ci, name)
+            }
+            _ => self.java_type(type_),
+        }
+    }
+
+    // Convert the Scala value `expr` of `type_` to the Java one.
+    fn to_java(&self, type_: &Type, expr: &str) -> String {
+        match type_ {
+            Type::Int8
+            | Type::UInt8
+            | Type::Int16
+            | Type::UInt16
+            | Type::Int32
+            | Type::UInt32
+            | Type::Int64
+            | Type::UInt64
+            | Type::Float32
+            | Type::Float64
+            | Type::Boolean => format!("({expr}: {})", self.java_type(type_)),
+            Type::Optional { inner_type } => {
+                format!("{}.orNull", self.map(expr, &self.to_java(inner_type, "v")))
+            }
+            Type::Sequence { inner_type } => {
+                format!("{}.asJava", self.map(expr, &self.to_java(inner_type, "v")))
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "{}.asJava",
+                self.map_entries(
+                    expr,
+                    &self.to_java(key_type, "k"),
+                    &self.to_java(value_type, "v")
+                )
+            ),
+            Type::Record { name, .. } | Type::Enum { name, .. } if self.wrapped.contains(name) => {
+                format!("{expr}.asJava")
+            }
+            _ => expr.to_string(),
+        }
+    }
+
+    // Convert the Java value `expr` of `type_` to the Scala one.
+    fn from_java(&self, type_: &Type, expr: &str) -> String {
+        match type_ {
+            Type::Int8
+            | Type::UInt8
+            | Type::Int16
+            | Type::UInt16
+            | Type::Int32
+            | Type::UInt32
+            | Type::Int64
+            | Type::UInt64
+            | Type::Float32
+            | Type::Float64
+            | Type::Boolean => format!("({expr}: {})", self.scala_type(type_)),
+            Type::Optional { inner_type } => {
+                self.map(&format!("Option({expr})"), &self.from_java(inner_type, "v"))
+            }
+            Type::Sequence { inner_type } => format!(
+                "{}.toSeq",
+                self.map(&format!("{expr}.asScala"), &self.from_java(inner_type, "v"))
+            ),
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "{}.toMap",
+                self.map_entries(
+                    &format!("{expr}.asScala"),
+                    &self.from_java(key_type, "k"),
+                    &self.from_java(value_type, "v")
+                )
+            ),
+            Type::Record { name, .. } | Type::Enum { name, .. } if self.wrapped.contains(name) => {
+                format!(
+                    "{}.fromJava({expr})",
+                    JavaCodeOracle.class_name(self.ci, name)
+                )
+            }
+            _ => expr.to_string(),
+        }
+    }
+
+    // `collection.map(v => converted)`, or the collection itself when the elements stay the same.
+    fn map(&self, collection: &str, converted: &str) -> String {
+        if converted == "v" {
+            collection.to_string()
+        } else {
+            format!("{collection}.map(v => {converted})")
+        }
+    }
+
+    fn map_entries(&self, map: &str, converted_key: &str, converted_value: &str) -> String {
+        if converted_key == "k" && converted_value == "v" {
+            map.to_string()
+        } else {
+            format!("{map}.map {{ case (k, v) => ({converted_key}, {converted_value}) }}")
+        }
+    }
+
+    fn record(&self, name: &str) -> ScalaClass {
+        let rec = self.ci.get_record_definition(name).unwrap();
+        let java_name = self.java_type(&rec.as_type());
+        let java_value = if !rec.has_fields() && self.config.singleton_empty_records {
+            format!("{java_name}.INSTANCE")
+        } else {
+            format!("new {java_name}({})", self.java_args(rec.fields()))
+        };
+        ScalaClass {
+            name: JavaCodeOracle.class_name(self.ci, name),
+            java_name,
+            params: self.params(rec.fields()),
+            java_value,
+            scala_args: self.scala_args(rec.fields(), "value"),
+        }
+    }
+
+    fn enum_(&self, name: &str) -> ScalaEnum {
+        let e = self.ci.get_enum_definition(name).unwrap();
+        let java_name = self.java_type(&e.as_type());
+        let variants = e
+            .variants()
+            .iter()
+            .map(|variant| {
+                let variant_name = variant.as_codetype().type_label(self.ci, self.config);
+                ScalaClass {
+                    name: variant_name.clone(),
+                    java_name: format!("{java_name}.{variant_name}"),
+                    params: self.params(variant.fields()),
+                    java_value: format!(
+                        "new {java_name}.{variant_name}({})",
+                        self.java_args(variant.fields())
+                    ),
+                    scala_args: self.scala_args(variant.fields(), "variant"),
+                }
+            })
+            .collect();
+        ScalaEnum {
+            name: JavaCodeOracle.class_name(self.ci, name),
+            java_name,
+            variants,
+        }
+    }
+
+    fn params(&self, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                format!(
+                    "{}: {}",
+                    self.field_name(field, i),
+                    self.scala_type(&field.as_type())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn java_args(&self, fields: &[Field]) -> String {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| self.to_java(&field.as_type(), &self.field_name(field, i)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // The arguments making the wrapper out of the Java value `java_value`, through its accessors.
+    fn scala_args(&self, fields: &[Field], java_value: &str) -> String {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let accessor = match field.name() {
+                    "" => format!("v{}", i + 1),
+                    name => JavaCodeOracle.var_name(name, self.config),
+                };
+                let accessor = backquoted(accessor);
+                self.from_java(&field.as_type(), &format!("{java_value}.{accessor}()"))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // Unnamed fields of enum variants are `v1`, `v2`... like in the Java records.
+    fn field_name(&self, field: &Field, i: usize) -> String {
+        backquoted(match field.name() {
+            "" => format!("v{}", i + 1),
+            name => JavaCodeOracle.var_name_raw(name, self.config),
+        })
+    }
+}
+
+fn backquoted(name: String) -> String {
+    if SCALA_KEYWORDS.contains(&name.as_str()) {
+        format!("`{name}`")
+    } else {
+        name
+    }
+}
+
+// A case class, or a case object without parameters
+struct ScalaClass {
+    name: String,
+    java_name: String,
+    params: String,
+    // The Java value of the case class, from its parameters
+    java_value: String,
+    // The arguments of the case class, from the Java value
+    scala_args: String,
+}
+
+struct ScalaEnum {
+    name: String,
+    java_name: String,
+    variants: Vec<ScalaClass>,
+}
+
+#[derive(Template)]
+#[template(syntax = "java", escape = "none", path = "ScalaInteropTemplate.scala")]
+struct ScalaInterop<'a> {
+    package_name: String,
+    namespace: &'a str,
+    records: Vec<ScalaClass>,
+    enums: Vec<ScalaEnum>,
+    // The records and enums without a wrapper
+    skipped: Vec<String>,
+}
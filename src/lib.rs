@@ -9,6 +9,8 @@ use uniffi_bindgen::{BindingGenerator, Component, ComponentInterface, Generation
 
 mod gen_java;
 
+pub use gen_java::{generate_type_report, required_ffi_symbols};
+
 pub struct JavaBindingGenerator;
 impl BindingGenerator for JavaBindingGenerator {
     type Config = gen_java::Config;
@@ -67,26 +69,113 @@ impl BindingGenerator for JavaBindingGenerator {
             r"(?m)^(?:public\s)?(?:final\s)?(?:sealed\s)?(?:abstract\s)?(?:static\s)?(?:class|interface|enum|record)\s(\w+)",
         )
         .unwrap();
+        // Most classes are in the bindings package, but the shared runtime classes can be in their own.
+        // An empty `package_name` is the default package, its classes are written without a package line.
+        let package_capture = regex::Regex::new(r"(?m)^package ([\w.]*);").unwrap();
         for Component { ci, config, .. } in components {
             let bindings_str = gen_java::generate_bindings(config, ci)?;
-            let java_package_out_dir = &settings.out_dir.join(
-                config
-                    .package_name()
-                    .split('.')
-                    .collect::<Vec<_>>()
-                    .join("/"),
-            );
-            fs::create_dir_all(java_package_out_dir)?;
-            let package_line = format!("package {};", config.package_name());
-            let split_classes = bindings_str.split(&package_line);
-            let writable = split_classes
-                .map(|file| (filename_capture.captures(file), file))
-                .filter(|(x, _)| x.is_some())
-                .map(|(captures, file)| (captures.unwrap().get(1).unwrap().as_str(), file))
+            let java_version_features = gen_java::java_version_features(&bindings_str);
+            let minimum_java_version = gen_java::minimum_java_version(&java_version_features);
+            // `java_version` is the Java version the bindings have to compile with.
+            if let Some(java_version) = config
+                .java_version
+                .filter(|java_version| *java_version < minimum_java_version)
+            {
+                let features = java_version_features
+                    .iter()
+                    .filter(|(_, version)| *version > java_version)
+                    .map(|(name, version)| format!("{name} ({version})"))
+                    .collect::<Vec<_>>();
+                anyhow::bail!(
+                    "java_version: the bindings for {} need Java {minimum_java_version}, they use {}",
+                    ci.namespace(),
+                    features.join(", ")
+                );
+            }
+            let package_lines = package_capture
+                .captures_iter(&bindings_str)
+                .map(|captures| {
+                    (
+                        captures.get(0).unwrap().range(),
+                        captures.get(1).unwrap().as_str(),
+                    )
+                })
                 .collect::<Vec<_>>();
-            for (filename, file) in writable {
+            for (i, (package_line, package_name)) in package_lines.iter().enumerate() {
+                let end = package_lines
+                    .get(i + 1)
+                    .map_or(bindings_str.len(), |(next_line, _)| next_line.start);
+                let file = &bindings_str[package_line.end..end];
+                let Some(captures) = filename_capture.captures(file) else {
+                    continue;
+                };
+                let filename = captures.get(1).unwrap().as_str();
+                let java_package_out_dir = &settings
+                    .out_dir
+                    .join(package_name.split('.').collect::<Vec<_>>().join("/"));
+                fs::create_dir_all(java_package_out_dir)?;
                 let java_file_location = java_package_out_dir.join(format!("{}.java", filename));
-                fs::write(&java_file_location, format!("{}\n{}", package_line, file))?;
+                let contents = if package_name.is_empty() {
+                    file.trim_start().to_string()
+                } else {
+                    format!("package {};\n{}", package_name, file)
+                };
+                let contents = if config.format_output {
+                    gen_java::normalize_java_source(&contents)
+                } else {
+                    contents
+                };
+                fs::write(&java_file_location, contents)?;
+            }
+            if config.emit_osgi_manifest {
+                // One fragment per package, library mode generates several bindings into one dir.
+                let meta_inf_dir = settings.out_dir.join("META-INF");
+                fs::create_dir_all(&meta_inf_dir)?;
+                fs::write(
+                    meta_inf_dir.join(format!("{}.MF", config.package_name())),
+                    gen_java::generate_osgi_manifest(config, ci, minimum_java_version),
+                )?;
+            }
+            if config.emit_java_version_report {
+                let meta_inf_dir = settings.out_dir.join("META-INF");
+                fs::create_dir_all(&meta_inf_dir)?;
+                fs::write(
+                    meta_inf_dir.join(format!("{}.java-version.properties", config.package_name())),
+                    gen_java::java_version_report(&java_version_features),
+                )?;
+            }
+            if config.emit_value_tests {
+                // Kept out of the bindings package directory, they need JUnit to compile.
+                let test_package_dir = settings.out_dir.join("value-tests").join(
+                    config
+                        .package_name()
+                        .split('.')
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                );
+                fs::create_dir_all(&test_package_dir)?;
+                let value_tests = gen_java::generate_value_tests(config, ci)?;
+                let class_name = &filename_capture.captures(&value_tests).unwrap()[1];
+                fs::write(
+                    test_package_dir.join(format!("{class_name}.java")),
+                    &value_tests,
+                )?;
+            }
+            if config.scala_interop {
+                // Next to the Java sources, Scala projects usually keep them apart.
+                let scala_package_dir = settings.out_dir.join("scala").join(
+                    config
+                        .package_name()
+                        .split('.')
+                        .chain(["scaladsl"])
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                );
+                fs::create_dir_all(&scala_package_dir)?;
+                fs::write(
+                    scala_package_dir.join(format!("{}.scala", ci.namespace())),
+                    gen_java::generate_scala_interop(config, ci)?,
+                )?;
             }
             if settings.try_format_code {
                 // TODO: if there's a CLI formatter that makes sense to use here, use it, PRs welcome
@@ -100,6 +189,47 @@ impl BindingGenerator for JavaBindingGenerator {
     }
 }
 
+/// The generator the CLI runs, `JavaBindingGenerator` writing a `generate_type_report` next to the
+/// bindings with `--emit-type-report`.
+struct CliBindingGenerator {
+    emit_type_report: bool,
+}
+
+impl BindingGenerator for CliBindingGenerator {
+    type Config = gen_java::Config;
+
+    fn new_config(&self, root_toml: &toml::Value) -> Result<Self::Config> {
+        JavaBindingGenerator.new_config(root_toml)
+    }
+
+    fn update_component_configs(
+        &self,
+        settings: &GenerationSettings,
+        components: &mut Vec<Component<Self::Config>>,
+    ) -> Result<()> {
+        JavaBindingGenerator.update_component_configs(settings, components)
+    }
+
+    fn write_bindings(
+        &self,
+        settings: &GenerationSettings,
+        components: &[Component<Self::Config>],
+    ) -> Result<()> {
+        JavaBindingGenerator.write_bindings(settings, components)?;
+        if self.emit_type_report {
+            for Component { ci, config, .. } in components {
+                fs::write(
+                    settings
+                        .out_dir
+                        .join(format!("{}.type-report.txt", config.package_name())),
+                    generate_type_report(config, ci),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Parser)]
 #[clap(name = "uniffi-bindgen-java")]
 #[clap(version = clap::crate_version!())]
@@ -150,6 +280,11 @@ enum Commands {
         /// all sub-dependencies causes obscure platform specific problems.
         #[clap(long)]
         metadata_no_deps: bool,
+
+        /// Also write a `<package>.type-report.txt` to the output directory, listing every type
+        /// with its Java type, FfiConverter and imports, to debug bindings that don't compile.
+        #[clap(long)]
+        emit_type_report: bool,
     },
     /// Generate Rust scaffolding code
     Scaffolding {
@@ -183,7 +318,9 @@ pub fn run_main() -> Result<()> {
             crate_name,
             source,
             metadata_no_deps,
+            emit_type_report,
         } => {
+            let binding_generator = CliBindingGenerator { emit_type_report };
             if library_mode {
                 use uniffi_bindgen::library_mode::generate_bindings;
                 if lib_file.is_some() {
@@ -204,7 +341,7 @@ pub fn run_main() -> Result<()> {
                 generate_bindings(
                     &source,
                     crate_name,
-                    &JavaBindingGenerator,
+                    &binding_generator,
                     &config_supplier,
                     config.as_deref(),
                     &out_dir,
@@ -215,7 +352,7 @@ pub fn run_main() -> Result<()> {
                 generate_bindings(
                     &source,
                     config.as_deref(),
-                    JavaBindingGenerator,
+                    binding_generator,
                     out_dir.as_deref(),
                     lib_file.as_deref(),
                     crate_name.as_deref(),
@@ -2,25 +2,55 @@ package {{ config.package_name() }};
 
 import java.util.concurrent.CompletableFuture;
 import java.util.concurrent.ExecutionException;
+import java.util.concurrent.Executor;
+import java.util.concurrent.ForkJoinPool;
 import java.util.function.BiConsumer;
 import java.util.function.BiFunction;
 import java.util.function.Consumer;
 import java.util.function.Function;
-import java.util.function.Supplier;{% if config.quarkus %}
+import java.util.function.Supplier;{% if config.shutdown_hook %}
+import java.util.List;
+import java.util.Set;
+import java.util.concurrent.ConcurrentHashMap;{% endif %}{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForProxy;
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public final class UniffiAsyncHelpers {
     // Async return type handlers
     static final byte UNIFFI_RUST_FUTURE_POLL_READY = (byte) 0;
     static final byte UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = (byte) 1;
     static final UniffiHandleMap<CompletableFuture<Byte>> uniffiContinuationHandleMap = new UniffiHandleMap<>();
     static final UniffiHandleMap<CancelableForeignFuture> uniffiForeignFutureHandleMap = new UniffiHandleMap<>();
+    {%- if config.shutdown_hook %}
+    // Futures of Rust calls that haven't completed yet, so they can be dropped on shutdown
+    static final Set<CompletableFuture<?>> uniffiPendingFutures = ConcurrentHashMap.newKeySet();
+    {%- endif %}
+
+    // Drives Rust futures to completion and waits on Java futures handed to Rust.
+    private static volatile Executor uniffiExecutor = ForkJoinPool.commonPool();
+
+    /**
+     * Replace the executor async calls run on, {@link ForkJoinPool#commonPool()} by default.
+     *
+     * <p>Each pending call to an async Rust function holds one of its threads until the Rust future
+     * is ready, and so does each Java future returned to Rust by an async callback. With many calls
+     * in flight, an executor with more threads than the common pool keeps them from queueing up
+     * behind each other. Calls that already started keep the executor they started on.
+     */
+    public static void setExecutor(Executor executor) {
+        uniffiExecutor = java.util.Objects.requireNonNull(executor, "executor");
+    }
+
+    /** The executor async calls run on, see {@link #setExecutor(Executor)}. */
+    public static Executor getExecutor() {
+        return uniffiExecutor;
+    }
 
     // FFI type for Rust future continuations{% if config.quarkus %}
     @RegisterForReflection{%- endif %}
-    enum UniffiRustFutureContinuationCallbackImpl implements UniffiRustFutureContinuationCallback {
+    enum UniffiRustFutureContinuationCallbackImpl implements {{ "RustFutureContinuationCallback"|ffi_callback_name(config) }} {
         INSTANCE;
 
         @Override
@@ -32,7 +62,7 @@ public final class UniffiAsyncHelpers {
     @FunctionalInterface{% if config.quarkus %}
     @RegisterForProxy{%- endif %}
     interface PollingFunction {
-        void apply(long rustFuture, UniffiRustFutureContinuationCallback callback, long continuationHandle);
+        void apply(long rustFuture, {{ "RustFutureContinuationCallback"|ffi_callback_name(config) }} callback, long continuationHandle);
     }
     {% if config.quarkus %}
     @RegisterForReflection{%- endif %}
@@ -81,12 +111,16 @@ public final class UniffiAsyncHelpers {
     static <T, F, E extends Exception> CompletableFuture<T> uniffiRustCallAsync(
         long rustFuture,
         PollingFunction pollFunc,
-        BiFunction<Long, UniffiRustCallStatus, F> completeFunc,
+        BiFunction<Long, {{ "UniffiRustCallStatus"|runtime_type(config) }}, F> completeFunc,
         Consumer<Long> freeFunc,
         Function<F, T> liftFunc,
         UniffiRustCallStatusErrorHandler<E> errorHandler
     ){
         CompletableFuture<T> future = new UniffiFreeingFuture<>(rustFuture, freeFunc);
+        {%- if config.shutdown_hook %}
+        uniffiPendingFutures.add(future);
+        future.whenComplete((result, error) -> uniffiPendingFutures.remove(future));
+        {%- endif %}
 
         CompletableFuture.runAsync(() -> {
             try {
@@ -109,7 +143,7 @@ public final class UniffiAsyncHelpers {
                     freeFunc.accept(rustFuture);
                 }
             }
-        });
+        }, uniffiExecutor);
 
         return future;
     }
@@ -122,12 +156,16 @@ public final class UniffiAsyncHelpers {
     static <E extends Exception> CompletableFuture<Void> uniffiRustCallAsync(
         long rustFuture,
         PollingFunction pollFunc,
-        BiConsumer<Long, UniffiRustCallStatus> completeFunc,
+        BiConsumer<Long, {{ "UniffiRustCallStatus"|runtime_type(config) }}> completeFunc,
         Consumer<Long> freeFunc,
         Runnable liftFunc,
         UniffiRustCallStatusErrorHandler<E> errorHandler
     ){
         CompletableFuture<Void> future = new UniffiFreeingFuture<>(rustFuture, freeFunc);
+        {%- if config.shutdown_hook %}
+        uniffiPendingFutures.add(future);
+        future.whenComplete((result, error) -> uniffiPendingFutures.remove(future));
+        {%- endif %}
         
         CompletableFuture.runAsync(() -> {
             try {
@@ -153,11 +191,24 @@ public final class UniffiAsyncHelpers {
                     freeFunc.accept(rustFuture);
                 }
             }
-        });
+        }, uniffiExecutor);
 
         return future;
     }
     
+    {%- if config.shutdown_hook %}
+    // Cancel the futures of Rust calls that haven't completed, which drops the Rust futures.
+    static int uniffiCancelPendingFutures() {
+        int cancelled = 0;
+        for (CompletableFuture<?> future : List.copyOf(uniffiPendingFutures)) {
+            if (future.cancel(true)) {
+                cancelled++;
+            }
+        }
+        return cancelled;
+    }
+    {%- endif %}
+
     private static byte poll(long rustFuture, PollingFunction pollFunc) throws InterruptedException, ExecutionException {
         CompletableFuture<Byte> pollFuture = new CompletableFuture<>();
         var handle = uniffiContinuationHandleMap.insert(pollFuture);
@@ -168,10 +219,10 @@ public final class UniffiAsyncHelpers {
     
     {%- if ci.has_async_callback_interface_definition() %}{% if config.quarkus %}
     @RegisterForReflection{%- endif %}
-    static <T> UniffiForeignFuture uniffiTraitInterfaceCallAsync(
+    static <T> {{ "ForeignFuture"|ffi_struct_name(config) }} uniffiTraitInterfaceCallAsync(
         Supplier<CompletableFuture<T>> makeCall,
         Consumer<T> handleSuccess,
-        Consumer<UniffiRustCallStatus.ByValue> handleError 
+        Consumer<{{ "UniffiRustCallStatus"|runtime_type(config) }}.ByValue> handleError 
     ){
         // Uniffi does its best to support structured concurrency across the FFI.
         // If the Rust future is dropped, `UniffiForeignFutureFreeImpl` is called, which will cancel the Java completable future if it's still running.
@@ -184,27 +235,30 @@ public final class UniffiAsyncHelpers {
                 if (e instanceof ExecutionException) {
                     e = e.getCause();
                 }
+                {%- if config.aborts_on_callback_exception() %}
+                UniffiHelpers.uniffiAbortOnCallbackException(e);
+                {%- endif %}
                 handleError.accept(
-                    UniffiRustCallStatus.create(
-                        UniffiRustCallStatus.UNIFFI_CALL_UNEXPECTED_ERROR,
+                    {{ "UniffiRustCallStatus"|runtime_type(config) }}.create(
+                        {{ "UniffiRustCallStatus"|runtime_type(config) }}.UNIFFI_CALL_UNEXPECTED_ERROR,
                         {{ Type::String.borrow()|lower_fn(config, ci) }}(e.toString())
                     )
                 );
             }
 
             return null;
-        });
+        }, uniffiExecutor);
         long handle = uniffiForeignFutureHandleMap.insert(new CancelableForeignFuture(foreignFutureCf, ffHandler));
-        return new UniffiForeignFuture(handle, UniffiForeignFutureFreeImpl.INSTANCE);
+        return new {{ "ForeignFuture"|ffi_struct_name(config) }}(handle, UniffiForeignFutureFreeImpl.INSTANCE);
     }
 
-    @SuppressWarnings("unchecked")
-    static <T, E extends Throwable> UniffiForeignFuture uniffiTraitInterfaceCallAsyncWithError(
+    static <T, E extends Throwable> {{ "ForeignFuture"|ffi_struct_name(config) }} uniffiTraitInterfaceCallAsyncWithError(
         Supplier<CompletableFuture<T>> makeCall,
         Consumer<T> handleSuccess,
-        Consumer<UniffiRustCallStatus.ByValue> handleError, 
-        Function<E, RustBuffer.ByValue> lowerError,
-        Class<E> errorClass
+        Consumer<{{ "UniffiRustCallStatus"|runtime_type(config) }}.ByValue> handleError, 
+        Function<E, {{ "RustBuffer"|runtime_type(config) }}.ByValue> lowerError,
+        Class<E> errorClass,
+        Function<Throwable, E> mapException
     ){
         var foreignFutureCf = makeCall.get();
         CompletableFuture<Void> ffHandler = CompletableFuture.supplyAsync(() -> {
@@ -215,17 +269,22 @@ public final class UniffiAsyncHelpers {
                 if (e instanceof ExecutionException) {
                     e = e.getCause();
                 }
-                if (errorClass.isInstance(e)) {
+                // Other exceptions can be mapped to the declared error with `callback_exception_mapping`.
+                E error = errorClass.isInstance(e) ? errorClass.cast(e) : mapException.apply(e);
+                if (error != null) {
                     handleError.accept(
-                        UniffiRustCallStatus.create(
-                            UniffiRustCallStatus.UNIFFI_CALL_ERROR,
-                            lowerError.apply((E) e)
+                        {{ "UniffiRustCallStatus"|runtime_type(config) }}.create(
+                            {{ "UniffiRustCallStatus"|runtime_type(config) }}.UNIFFI_CALL_ERROR,
+                            lowerError.apply(error)
                         )
                     );
                 } else {
+                    {%- if config.aborts_on_callback_exception() %}
+                    UniffiHelpers.uniffiAbortOnCallbackException(e);
+                    {%- endif %}
                     handleError.accept(
-                        UniffiRustCallStatus.create(
-                            UniffiRustCallStatus.UNIFFI_CALL_UNEXPECTED_ERROR,
+                        {{ "UniffiRustCallStatus"|runtime_type(config) }}.create(
+                            {{ "UniffiRustCallStatus"|runtime_type(config) }}.UNIFFI_CALL_UNEXPECTED_ERROR,
                             {{ Type::String.borrow()|lower_fn(config, ci) }}(e.getMessage())
                         )
                     );
@@ -233,14 +292,14 @@ public final class UniffiAsyncHelpers {
             }
 
             return null;
-        });
+        }, uniffiExecutor);
 
         long handle = uniffiForeignFutureHandleMap.insert(new CancelableForeignFuture(foreignFutureCf, ffHandler));
-        return new UniffiForeignFuture(handle, UniffiForeignFutureFreeImpl.INSTANCE);
+        return new {{ "ForeignFuture"|ffi_struct_name(config) }}(handle, UniffiForeignFutureFreeImpl.INSTANCE);
     }
     {% if config.quarkus %}
     @RegisterForReflection{%- endif %}
-    enum UniffiForeignFutureFreeImpl implements UniffiForeignFutureFree {
+    enum UniffiForeignFutureFreeImpl implements {{ "ForeignFutureFree"|ffi_callback_name(config) }} {
         INSTANCE;
 
         @Override
@@ -4,6 +4,7 @@ import java.nio.ByteBuffer;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum FfiConverterBoolean implements FfiConverter<Boolean, Byte> {
   INSTANCE;
 
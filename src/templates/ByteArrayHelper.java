@@ -1,18 +1,62 @@
 package {{ config.package_name() }};
 
-import java.nio.ByteBuffer;{% if config.quarkus %}
+import java.nio.ByteBuffer;
+{%- match config.bytes_type.as_ref() %}
+{%- when Some(bytes_type) %}
+{%- for import_name in bytes_type.imports_for(ci, config) %}
+import {{ import_name }};
+{%- endfor %}
+{%- else %}
+{%- endmatch %}{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- match config.bytes_type.as_ref() %}
+{%- when Some(bytes_type) %}
+{%- let bytes_type_name = Type::Bytes.borrow()|type_name(ci, config) %}
+// FFI converter for `bytes` as the configured `bytes_type`, lifted from and lowered to a `byte[]`.
+{%- call java::suppress_warnings() %}
+public enum FfiConverterByteArray implements FfiConverterRustBuffer<{{ bytes_type_name }}>{
+  INSTANCE;
+
+    @Override
+    public {{ bytes_type_name }} read(ByteBuffer buf) {
+        byte[] bytes = RustBufferReader.readBytes(buf);
+        try {
+            return {{ bytes_type.lift("bytes") }};
+        } catch (Exception e) {
+            throw new RuntimeException(e);
+        }
+    }
+
+    @Override
+    public long allocationSize({{ bytes_type_name }} value) {
+        return 4L + (long)toBytes(value).length;
+    }
+
+    @Override
+    public void write({{ bytes_type_name }} value, ByteBuffer buf) {
+        byte[] bytes = toBytes(value);
+        buf.putInt(bytes.length);
+        buf.put(bytes);
+    }
+
+    private static byte[] toBytes({{ bytes_type_name }} value) {
+        try {
+            return {{ bytes_type.lower("value") }};
+        } catch (Exception e) {
+            throw new RuntimeException(e);
+        }
+    }
+}
+{%- else %}
+{%- call java::suppress_warnings() %}
 public enum FfiConverterByteArray implements FfiConverterRustBuffer<byte[]>{
   INSTANCE;
 
     @Override
     public byte[] read(ByteBuffer buf) {
-        int len = buf.getInt();
-        byte[] byteArr = new byte[len];
-        buf.get(byteArr);
-        return byteArr;
+        return RustBufferReader.readBytes(buf);
     }
   
     @Override
@@ -26,3 +70,4 @@ public enum FfiConverterByteArray implements FfiConverterRustBuffer<byte[]>{
         buf.put(value);
     }
 }
+{%- endmatch %}
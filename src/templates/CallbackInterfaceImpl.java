@@ -16,46 +16,46 @@ import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 // Put the implementation in an object so we don't pollute the top-level namespace
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class {{ trait_impl }} {
     public static final {{ trait_impl }} INSTANCE = new {{ trait_impl }}();
+    // Created when registering rather than with `INSTANCE`, a JNA structure loads JNA's native
+    // library and this class can be initialized before that's allowed, e.g. at native-image build time.
     {{ vtable|ffi_type_name_by_value(config, ci) }} vtable;
     
-    {{ trait_impl }}() {
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    void register(UniffiLib lib) {
         vtable = new {{ vtable|ffi_type_name_by_value(config, ci) }}(
             {%- for (ffi_callback, meth) in vtable_methods.iter() %}
-            {{ meth.name()|var_name }}.INSTANCE,
+            {{ meth.name()|var_name(config) }}.INSTANCE,
             {%- endfor %}
             UniffiFree.INSTANCE
         );
-    }
-    
-    // Registers the foreign callback with the Rust side.
-    // This method is generated for each callback interface.
-    void register(UniffiLib lib) {
         lib.{{ ffi_init_callback.name() }}(vtable);
     }        
 
     {%- for (ffi_callback, meth) in vtable_methods.iter() %}
-    {% let inner_method_class = meth.name()|var_name %}{% if config.quarkus %}
+    {% let inner_method_class = meth.name()|var_name(config) %}{% if config.quarkus %}
     @RegisterForReflection{%- endif %}
-    public static class {{ inner_method_class }} implements {{ ffi_callback.name()|ffi_callback_name }} {
+    public static class {{ inner_method_class }} implements {{ ffi_callback.name()|ffi_callback_name(config) }} {
         public static final {{ inner_method_class }} INSTANCE = new {{ inner_method_class }}();
         private {{ inner_method_class }}() {}
 
         @Override
         public {% match ffi_callback.return_type() %}{% when Some(return_type) %}{{ return_type|ffi_type_name_for_ffi_struct(config, ci) }}{% when None %}void{% endmatch %} callback(
             {%- for arg in ffi_callback.arguments() -%}
-            {{ arg.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ arg.name().borrow()|var_name }}{% if !loop.last || (loop.last && ffi_callback.has_rust_call_status_arg()) %},{% endif %}
+            {{ arg.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ arg.name().borrow()|var_name(config) }}{% if !loop.last || (loop.last && ffi_callback.has_rust_call_status_arg()) %},{% endif %}
             {%- endfor -%}
             {%- if ffi_callback.has_rust_call_status_arg() -%}
-            UniffiRustCallStatus uniffiCallStatus
+            {{ "UniffiRustCallStatus"|runtime_type(config) }} {{ "CallStatus"|internal(config) }}
             {%- endif -%}
         ) {
-            var uniffiObj = {{ ffi_converter_name }}.INSTANCE.handleMap.get(uniffiHandle);
+            var {{ "Obj"|internal(config) }} = {{ ffi_converter_name }}.INSTANCE.handleMap.get(uniffiHandle);
             {% if !meth.is_async() && meth.throws_type().is_some() %}Callable{% else %}Supplier{%endif%}<{% if meth.is_async() %}{{ meth|async_return_type(ci, config) }}{% else %}{% match meth.return_type() %}{% when Some(return_type)%}{{ return_type|type_name(ci, config)}}{% when None %}Void{% endmatch %}{% endif %}> makeCall = () -> {
-                {% if meth.return_type().is_some() || meth.is_async() %}return {% endif %}uniffiObj.{{ meth.name()|fn_name() }}(
+                {% if meth.return_type().is_some() || meth.is_async() %}return {% endif %}{{ "Obj"|internal(config) }}.{{ meth.name()|callable_name(name, config) }}(
                     {%- for arg in meth.arguments() %}
-                    {{ arg|lift_fn(config, ci) }}({{ arg.name()|var_name }}){% if !loop.last %},{% endif %}
+                    {{ arg|lift_fn(config, ci) }}({{ arg.name()|var_name(config) }}){% if !loop.last %},{% endif %}
                     {%- endfor %}
                 );
                 {% if meth.return_type().is_none() && !meth.is_async() %}return null;{% endif %}
@@ -70,37 +70,38 @@ public class {{ trait_impl }} {
 
             {%- match meth.throws_type() %}
             {%- when None %}
-            UniffiHelpers.uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn);
+            UniffiHelpers.uniffiTraitInterfaceCall({{ "CallStatus"|internal(config) }}, makeCall, writeReturn);
             {%- when Some(error_type) %}
             UniffiHelpers.uniffiTraitInterfaceCallWithError(
-                uniffiCallStatus,
+                {{ "CallStatus"|internal(config) }},
                 makeCall,
                 writeReturn,
                 ({{error_type|type_name(ci, config) }} e) -> { return {{ error_type|lower_fn(config, ci) }}(e); },
-                {{error_type|type_name(ci, config)}}.class
+                {{error_type|type_name(ci, config)}}.class,
+                {{ self::map_callback_exception(config, ci, error_type) }}
             );
             {%- endmatch %}
 
             {%- else %}
-            Consumer<{{ meth|async_inner_return_type(ci, config) }}> uniffiHandleSuccess = ({% match meth.return_type() %}{%- when Some(return_type) %}returnValue{%- when None %}nothing{% endmatch %}) -> {
-                var uniffiResult = new {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}.UniffiByValue(
+            Consumer<{{ meth|async_inner_return_type(ci, config) }}> {{ "HandleSuccess"|internal(config) }} = ({% match meth.return_type() %}{%- when Some(return_type) %}returnValue{%- when None %}nothing{% endmatch %}) -> {
+                var {{ "Result"|internal(config) }} = new {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name(config) }}.UniffiByValue(
                     {%- match meth.return_type() %}
                     {%- when Some(return_type) %}
                     {{ return_type|lower_fn(config, ci) }}(returnValue),
                     {%- when None %}
                     {%- endmatch %}
-                    new UniffiRustCallStatus.ByValue()
+                    new {{ "UniffiRustCallStatus"|runtime_type(config) }}.ByValue()
                 );
-                uniffiResult.write();
-                uniffiFutureCallback.callback(uniffiCallbackData, uniffiResult);
+                {{ "Result"|internal(config) }}.write();
+                uniffiFutureCallback.callback(uniffiCallbackData, {{ "Result"|internal(config) }});
             };
-            Consumer<UniffiRustCallStatus.ByValue> uniffiHandleError = (callStatus) -> {
+            Consumer<{{ "UniffiRustCallStatus"|runtime_type(config) }}.ByValue> {{ "HandleError"|internal(config) }} = (callStatus) -> {
                 uniffiFutureCallback.callback(
                     uniffiCallbackData,
-                    new {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}.UniffiByValue(
+                    new {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name(config) }}.UniffiByValue(
                         {%- match meth.return_type() %}
                         {%- when Some(return_type) %}
-                        {{ return_type.into()|ffi_default_value }},
+                        {{ return_type.into()|ffi_default_value(config) }},
                         {%- when None %}
                         {%- endmatch %}
                         callStatus
@@ -113,16 +114,17 @@ public class {{ trait_impl }} {
                 {%- when None %}
                 UniffiAsyncHelpers.uniffiTraitInterfaceCallAsync(
                     makeCall,
-                    uniffiHandleSuccess,
-                    uniffiHandleError
+                    {{ "HandleSuccess"|internal(config) }},
+                    {{ "HandleError"|internal(config) }}
                 )
                 {%- when Some(error_type) %}
                 UniffiAsyncHelpers.uniffiTraitInterfaceCallAsyncWithError(
                     makeCall,
-                    uniffiHandleSuccess,
-                    uniffiHandleError,
+                    {{ "HandleSuccess"|internal(config) }},
+                    {{ "HandleError"|internal(config) }},
                     ({{error_type|type_name(ci, config) }} e) -> {{ error_type|lower_fn(config, ci) }}(e),
-                    {{ error_type|type_name(ci, config)}}.class
+                    {{ error_type|type_name(ci, config)}}.class,
+                    {{ self::map_callback_exception(config, ci, error_type) }}
                 )
                 {%- endmatch %}
             );
@@ -132,7 +134,7 @@ public class {{ trait_impl }} {
     {%- endfor %}
     {% if config.quarkus %}
     @RegisterForReflection{%- endif %}
-    public static class UniffiFree implements {{ "CallbackInterfaceFree"|ffi_callback_name }} {
+    public static class UniffiFree implements {{ "CallbackInterfaceFree"|ffi_callback_name(config) }} {
         public static final UniffiFree INSTANCE = new UniffiFree();
 
         private UniffiFree() {}
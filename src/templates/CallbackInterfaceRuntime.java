@@ -2,6 +2,7 @@ package {{ config.package_name() }};
 
 import java.nio.ByteBuffer;
 
+{%- call java::suppress_warnings() %}
 public abstract class FfiConverterCallbackInterface<CallbackInterface extends Object> implements FfiConverter<CallbackInterface, Long> {
     // Magic number for the Rust proxy to call using the same mechanism as every other method,
     // to free the callback once it's dropped by Rust.
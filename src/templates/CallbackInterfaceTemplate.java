@@ -5,6 +5,8 @@
 {%- let methods = cbi.methods() %}
 {%- let vtable = cbi.vtable() %}
 {%- let vtable_methods = cbi.vtable_methods() %}
+{%- let functional_interface = methods.len() == 1 %}
+{%- let results_as_values = false %}
 
 {% include "Interface.java" %}
 {% include "CallbackInterfaceImpl.java" %}
@@ -14,9 +16,50 @@ package {{ config.package_name() }};
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 // The ffiConverter which transforms the Callbacks in to handles to pass to Rust.{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public final class {{ ffi_converter_name }} extends FfiConverterCallbackInterface<{{ interface_name }}> {
   static final {{ ffi_converter_name }} INSTANCE = new {{ ffi_converter_name }}();
 
   private {{ ffi_converter_name }}() {}
 }
 
+{%- match cbi|flow_publisher(ci, config) %}
+{%- when Some(publisher) %}
+{%- let method_name = publisher.0.clone() %}
+{%- let item_type = publisher.1.clone() %}
+
+package {{ config.package_name() }};
+
+import java.util.concurrent.Flow;
+import java.util.concurrent.SubmissionPublisher;
+
+/**
+ * A {@link Flow.Publisher} of the values Rust calls {@link {{ interface_name }}#{{ method_name }}} with,
+ * generated with {@code flow_publishers}. Pass it to Rust as the {@code {{ interface_name }}} and
+ * subscribe to it.
+ *
+ * <p>Values are delivered on the executor of a {@link SubmissionPublisher}. Backpressure is best
+ * effort: when a subscriber's buffer is full, the Rust thread calling {@code {{ method_name }}} blocks
+ * until it has room. Closing the publisher completes the subscriptions.
+ */
+{%- call java::suppress_warnings() %}
+public final class {{ interface_name }}Publisher implements {{ interface_name }}, Flow.Publisher<{{ item_type }}>, AutoCloseable {
+  private final SubmissionPublisher<{{ item_type }}> publisher = new SubmissionPublisher<>();
+
+  @Override
+  public void {{ method_name }}({{ item_type }} value) {
+    publisher.submit(value);
+  }
+
+  @Override
+  public void subscribe(Flow.Subscriber<? super {{ item_type }}> subscriber) {
+    publisher.subscribe(subscriber);
+  }
+
+  @Override
+  public void close() {
+    publisher.close();
+  }
+}
+{%- when None %}
+{%- endmatch %}
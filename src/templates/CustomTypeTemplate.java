@@ -11,6 +11,7 @@ import java.util.Map;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public record {{ type_name }}(
   {{ builtin|type_name(ci, config) }} value
 ) {
@@ -23,6 +24,7 @@ import com.sun.jna.Pointer;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum {{ ffi_converter_name }} implements FfiConverter<{{ type_name }}, {{ ffi_type_name}}> {
   INSTANCE;
   @Override
@@ -58,46 +60,57 @@ public enum {{ ffi_converter_name }} implements FfiConverter<{{ type_name }}, {{
   When the config specifies a different type name, use that other type inside our newtype.
   Lift/lower using their configured code.
 #}
-{%- match custom_type_config.type_name %}
+{%- match custom_type_config.type_name_for(ci, config) %}
 {%- when Some(concrete_type_name) %}
 
 package {{ package_name }};
 
-{%- match custom_type_config.imports %}
-{%- when Some(imports) %}
-{%- for import_name in imports %}
+{%- for import_name in custom_type_config.imports_for(ci, config) %}
 import {{ import_name }};
 {%- endfor %}
-{%- else %}
-{%- endmatch %}
 import java.util.List;
 import java.util.Map;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public record {{ type_name }}(
   {{ concrete_type_name }} value
 ) {}
 
 {%- else %}
+{%- if custom_type_config.converter_class.is_some() %}
+
+package {{ package_name }};
+
+import java.util.List;
+import java.util.Map;{% if config.quarkus %}
+import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
+{% if config.quarkus %}
+@RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
+public record {{ type_name }}(
+  {{ builtin|type_name(ci, config) }} value
+) {}
+{%- endif %}
 {%- endmatch %}
 
+{#- A configured `converter_class` lifts and lowers the type instead of a generated converter. #}
+{%- if custom_type_config.converter_class.is_none() %}
+
 package {{ package_name }};
 
 import java.nio.ByteBuffer;
 import com.sun.jna.Pointer;
 
-{%- match custom_type_config.imports %}
-{%- when Some(imports) %}
-{%- for import_name in imports %}
+{%- for import_name in custom_type_config.imports_for(ci, config) %}
 import {{ import_name }};
-{%- endfor %}
-{%- else %}
-{%- endmatch %}{% if config.quarkus %}
+{%- endfor %}{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
 // FFI converter with custom code.
+{%- call java::suppress_warnings() %}
 public enum {{ ffi_converter_name }} implements FfiConverter<{{ type_name }}, {{ ffi_type_name }}> {
     INSTANCE;
     @Override
@@ -146,4 +159,5 @@ public enum {{ ffi_converter_name }} implements FfiConverter<{{ type_name }}, {{
       }
     }
 }
+{%- endif %}
 {%- endmatch %}
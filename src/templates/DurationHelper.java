@@ -7,6 +7,7 @@ import java.time.DateTimeException;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum FfiConverterDuration implements FfiConverterRustBuffer<Duration> {
     INSTANCE;
     
@@ -1,32 +1,67 @@
+{%- let interfaces = self::implemented_interfaces(config, ci, type_) %}
 package {{ config.package_name() }};
 
 import java.util.List;
-import java.util.Map;
+import java.util.Map;{% if config.is_comparable(name) %}
+import java.util.Comparator;{%- endif %}
+{%- for import_name in config.implements_imports(name, ci) %}
+import {{ import_name }};
+{%- endfor %}
 {% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {%- if e.is_flat() %}
-{% call java::docstring(e, 0) %}
+{% call java::deprecated_docstring(e, 0, "") %}
 {% match e.variant_discr_type() %}
 {% when None %}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
-public enum {{ type_name }} {
+{%- call java::suppress_warnings() %}
+public enum {{ type_name }} {% if !interfaces.is_empty() %}implements {{ interfaces|join(", ") }} {% endif %}{
   {%- for variant in e.variants() -%}
   {%- call java::docstring(variant, 4) %}
-  {{ variant|variant_name}}{% if loop.last %};{% else %},{% endif %}
+  {{ variant|variant_name}}{% if loop.last && !config.enum_forward_compat %};{% else %},{% endif %}
   {%- endfor %}
+  {%- if config.enum_forward_compat %}
+  /**
+   * A variant added to the Rust enum after these bindings were generated.
+   *
+   * It can be received from Rust but not passed back to it.
+   */
+  UNKNOWN;
+  {%- else if e.variants().is_empty() %}
+  ;
+  {%- endif %}
+  {%- call java::enum_variant_constants(e) %}
+  {%- call java::enum_visitor(e, type_name) %}
+  {%- call java::enum_discriminant_order(e, type_name) %}
+  {%- call java::parcelable(type_, type_name, false) %}
 }
 {% when Some with (variant_discr_type) %}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
-public enum {{ type_name }} {
+{%- call java::suppress_warnings() %}
+public enum {{ type_name }} {% if !interfaces.is_empty() %}implements {{ interfaces|join(", ") }} {% endif %}{
   {% for variant in e.variants() -%}
   {%- call java::docstring(variant, 4) %}
-  {{ variant|variant_name}}({{ e|variant_discr_literal(loop.index0)}}){% if loop.last %};{% else %},{% endif %}}
+  {{ variant|variant_name}}({{ e|variant_discr_literal(loop.index0)}}){% if loop.last && !config.enum_forward_compat %};{% else %},{% endif %}
   {%- endfor %}
+  {%- if config.enum_forward_compat %}
+  /**
+   * A variant added to the Rust enum after these bindings were generated.
+   *
+   * It can be received from Rust but not passed back to it.
+   */
+  UNKNOWN(null);
+  {%- else if e.variants().is_empty() %}
+  ;
+  {%- endif %}
+  {%- call java::enum_variant_constants(e) %}
+  {%- call java::enum_visitor(e, type_name) %}
+  {%- call java::enum_discriminant_order(e, type_name) %}
 
   private final {{ variant_discr_type|type_name(ci, config) }} value;
   {{type_name}}({{ variant_discr_type|type_name(ci, config) }} value) {
     this.value = value;
   }
+  {%- call java::parcelable(type_, type_name, false) %}
 }
 {% endmatch %}
 
@@ -36,16 +71,29 @@ import java.nio.ByteBuffer;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum {{ e|ffi_converter_name}} implements FfiConverterRustBuffer<{{ type_name }}> {
     INSTANCE;
 
+    // `values()` clones the backing array on every call, so keep a single copy to index into.
+    private static final {{ type_name }}[] VALUES = {{ type_name }}.values();
+
     @Override
     public {{ type_name }} read(ByteBuffer buf) {
+        {%- if config.enum_forward_compat %}
+        int index = buf.getInt() - 1;
+        // The last value is `UNKNOWN`, which Rust never sends.
+        if (index < 0 || index >= VALUES.length - 1) {
+            return {{ type_name }}.UNKNOWN;
+        }
+        return VALUES[index];
+        {%- else %}
         try {
-            return {{ type_name }}.values()[buf.getInt() - 1];
+            return VALUES[buf.getInt() - 1];
         } catch (IndexOutOfBoundsException e) {
             throw new RuntimeException("invalid enum value, something is very wrong!!", e);
         }
+        {%- endif %}
     }
 
     @Override
@@ -55,17 +103,29 @@ public enum {{ e|ffi_converter_name}} implements FfiConverterRustBuffer<{{ type_
 
     @Override
     public void write({{ type_name }} value, ByteBuffer buf) {
+        {%- if config.enum_forward_compat %}
+        if (value == {{ type_name }}.UNKNOWN) {
+            throw new IllegalArgumentException("{{ type_name }}.UNKNOWN can't be passed to Rust");
+        }
+        {%- endif %}
         buf.putInt(value.ordinal() + 1);
     }
 }
 
 {% else %}
 
-{%- call java::docstring(e, 0) %}{% if config.quarkus %}
+{#-
+  Each variant is its own `record`, so the compiler generated `equals`/`hashCode` already give value
+  semantics. Record `equals` requires the other object to be an instance of the same record class,
+  which means two different variants are never equal, even when their field values overlap.
+#}
+{%- call java::deprecated_docstring(e, 0, "") %}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
-public sealed interface {{ type_name }}{% if contains_object_references %} extends AutoCloseable {% endif %} {
+{%- call java::suppress_warnings() %}
+public sealed interface {{ type_name }}{% if !interfaces.is_empty() %} extends {{ interfaces|join(", ") }} {% endif %} {
   {% for variant in e.variants() -%}
-  {%- call java::docstring(variant, 4) %}
+  {%- let variant_name = variant|type_name(ci, config) %}
+  {%- call java::docstring_value(self::params_docstring(variant.docstring(), variant.fields(), config), 4) %}
   {% if !variant.has_fields() -%}
   record {{ variant|type_name(ci, config)}}() implements {{ type_name }} {
     {% if contains_object_references %}
@@ -74,11 +134,18 @@ public sealed interface {{ type_name }}{% if contains_object_references %} exten
       // Nothing to destroy
     }
     {% endif %}
+    {%- call java::enum_tag(variant, type_name) %}
+    {%- if config.has_tostring_template() %}
+
+    @Override
+    public String toString() {
+      return {{ self::tostring_expr(config, variant_name, variant.fields()) }};
+    }
+    {%- endif %}
   }
   {% else -%}
   record {{ variant|type_name(ci, config)}}(
-    {%- for field in variant.fields()  -%}
-    {%- call java::docstring(field, 8) %}
+    {%- for field in variant.fields() %}
     {{ field|type_name(ci, config)}} {% call java::field_name(field, loop.index) %}{% if loop.last %}{% else %}, {% endif %}
     {%- endfor -%}
   ) implements {{ type_name }} {
@@ -88,10 +155,60 @@ public sealed interface {{ type_name }}{% if contains_object_references %} exten
       {% call java::destroy_fields(variant) %}
     }
     {% endif %}
+    {%- if config.positional_accessors %}
+    {%- for field in variant.fields() %}
+    {%- if field.name().is_empty() %}
+
+    public {{ field|type_name(ci, config) }} component{{ loop.index }}() {
+      return v{{ loop.index }};
+    }
+    {%- endif %}
+    {%- endfor %}
+    {%- endif %}
+    {%- call java::enum_tag(variant, type_name) %}
+    {%- if config.has_tostring_template() %}
+
+    @Override
+    public String toString() {
+      return {{ self::tostring_expr(config, variant_name, variant.fields()) }};
+    }
+    {%- endif %}
   }
   {%- endif %}
   {% endfor %}
+  {%- call java::enum_variant_constants(e) %}
+
+  /**
+   * The classes of the variants, in declaration order, to enumerate them like {@code values()} of
+   * a Java enum.
+   */
+  public static List<Class<? extends {{ type_name }}>> variants() {
+    return List.of(
+      {%- for variant in e.variants() %}{{ type_name }}.{{ variant|type_name(ci, config) }}.class{% if !loop.last %}, {% endif %}{% endfor -%}
+    );
+  }
+  {%- if config.enum_with_tag %}
+
+  /**
+   * The tag of the variant, to switch on without pattern matching.
+   */
+  {{ type_name }}Kind kind();
+  {%- endif %}
+  {%- call java::parcelable(type_, type_name, true) %}
 }
+{%- if config.enum_with_tag %}
+
+package {{ config.package_name() }};
+
+/**
+ * The tags of the variants of {@link {{ type_name }}}, returned by {@link {{ type_name }}#kind()}.
+ */
+public enum {{ type_name }}Kind {
+  {%- for variant in e.variants() %}
+  {{ variant|variant_name }}{% if loop.last %};{% else %},{% endif %}
+  {%- endfor %}
+}
+{%- endif %}
 
 package {{ config.package_name() }};
 
@@ -99,6 +216,7 @@ import java.nio.ByteBuffer;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum {{ e|ffi_converter_name}} implements FfiConverterRustBuffer<{{ type_name }}> {
     INSTANCE;
 
@@ -144,6 +262,13 @@ public enum {{ e|ffi_converter_name}} implements FfiConverterRustBuffer<{{ type_
         {%- endfor %}
       };
     }
+    {%- if self::grows_write_buffer(config, ci, type_) %}
+
+    @Override
+    public {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer({{ type_name }} value) {
+        return {{ "LowerIntoGrowingBuffer"|internal(config) }}(value);
+    }
+    {%- endif %}
 }
 
 {% endif %}
@@ -6,8 +6,9 @@ import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {%- let canonical_type_name = type_|canonical_name %}
 
 {% if e.is_flat() %}
-{%- call java::docstring(e, 0) %}{% if config.quarkus %}
+{%- call java::deprecated_docstring(e, 0, "") %}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class {{ type_name }} extends Exception {
     private {{ type_name }}(String message) {
       super(message);
@@ -25,8 +26,9 @@ public class {{ type_name }} extends Exception {
 
 
 {%- else %}
-{%- call java::docstring(e, 0) %}{% if config.quarkus %}
+{%- call java::deprecated_docstring(e, 0, "") %}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class {{ type_name }} extends Exception {
     private {{ type_name }}(String message) {
       super(message); 
@@ -85,9 +87,10 @@ package {{ config.package_name() }};{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class {{ type_name }}ErrorHandler implements UniffiRustCallStatusErrorHandler<{{ type_name }}> {
   @Override
-  public {{ type_name }} lift(RustBuffer.ByValue errorBuf){
+  public {{ type_name }} lift({{ "RustBuffer"|runtime_type(config) }}.ByValue errorBuf){
      return {{ ffi_converter_instance }}.lift(errorBuf);
   }
 }
@@ -98,6 +101,7 @@ import java.nio.ByteBuffer;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum {{ e|ffi_converter_name }} implements FfiConverterRustBuffer<{{ type_name }}> {
     INSTANCE;
 
@@ -159,4 +163,11 @@ public enum {{ e|ffi_converter_name }} implements FfiConverterRustBuffer<{{ type
             default -> throw new RuntimeException("invalid error enum value, something is very wrong!!");
         };
     }
+    {%- if self::grows_write_buffer(config, ci, type_) %}
+
+    @Override
+    public {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer({{ type_name }} value) {
+        return {{ "LowerIntoGrowingBuffer"|internal(config) }}(value);
+    }
+    {%- endif %}
 }
@@ -4,32 +4,48 @@ import java.nio.ByteBuffer;
 import java.nio.ByteOrder;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForProxy;{%- endif %}
 
-// The FfiConverter interface handles converter types to and from the FFI
-//
-// All implementing objects should be public to support external types.  When a
-// type is external we need to import it's FfiConverter.{% if config.quarkus %}
+/**
+ * Converts a Java type to and from the FFI.
+ *
+ * <p>Every generated type has a public converter named {@code FfiConverter<canonical name>}, like
+ * {@code FfiConverterString} or {@code FfiConverterTypeMyRecord}, with a single {@code INSTANCE}.
+ * They have to be public to support external types, which import the converter of the crate that
+ * defines them, and can also be used to pass values to other FFI layers. Values lowered into a
+ * {@code RustBuffer} must be lifted exactly once, lifting frees the buffer.
+ */{% if config.quarkus %}
 @RegisterForProxy{%- endif %}
+{%- call java::suppress_warnings() %}
 public interface FfiConverter<JavaType, FfiType> {
-    // Convert an FFI type to a Java type
+    /**
+     * Convert an FFI value to a Java value, taking ownership of it.
+     */
     JavaType lift(FfiType value);
 
-    // Convert an Java type to an FFI type
+    /**
+     * Convert a Java value to an FFI value, the receiver of which takes ownership of it.
+     */
     FfiType lower(JavaType value);
 
-    // Read a Java type from a `ByteBuffer`
+    /**
+     * Read a Java value from a {@code ByteBuffer}, as serialized by the Rust side.
+     */
     JavaType read(ByteBuffer buf);
 
-    // Calculate bytes to allocate when creating a `RustBuffer`
-    //
-    // This must return at least as many bytes as the write() function will
-    // write. It can return more bytes than needed, for example when writing
-    // Strings we can't know the exact bytes needed until we the UTF-8
-    // encoding, so we pessimistically allocate the largest size possible (3
-    // bytes per codepoint).  Allocating extra bytes is not really a big deal
-    // because the `RustBuffer` is short-lived.
+    /**
+     * Calculate bytes to allocate when creating a {@code RustBuffer}.
+     *
+     * <p>This must return at least as many bytes as the write() function will
+     * write. It can return more bytes than needed, for example when writing
+     * Strings we can't know the exact bytes needed until we the UTF-8
+     * encoding, so we pessimistically allocate the largest size possible (3
+     * bytes per codepoint).  Allocating extra bytes is not really a big deal
+     * because the {@code RustBuffer} is short-lived.
+     */
     long allocationSize(JavaType value);
 
-    // Write a Java type to a `ByteBuffer`
+    /**
+     * Write a Java value to a {@code ByteBuffer}, as the Rust side deserializes it.
+     */
     void write(JavaType value, ByteBuffer buf);
 
     // Lower a value into a `RustBuffer`
@@ -38,8 +54,14 @@ public interface FfiConverter<JavaType, FfiType> {
     // FfiType.  It's used by the callback interface code.  Callback interface
     // returns are always serialized into a `RustBuffer` regardless of their
     // normal FFI type.
-    default RustBuffer.ByValue lowerIntoRustBuffer(JavaType value) {
-        RustBuffer.ByValue rbuf = RustBuffer.alloc(allocationSize(value));
+    default {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer(JavaType value) {
+        long size = allocationSize(value);
+        // A `ByteBuffer` is indexed by `int`, so bigger values can't be written into one.
+        if (size > Integer.MAX_VALUE) {
+            throw new IllegalArgumentException(
+                "Value needs " + size + " bytes, more than the " + Integer.MAX_VALUE + " a RustBuffer can be written with");
+        }
+        {{ "RustBuffer"|runtime_type(config) }}.ByValue rbuf = UniffiHelpers.uniffiRustBufferAlloc(size);
         try {
             ByteBuffer bbuf = rbuf.data.getByteBuffer(0, rbuf.capacity);
             bbuf.order(ByteOrder.BIG_ENDIAN);
@@ -47,16 +69,56 @@ public interface FfiConverter<JavaType, FfiType> {
             rbuf.writeField("len", (long)bbuf.position());
             return rbuf;
         } catch (Throwable e) {
-            RustBuffer.free(rbuf);
+            UniffiHelpers.uniffiRustBufferFree(rbuf);
+            throw e;
+        }
+    }
+
+    {%- match config.buffer_growth_factor_literal() %}
+    {%- when Some(growth_factor) %}
+
+    // Lower a value into a `RustBuffer` without computing its size first, with
+    // `buffer_growth_factor`.
+    //
+    // The value is written into a heap buffer, starting over in one `buffer_growth_factor` times
+    // bigger whenever it runs out of space, and what was written is copied into a `RustBuffer`.
+    // Converters of types that don't lower object or callback handles use it for
+    // `lowerIntoRustBuffer()`, writing those again would leak the handles written first.
+    default {{ "RustBuffer"|runtime_type(config) }}.ByValue {{ "LowerIntoGrowingBuffer"|internal(config) }}(JavaType value) {
+        ByteBuffer bbuf = ByteBuffer.allocate(256);
+        while (true) {
+            bbuf.order(ByteOrder.BIG_ENDIAN);
+            try {
+                write(value, bbuf);
+                break;
+            } catch (java.nio.BufferOverflowException e) {
+                // A `ByteBuffer` is indexed by `int`, so bigger values can't be written into one.
+                if (bbuf.capacity() == Integer.MAX_VALUE) {
+                    throw new IllegalArgumentException(
+                        "Value needs more than the " + Integer.MAX_VALUE + " bytes a RustBuffer can be written with");
+                }
+                bbuf = ByteBuffer.allocate((int) Math.min(Integer.MAX_VALUE, (long) Math.ceil(bbuf.capacity() * {{ growth_factor }})));
+            }
+        }
+        bbuf.flip();
+        {{ "RustBuffer"|runtime_type(config) }}.ByValue rbuf = UniffiHelpers.uniffiRustBufferAlloc(bbuf.remaining());
+        try {
+            rbuf.data.getByteBuffer(0, rbuf.capacity).put(bbuf);
+            rbuf.writeField("len", (long)bbuf.limit());
+            return rbuf;
+        } catch (Throwable e) {
+            UniffiHelpers.uniffiRustBufferFree(rbuf);
             throw e;
         }
     }
+    {%- when None %}
+    {%- endmatch %}
 
     // Lift a value from a `RustBuffer`.
     //
     // This here mostly because of the symmetry with `lowerIntoRustBuffer()`.
     // It's currently only used by the `FfiConverterRustBuffer` class below.
-    default JavaType liftFromRustBuffer(RustBuffer.ByValue rbuf) {
+    default JavaType liftFromRustBuffer({{ "RustBuffer"|runtime_type(config) }}.ByValue rbuf) {
         ByteBuffer byteBuf = rbuf.asByteBuffer();
         try {
            JavaType item = read(byteBuf);
@@ -65,7 +127,7 @@ public interface FfiConverter<JavaType, FfiType> {
            }
            return item;
         } finally {
-            RustBuffer.free(rbuf);
+            UniffiHelpers.uniffiRustBufferFree(rbuf);
         }
     }
 }
@@ -75,13 +137,14 @@ package {{ config.package_name() }};
 import io.quarkus.runtime.annotations.RegisterForProxy;{%- endif %}
 // FfiConverter that uses `RustBuffer` as the FfiType{% if config.quarkus %}
 @RegisterForProxy{%- endif %}
-public interface FfiConverterRustBuffer<JavaType> extends FfiConverter<JavaType, RustBuffer.ByValue> {
+{%- call java::suppress_warnings() %}
+public interface FfiConverterRustBuffer<JavaType> extends FfiConverter<JavaType, {{ "RustBuffer"|runtime_type(config) }}.ByValue> {
     @Override
-    default JavaType lift(RustBuffer.ByValue value) {
+    default JavaType lift({{ "RustBuffer"|runtime_type(config) }}.ByValue value) {
         return liftFromRustBuffer(value);
     }
     @Override
-    default RustBuffer.ByValue lower(JavaType value) {
+    default {{ "RustBuffer"|runtime_type(config) }}.ByValue lower(JavaType value) {
         return lowerIntoRustBuffer(value);
     }
 }
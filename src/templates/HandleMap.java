@@ -6,6 +6,7 @@ import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 
 // This is used pass an opaque 64-bit handle representing a foreign object to the Rust code.{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 class UniffiHandleMap<T extends Object> {
     private final ConcurrentHashMap<Long, T> map = new ConcurrentHashMap<>();
     private final AtomicLong counter = new AtomicLong(0);
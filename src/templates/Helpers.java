@@ -1,4 +1,4 @@
-package {{ config.package_name() }};
+package {{ config.runtime_package() }};
 
 import com.sun.jna.Structure;
 import com.sun.jna.Pointer;{% if config.quarkus %}
@@ -7,6 +7,7 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
 {% if config.quarkus %}
 @RegisterForReflection(registerFullHierarchy = true){%- endif %}
 @Structure.FieldOrder({ "code", "error_buf" })
+{%- call java::suppress_warnings() %}
 public class UniffiRustCallStatus extends Structure {
     public byte code;
     public RustBuffer.ByValue error_buf;
@@ -52,11 +53,32 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
 {%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection(registerFullHierarchy = true){%- endif %}
+{%- call java::suppress_warnings() %}
 public class InternalException extends RuntimeException {
     public InternalException(String message) {
         super(message);
     }
 }
+{%- match config.generated_panic_exception() %}
+{%- when Some(panic_exception) %}
+
+package {{ config.package_name() }};
+{% if config.quarkus %}
+import io.quarkus.runtime.annotations.RegisterForReflection;
+{%- endif %}
+
+/**
+ * Thrown when Rust panics, set with `panic_exception`.
+ */{% if config.quarkus %}
+@RegisterForReflection(registerFullHierarchy = true){%- endif %}
+{%- call java::suppress_warnings() %}
+public class {{ panic_exception }} extends InternalException {
+    public {{ panic_exception }}(String message) {
+        super(message);
+    }
+}
+{%- else %}
+{%- endmatch %}
 
 package {{ config.package_name() }};
 {% if config.quarkus %}
@@ -64,8 +86,9 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
 {%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection(registerFullHierarchy = true){%- endif %}
+{%- call java::suppress_warnings() %}
 public interface UniffiRustCallStatusErrorHandler<E extends Exception> {
-    E lift(RustBuffer.ByValue errorBuf);
+    E lift({{ "RustBuffer"|runtime_type(config) }}.ByValue errorBuf);
 }
 
 package {{ config.package_name() }};
@@ -75,10 +98,11 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
 {% if config.quarkus %}
 @RegisterForReflection(registerFullHierarchy = true){%- endif %}
 // UniffiRustCallStatusErrorHandler implementation for times when we don't expect a CALL_ERROR
+{%- call java::suppress_warnings() %}
 class UniffiNullRustCallStatusErrorHandler implements UniffiRustCallStatusErrorHandler<InternalException> {
     @Override
-    public InternalException lift(RustBuffer.ByValue errorBuf) {
-        RustBuffer.free(errorBuf);
+    public InternalException lift({{ "RustBuffer"|runtime_type(config) }}.ByValue errorBuf) {
+        UniffiHelpers.uniffiRustBufferFree(errorBuf);
         return new InternalException("Unexpected CALL_ERROR");
     }
 }
@@ -97,24 +121,25 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
 // synchronize itself
 {% if config.quarkus %}
 @RegisterForReflection(registerFullHierarchy = true){%- endif %}
+{%- call java::suppress_warnings() %}
 public final class UniffiHelpers {
   // Call a rust function that returns a Result<>.  Pass in the Error class companion that corresponds to the Err
-  static <U, E extends Exception> U uniffiRustCallWithError(UniffiRustCallStatusErrorHandler<E> errorHandler, Function<UniffiRustCallStatus, U> callback) throws E {
-      UniffiRustCallStatus status = new UniffiRustCallStatus();
+  static <U, E extends Exception> U uniffiRustCallWithError(UniffiRustCallStatusErrorHandler<E> errorHandler, Function<{{ "UniffiRustCallStatus"|runtime_type(config) }}, U> callback) throws E {
+      {{ "UniffiRustCallStatus"|runtime_type(config) }} status = new {{ "UniffiRustCallStatus"|runtime_type(config) }}();
       U returnValue = callback.apply(status);
       uniffiCheckCallStatus(errorHandler, status);
       return returnValue;
   }
   
   // Overload to call a rust function that returns a Result<()>, because void is outside Java's type system.  Pass in the Error class companion that corresponds to the Err
-  static <E extends Exception> void uniffiRustCallWithError(UniffiRustCallStatusErrorHandler<E> errorHandler, Consumer<UniffiRustCallStatus> callback) throws E {
-      UniffiRustCallStatus status = new UniffiRustCallStatus();
+  static <E extends Exception> void uniffiRustCallWithError(UniffiRustCallStatusErrorHandler<E> errorHandler, Consumer<{{ "UniffiRustCallStatus"|runtime_type(config) }}> callback) throws E {
+      {{ "UniffiRustCallStatus"|runtime_type(config) }} status = new {{ "UniffiRustCallStatus"|runtime_type(config) }}();
       callback.accept(status);
       uniffiCheckCallStatus(errorHandler, status);
   }
 
   // Check UniffiRustCallStatus and throw an error if the call wasn't successful
-  static <E extends Exception> void uniffiCheckCallStatus(UniffiRustCallStatusErrorHandler<E> errorHandler, UniffiRustCallStatus status) throws E {
+  static <E extends Exception> void uniffiCheckCallStatus(UniffiRustCallStatusErrorHandler<E> errorHandler, {{ "UniffiRustCallStatus"|runtime_type(config) }} status) throws E {
       if (status.isSuccess()) {
           return;
       } else if (status.isError()) {
@@ -124,57 +149,94 @@ public final class UniffiHelpers {
           // with the message.  but if that code panics, then it just sends back
           // an empty buffer.
           if (status.error_buf.len > 0) {
-              throw new InternalException({{ Type::String.borrow()|lift_fn(config, ci)  }}(status.error_buf));
+              throw new {{ config.panic_exception() }}({{ Type::String.borrow()|lift_fn(config, ci)  }}(status.error_buf));
           } else {
-              throw new InternalException("Rust panic");
+              throw new {{ config.panic_exception() }}("Rust panic");
           }
       } else {
           throw new InternalException("Unknown rust call status: " + status.code);
       }
   }
 
+  // Allocate a `RustBuffer` through this component's library. This can't live on `RustBuffer` itself
+  // because that may be shared with other components through the `runtime_package`.
+  static {{ "RustBuffer"|runtime_type(config) }}.ByValue uniffiRustBufferAlloc(long size) {
+      {{ "RustBuffer"|runtime_type(config) }}.ByValue buffer = uniffiRustCall(({{ "UniffiRustCallStatus"|runtime_type(config) }} status) -> {
+          return ({{ "RustBuffer"|runtime_type(config) }}.ByValue) UniffiLib.getInstance().{{ ci.ffi_rustbuffer_alloc().name() }}(size, status);
+      });
+      if (buffer.data == null) {
+          throw new RuntimeException("RustBuffer.alloc() returned null data pointer (size=" + size + ")");
+      }
+      return buffer;
+  }
+
+  static void uniffiRustBufferFree({{ "RustBuffer"|runtime_type(config) }}.ByValue buffer) {
+      uniffiRustCall((status) -> {
+          UniffiLib.getInstance().{{ ci.ffi_rustbuffer_free().name() }}(buffer, status);
+          return null;
+      });
+  }
+
   // Call a rust function that returns a plain value
-  static <U> U uniffiRustCall(Function<UniffiRustCallStatus, U> callback) {
+  static <U> U uniffiRustCall(Function<{{ "UniffiRustCallStatus"|runtime_type(config) }}, U> callback) {
       return uniffiRustCallWithError(new UniffiNullRustCallStatusErrorHandler(), callback);
   }
   
   // Call a rust function that returns nothing
-  static void uniffiRustCall(Consumer<UniffiRustCallStatus> callback) {
+  static void uniffiRustCall(Consumer<{{ "UniffiRustCallStatus"|runtime_type(config) }}> callback) {
       uniffiRustCallWithError(new UniffiNullRustCallStatusErrorHandler(), callback);
   }
 
   static <T> void uniffiTraitInterfaceCall(
-      UniffiRustCallStatus callStatus,
+      {{ "UniffiRustCallStatus"|runtime_type(config) }} callStatus,
       Supplier<T> makeCall,
       Consumer<T> writeReturn
   ) {
       try {
           writeReturn.accept(makeCall.get());
       } catch (Exception e) {
-          callStatus.setCode(UniffiRustCallStatus.UNIFFI_CALL_UNEXPECTED_ERROR);
+          {%- if config.aborts_on_callback_exception() %}
+          uniffiAbortOnCallbackException(e);
+          {%- endif %}
+          callStatus.setCode({{ "UniffiRustCallStatus"|runtime_type(config) }}.UNIFFI_CALL_UNEXPECTED_ERROR);
           callStatus.setErrorBuf({{ Type::String.borrow()|lower_fn(config, ci) }}(e.toString()));
       }
   }
 
   static <T, E extends Throwable> void uniffiTraitInterfaceCallWithError(
-      UniffiRustCallStatus callStatus,
+      {{ "UniffiRustCallStatus"|runtime_type(config) }} callStatus,
       Callable<T> makeCall,
       Consumer<T> writeReturn,
-      Function<E, RustBuffer.ByValue> lowerError,
-      Class<E> errorClazz
+      Function<E, {{ "RustBuffer"|runtime_type(config) }}.ByValue> lowerError,
+      Class<E> errorClazz,
+      Function<Throwable, E> mapException
   ) {
       try {
           writeReturn.accept(makeCall.call());
       } catch (Exception e) {
-          if (errorClazz.isAssignableFrom(e.getClass())) {
-              @SuppressWarnings("unchecked")
-              E castedE = (E) e;
-              callStatus.setCode(UniffiRustCallStatus.UNIFFI_CALL_ERROR);
-              callStatus.setErrorBuf(lowerError.apply(castedE));
+          // Other exceptions can be mapped to the declared error with `callback_exception_mapping`.
+          E error = errorClazz.isInstance(e) ? errorClazz.cast(e) : mapException.apply(e);
+          if (error != null) {
+              callStatus.setCode({{ "UniffiRustCallStatus"|runtime_type(config) }}.UNIFFI_CALL_ERROR);
+              callStatus.setErrorBuf(lowerError.apply(error));
           } else {
-              callStatus.setCode(UniffiRustCallStatus.UNIFFI_CALL_UNEXPECTED_ERROR);
+              {%- if config.aborts_on_callback_exception() %}
+              uniffiAbortOnCallbackException(e);
+              {%- endif %}
+              callStatus.setCode({{ "UniffiRustCallStatus"|runtime_type(config) }}.UNIFFI_CALL_UNEXPECTED_ERROR);
               callStatus.setErrorBuf({{ Type::String.borrow()|lower_fn(config, ci) }}(e.toString()));
           }
       }
   }
+  {%- if config.aborts_on_callback_exception() %}
+
+  // With `on_callback_exception = "abort"`, a callback interface method throwing an exception it
+  // doesn't declare ends the process, rather than returning it to Rust as an unexpected error.
+  static void uniffiAbortOnCallbackException(Throwable e) {
+      System.err.println("Callback interface method threw an undeclared exception, aborting");
+      e.printStackTrace();
+      // The exit status of a process killed by SIGABRT.
+      Runtime.getRuntime().halt(134);
+  }
+  {%- endif %}
 }
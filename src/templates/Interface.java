@@ -7,11 +7,20 @@ import com.sun.jna.*;
 import com.sun.jna.ptr.*;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 
-{%- call java::docstring_value(interface_docstring, 0) %}{% if config.quarkus %}
-@RegisterForReflection{%- endif %}
+{%- call java::deprecated_docstring_value(interface_docstring, 0, "", name) %}{% if config.quarkus %}
+@RegisterForReflection{%- endif %}{% if functional_interface %}
+@FunctionalInterface{%- endif %}
+{%- call java::suppress_warnings() %}
 public interface {{ interface_name }} {
     {% for meth in methods.iter() -%}
-    {%- call java::docstring(meth, 4) %}
-    public {% if meth.is_async() %}CompletableFuture<{% endif %}{% match meth.return_type() -%}{%- when Some with (return_type) %}{{ return_type|type_name(ci, config) }}{%- else -%}{% if meth.is_async() %}Void{% else %}void{% endif %}{%- endmatch %}{% if meth.is_async() %}>{% endif %} {{ meth.name()|fn_name }}({% call java::arg_list(meth, true) %}){% match meth.throws_type() %}{% when Some(throwable) %} {% if !meth.is_async() %}throws {{ throwable|type_name(ci, config) }}{% endif %}{% else %}{% endmatch %};
+    {%- call java::deprecated_docstring(meth, 4, name) %}
+    {%- let as_result = results_as_values && meth.throws_type().is_some() %}
+    {%- if as_result %}
+    public {% if meth.is_async() %}CompletableFuture<{{ meth|result_type(ci, config) }}>{% else %}{{ meth|result_type(ci, config) }}{% endif %} {{ meth.name()|callable_name(name, config) }}({% call java::arg_list(meth, true) %});
+    {%- else %}
+    public {% if meth.is_async() %}CompletableFuture<{% endif %}{% match meth.return_type() -%}{%- when Some with (return_type) %}{{ return_type|type_name(ci, config) }}{%- else -%}{% if meth.is_async() %}Void{% else %}void{% endif %}{%- endmatch %}{% if meth.is_async() %}>{% endif %} {{ meth.name()|callable_name(name, config) }}({% call java::arg_list(meth, true) %}){% match meth.throws_type() %}{% when Some(throwable) %} {% if !meth.is_async() %}throws {{ throwable|type_name(ci, config) }}{% endif %}{% else %}{% endmatch %};
+    {%- endif %}
+    {%- call java::blocking_async("default", meth, as_result, name) %}
+    {%- call java::default_overloads("default", meth, 4, as_result, name) %}
     {% endfor %}
 }
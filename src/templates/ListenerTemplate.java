@@ -0,0 +1,9 @@
+package {{ config.package_name() }};
+
+/**
+ * Implements {% for interface_name in interfaces %}{@link {{ interface_name }}}{% if loop.last %}{% else if loop.index + 1 == interfaces.len() %} and {% else %}, {% endif %}{% endfor %} at
+ * once, so a single object can be passed wherever any of them is expected.
+ */
+{%- call java::suppress_warnings() %}
+public interface {{ listener }} extends {{ interfaces|join(", ") }} {
+}
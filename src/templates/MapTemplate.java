@@ -4,7 +4,8 @@ package {{ config.package_name() }};
 
 import java.nio.ByteBuffer;
 import java.util.Map;
-import java.util.HashMap;
+import java.util.HashMap;{% if config.concurrent_maps %}
+import java.util.concurrent.ConcurrentHashMap;{%- endif %}
 import java.util.List;
 import java.util.stream.IntStream;
 import java.util.stream.Stream;
@@ -12,12 +13,26 @@ import java.util.stream.Collectors;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum {{ ffi_converter_name }} implements FfiConverterRustBuffer<Map<{{ key_type_name }}, {{ value_type_name }}>> {
     INSTANCE;
 
     @Override
     public Map<{{ key_type_name }}, {{ value_type_name }}> read(ByteBuffer buf) {
-        int len = buf.getInt();
+        int len = {% call java::read_length(0) %};
+        {%- if config.concurrent_maps %}
+        ConcurrentHashMap<{{ key_type_name }}, {{ value_type_name }}> map = new ConcurrentHashMap<>(len);
+        for (int i = 0; i < len; i++) {
+            {{ key_type_name }} key = {{ key_type|read_fn(config, ci) }}(buf);
+            {{ value_type_name }} value = {{ value_type|read_fn(config, ci) }}(buf);
+            // ConcurrentHashMap can't hold nulls, so an entry with an empty optional is left out.
+            // `get` still returns null for it, like it would for a HashMap.
+            if (key != null && value != null) {
+                map.put(key, value);
+            }
+        }
+        return map;
+        {%- else %}
         // Collectors.toMap would be preferred here, but theres a bug that doesn't allow
         // null values in the map, even though that is valid Java
         return IntStream.range(0, len).boxed().collect(
@@ -28,10 +43,16 @@ public enum {{ ffi_converter_name }} implements FfiConverterRustBuffer<Map<{{ ke
             ),
             HashMap::putAll
         );
+        {%- endif %}
     }
 
     @Override
     public long allocationSize(Map<{{ key_type_name }}, {{ value_type_name }}> value) {
+        // Sizing comes before writing, so this catches a `null` before anything is written. Only an
+        // optional map can be `null`, `OptionalTemplate.java` writes it without calling this.
+        if (value == null) {
+            throw new NullPointerException("a `{{ key_type_name }}` to `{{ value_type_name }}` map that isn't optional can't be null, pass an empty map instead");
+        }
         long spaceForMapSize = 4;
         long spaceForChildren = value.entrySet().stream().mapToLong(entry ->
             {{ key_type|allocation_size_fn(config, ci) }}(entry.getKey()) +
@@ -51,4 +72,11 @@ public enum {{ ffi_converter_name }} implements FfiConverterRustBuffer<Map<{{ ke
             {{ value_type|write_fn(config, ci) }}(entry.getValue(), buf);
         }
     }
+    {%- if self::grows_write_buffer(config, ci, type_) %}
+
+    @Override
+    public {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer(Map<{{ key_type_name }}, {{ value_type_name }}> value) {
+        return {{ "LowerIntoGrowingBuffer"|internal(config) }}(value);
+    }
+    {%- endif %}
 }
@@ -2,7 +2,11 @@ package {{ config.package_name() }};
 
 import com.sun.jna.Library;
 import com.sun.jna.Native;
+import com.sun.jna.Platform;
+import com.sun.jna.Structure;
+import java.util.Map;
 
+{%- call java::suppress_warnings() %}
 final class NamespaceLibrary {
   static synchronized String findLibraryName(String componentName) {
     String libOverride = System.getProperty("uniffi.component." + componentName + ".libraryOverride");
@@ -13,7 +17,25 @@ final class NamespaceLibrary {
   }
 
   static <Lib extends Library> Lib loadIndirect(String componentName, Class<Lib> clazz) {
-    return Native.load(findLibraryName(componentName), clazz);
+    // JNA caches native libraries by name and options. Loading through our own classloader keeps
+    // bindings that share a library name, but live in different classloaders, from sharing an instance.
+    Map<String, Object> options = Map.of(
+      Library.OPTION_CLASSLOADER, NamespaceLibrary.class.getClassLoader(),
+      Library.OPTION_STRING_ENCODING, {{ config.jna_options.string_encoding() }},
+      Library.OPTION_STRUCTURE_ALIGNMENT, {{ config.jna_options.structure_alignment() }});
+    String libraryName = findLibraryName(componentName);
+    try {
+      return Native.load(libraryName, clazz, options);
+    } catch (UnsatisfiedLinkError e) {
+      // JNA's message lists every path it tried, lead with what we were looking for.
+      String fileName = System.mapLibraryName(libraryName);
+      UnsatisfiedLinkError error = new UnsatisfiedLinkError(
+        "Unable to load the native library '" + libraryName + "' of " + componentName + " for "
+          + Platform.RESOURCE_PREFIX + ": expected " + fileName + " on the library path or as the resource "
+          + Platform.RESOURCE_PREFIX + "/" + fileName + ". " + e.getMessage());
+      error.initCause(e);
+      throw error;
+    }
   }
 
   static void uniffiCheckContractApiVersion(UniffiLib lib) {
@@ -47,13 +69,14 @@ import io.quarkus.runtime.annotations.RegisterForProxy;
 {%- endif %}
 {% if config.quarkus %}
 @RegisterForProxy{%- endif %}
-interface {{ callback.name()|ffi_callback_name }} extends Callback {
+{%- call java::suppress_warnings() %}
+interface {{ callback.name()|ffi_callback_name(config) }} extends Callback {
     public {% match callback.return_type() %}{%- when Some(return_type) %}{{ return_type|ffi_type_name_for_ffi_struct(config, ci) }}{%- when None %}void{%- endmatch %} callback(
         {%- for arg in callback.arguments() -%}
-        {{ arg.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ arg.name().borrow()|var_name }}{% if !loop.last %},{% endif %}
+        {{ arg.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ arg.name().borrow()|var_name(config) }}{% if !loop.last %},{% endif %}
         {%- endfor -%}
         {%- if callback.has_rust_call_status_arg() -%}{% if callback.arguments().len() != 0 %},{% endif %}
-        UniffiRustCallStatus uniffiCallStatus
+        {{ "UniffiRustCallStatus"|runtime_type(config) }} uniffiCallStatus
         {%- endif -%}
     );
 }
@@ -66,42 +89,43 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
 {%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
-@Structure.FieldOrder({ {% for field in ffi_struct.fields() %}"{{ field.name()|var_name_raw }}"{% if !loop.last %}, {% endif %}{% endfor %} })
-public class {{ ffi_struct.name()|ffi_struct_name }} extends Structure {
+@Structure.FieldOrder({ {% for field in ffi_struct.fields() %}"{{ field.name()|var_name_raw(config) }}"{% if !loop.last %}, {% endif %}{% endfor %} })
+{%- call java::suppress_warnings() %}
+public class {{ ffi_struct.name()|ffi_struct_name(config) }} extends Structure {
     {%- for field in ffi_struct.fields() %}
-    public {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ field.name()|var_name }} = {{ field.type_()|ffi_default_value }};
+    public {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ field.name()|var_name(config) }} = {{ field.type_()|ffi_default_value(config) }};
     {%- endfor %}
 
     // no-arg constructor required so JNA can instantiate and reflect
-    public {{ ffi_struct.name()|ffi_struct_name}}() {
+    public {{ ffi_struct.name()|ffi_struct_name(config)}}() {
         super();
     }
     
-    public {{ ffi_struct.name()|ffi_struct_name }}(
+    public {{ ffi_struct.name()|ffi_struct_name(config) }}(
         {%- for field in ffi_struct.fields() %}
-        {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ field.name()|var_name }}{% if !loop.last %},{% endif %}
+        {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ field.name()|var_name(config) }}{% if !loop.last %},{% endif %}
         {%- endfor %}
     ) {
         {%- for field in ffi_struct.fields() %}
-        this.{{ field.name()|var_name }} = {{ field.name()|var_name }};
+        this.{{ field.name()|var_name(config) }} = {{ field.name()|var_name(config) }};
         {%- endfor %}
     }
 
-    public static class UniffiByValue extends {{ ffi_struct.name()|ffi_struct_name }} implements Structure.ByValue {
+    public static class UniffiByValue extends {{ ffi_struct.name()|ffi_struct_name(config) }} implements Structure.ByValue {
         public UniffiByValue(
             {%- for field in ffi_struct.fields() %}
-            {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ field.name()|var_name }}{% if !loop.last %},{% endif %}
+            {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(config, ci) }} {{ field.name()|var_name(config) }}{% if !loop.last %},{% endif %}
             {%- endfor %}
         ) {
             super({%- for field in ffi_struct.fields() -%}
-                {{ field.name()|var_name }}{% if !loop.last %},{% endif %}        
+                {{ field.name()|var_name(config) }}{% if !loop.last %},{% endif %}        
             {% endfor %});
         }
     }
 
-    void uniffiSetValue({{ ffi_struct.name()|ffi_struct_name }} other) {
+    void uniffiSetValue({{ ffi_struct.name()|ffi_struct_name(config) }} other) {
         {%- for field in ffi_struct.fields() %}
-        {{ field.name()|var_name }} = other.{{ field.name()|var_name }};
+        {{ field.name()|var_name(config) }} = other.{{ field.name()|var_name(config) }};
         {%- endfor %}
     }
 
@@ -121,33 +145,135 @@ import io.quarkus.runtime.annotations.RegisterForProxy;
 // A JNA Library to expose the extern-C FFI definitions.
 // This is an implementation detail which will be called internally by the public API.{% if config.quarkus %}
 @RegisterForProxy{%- endif %}
+{%- call java::suppress_warnings() %}
 interface UniffiLib extends Library {
+    // The library is only loaded on the first call into it, not when any generated class is
+    // initialized, so classes can be initialized at build time, e.g. by GraalVM native-image.
     class UniffiLibLazyHolder {
-        private static UniffiLib INSTANCE;
+        private static volatile UniffiLib INSTANCE;
+        {%- if config.instrumentable_ffi %}
+        // The library as it was loaded, `INSTANCE` wraps it while an instrumentation is installed.
+        private static UniffiLib LOADED;
+        private static UniffiFfiInstrumentation INSTRUMENTATION;
+        {%- endif %}
     }
 
     static UniffiLib getInstance() {
-        if (UniffiLibLazyHolder.INSTANCE == null) {
-            UniffiLibLazyHolder.INSTANCE = UniffiLibInitializer.load();
+        UniffiLib instance = UniffiLibLazyHolder.INSTANCE;
+        if (instance == null) {
+            synchronized (UniffiLibLazyHolder.class) {
+                instance = UniffiLibLazyHolder.INSTANCE;
+                if (instance == null) {
+                    instance = {% if config.instrumentable_ffi %}loadInstrumented(){% else %}UniffiLibInitializer.load(){% endif %};
+                    UniffiLibLazyHolder.INSTANCE = instance;
+                }
+            }
+        }
+        return instance;
+    }
+    {%- if config.instrumentable_ffi %}
+
+    /**
+     * Have every call into the library go through {@code instrumentation}, or call it directly
+     * again with {@code null}.
+     */
+    static void setInstrumentation(UniffiFfiInstrumentation instrumentation) {
+        synchronized (UniffiLibLazyHolder.class) {
+            UniffiLibLazyHolder.INSTRUMENTATION = instrumentation;
+            if (UniffiLibLazyHolder.LOADED != null) {
+                UniffiLibLazyHolder.INSTANCE = instrument(UniffiLibLazyHolder.LOADED, instrumentation);
+            }
+        }
+    }
+
+    private static UniffiLib loadInstrumented() {
+        UniffiLibLazyHolder.LOADED = UniffiLibInitializer.load();
+        return instrument(UniffiLibLazyHolder.LOADED, UniffiLibLazyHolder.INSTRUMENTATION);
+    }
+
+    // Without an instrumentation the library is called directly, there's no proxy in between.
+    private static UniffiLib instrument(UniffiLib loaded, UniffiFfiInstrumentation instrumentation) {
+        if (instrumentation == null) {
+            return loaded;
+        }
+        return (UniffiLib) java.lang.reflect.Proxy.newProxyInstance(
+            UniffiLib.class.getClassLoader(),
+            new Class<?>[] { UniffiLib.class },
+            (proxy, method, args) -> {
+                if (method.getDeclaringClass() == Object.class) {
+                    return method.invoke(loaded, args);
+                }
+                return instrumentation.instrument(method.getName(), () -> {
+                    try {
+                        return method.invoke(loaded, args);
+                    } catch (java.lang.reflect.InvocationTargetException e) {
+                        if (e.getCause() instanceof RuntimeException runtimeException) {
+                            throw runtimeException;
+                        }
+                        if (e.getCause() instanceof Error error) {
+                            throw error;
+                        }
+                        throw new RuntimeException(e.getCause());
+                    } catch (IllegalAccessException e) {
+                        throw new IllegalStateException(e);
+                    }
+                });
+            });
+    }
+    {%- endif %}
+
+    /**
+     * Load the native library again and re-run its initialization, including the contract
+     * version and checksum checks.
+     *
+     * This is meant for test suites that need a fresh library between tests. Don't use it in
+     * production: objects created before reinitializing keep pointing into the old instance, and
+     * calls from other threads while reinitializing can see either instance.
+     */
+    static UniffiLib reinitialize() {
+        synchronized (UniffiLibLazyHolder.class) {
+            UniffiLibLazyHolder.INSTANCE = {% if config.instrumentable_ffi %}loadInstrumented(){% else %}UniffiLibInitializer.load(){% endif %};
+            return UniffiLibLazyHolder.INSTANCE;
         }
-        return UniffiLibLazyHolder.INSTANCE;
     }
 
     {% if ci.contains_object_types() %}
     // The Cleaner for the whole library
+    {%- if config.shutdown_hook %}
+    static UniffiTrackingCleaner CLEANER = new UniffiTrackingCleaner(UniffiCleaner.create());
+    {%- else %}
     static UniffiCleaner CLEANER = UniffiCleaner.create();
     {%- endif %}
+    {%- endif %}
     
     {% for func in ci.iter_ffi_function_definitions() -%}
     {% match func.return_type() %}{% when Some with (return_type) %}{{ return_type.borrow()|ffi_type_name_by_value(config, ci) }}{% when None %}void{% endmatch %} {{ func.name() }}({%- call java::arg_list_ffi_decl(func) %});
     {% endfor %}
 }
+{%- if config.instrumentable_ffi %}
+
+package {{ config.package_name() }};
+
+/**
+ * Wraps every call into the native library, e.g. to time it or trace it in an OpenTelemetry span.
+ * Install it with {@code {{ config.namespace_class_name(ci) }}.{{ "SetFfiInstrumentation"|internal(config) }}}.
+ */
+@FunctionalInterface
+public interface UniffiFfiInstrumentation {
+    /**
+     * Make the call to the FFI function named {@code function} by calling {@code call}, and return
+     * its result.
+     */
+    Object instrument(String function, java.util.function.Supplier<Object> call);
+}
+{%- endif %}
 
 package {{ config.package_name() }};
 
 // Java doesn't allow for static init blocks in an interface outside of a static property with a default.
 // To get around that and make sure that when the UniffiLib interface loads it has an initialized library
 // we call this class. The init code won't be called until a function on this interface is called unfortunately.
+{%- call java::suppress_warnings() %}
 final class UniffiLibInitializer {
     static UniffiLib load() {
         UniffiLib instance = NamespaceLibrary.loadIndirect("{{ ci.namespace() }}", UniffiLib.class);
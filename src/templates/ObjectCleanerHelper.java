@@ -10,6 +10,7 @@ import io.quarkus.runtime.annotations.RegisterForProxy;
 // defining a `UniffiCleaner` with a `UniffiClenaer.Cleanable` to abstract the
 // different implementations available at compile time.{% if config.quarkus %}
 @RegisterForProxy{%- endif %}
+{%- call java::suppress_warnings() %}
 interface UniffiCleaner {
     interface Cleanable {
         void clean();
@@ -47,6 +48,7 @@ import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 
 // The fallback Jna cleaner, which is available for both Android, and the JVM.{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 class UniffiJnaCleaner implements UniffiCleaner {
     private final Cleaner cleaner = Cleaner.getCleaner();
 
@@ -62,6 +64,7 @@ import com.sun.jna.internal.Cleaner;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 class UniffiJnaCleanable implements UniffiCleaner.Cleanable {
     private final Cleaner.Cleanable cleanable;
 
@@ -84,4 +87,57 @@ class UniffiJnaCleanable implements UniffiCleaner.Cleanable {
 {%- else %}
 {%-   include "ObjectCleanerHelperJvm.java" %}
 {%- endif %}
+{%- if config.shutdown_hook %}
+
+package {{ config.package_name() }};
+
+import java.util.List;
+import java.util.Set;
+import java.util.concurrent.ConcurrentHashMap;
+
+// Wraps the cleaner to keep track of the cleanables that haven't run yet, so they can all be run
+// on shutdown. The cleanup task only references the tracked cleanable, never the object, or the
+// object would never become unreachable.
+{%- call java::suppress_warnings() %}
+class UniffiTrackingCleaner implements UniffiCleaner {
+    private final UniffiCleaner cleaner;
+    private final Set<UniffiCleaner.Cleanable> pending = ConcurrentHashMap.newKeySet();
+
+    UniffiTrackingCleaner(UniffiCleaner cleaner) {
+        this.cleaner = cleaner;
+    }
+
+    @Override
+    public UniffiCleaner.Cleanable register(Object value, Runnable cleanUpTask) {
+        TrackedCleanable tracked = new TrackedCleanable();
+        tracked.cleanable = cleaner.register(value, () -> {
+            pending.remove(tracked);
+            cleanUpTask.run();
+        });
+        pending.add(tracked);
+        return tracked;
+    }
+
+    // Run all the cleanables that haven't run yet, returning how many did.
+    int cleanAll() {
+        int cleaned = 0;
+        for (UniffiCleaner.Cleanable tracked : List.copyOf(pending)) {
+            if (pending.remove(tracked)) {
+                tracked.clean();
+                cleaned++;
+            }
+        }
+        return cleaned;
+    }
+
+    private static final class TrackedCleanable implements UniffiCleaner.Cleanable {
+        private UniffiCleaner.Cleanable cleanable;
+
+        @Override
+        public void clean() {
+            cleanable.clean();
+        }
+    }
+}
+{%- endif %}
 
@@ -6,6 +6,7 @@ import java.lang.ref.Cleaner;
 
 // The SystemCleaner, available from API Level 33.
 // Some API Level 33 OSes do not support using it, so we require API Level 34.
+{%- call java::suppress_warnings() %}
 class AndroidSystemCleaner implements UniffiCleaner {
     private final Cleaner cleaner;
 
@@ -26,6 +27,7 @@ import android.os.Build;
 import androidx.annotation.RequiresApi;
 import java.lang.ref.Cleaner;
 
+{%- call java::suppress_warnings() %}
 class AndroidSystemCleanable implements UniffiCleaner.Cleanable {
     private final Cleaner.Cleanable cleanable;
     
@@ -4,16 +4,32 @@ import java.lang.ref.Cleaner;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 class JavaLangRefCleaner implements UniffiCleaner {
-    private final Cleaner cleaner;
+    // Created when the first object is registered, so no cleaner thread is started when one is set
+    // before that.
+    private static volatile Cleaner cleaner;
 
-    JavaLangRefCleaner() {
-      this.cleaner = Cleaner.create();
+    static void setCleaner(Cleaner cleaner) {
+        JavaLangRefCleaner.cleaner = java.util.Objects.requireNonNull(cleaner, "cleaner");
+    }
+
+    private static Cleaner cleaner() {
+        Cleaner result = cleaner;
+        if (result == null) {
+            synchronized (JavaLangRefCleaner.class) {
+                if (cleaner == null) {
+                    cleaner = Cleaner.create();
+                }
+                result = cleaner;
+            }
+        }
+        return result;
     }
 
     @Override
     public UniffiCleaner.Cleanable register(Object value, Runnable cleanUpTask) {
-        return new JavaLangRefCleanable(cleaner.register(value, cleanUpTask));
+        return new JavaLangRefCleanable(cleaner().register(value, cleanUpTask));
     }
 }
 
@@ -23,6 +39,7 @@ import java.lang.ref.Cleaner;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 class JavaLangRefCleanable implements UniffiCleaner.Cleanable {
     private final Cleaner.Cleanable cleanable;
     
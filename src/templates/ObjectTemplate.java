@@ -100,11 +100,15 @@
 {%- endif %}
 
 {%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let (interface_name, impl_class_name) = obj|object_names(ci) %}
+{%- let (interface_name, impl_class_name) = obj|object_names(ci, config) %}
 {%- let methods = obj.methods() %}
 {%- let interface_docstring = obj.docstring() %}
+{#- Only foreign implementations benefit from lambdas, so plain objects don't get the annotation #}
+{%- let functional_interface = obj.has_callback_interface() && methods.len() == 1 %}
 {%- let is_error = ci.is_name_used_as_error(name) %}
 {%- let ffi_converter_name = obj|ffi_converter_name %}
+{#- Foreign implementations are called through the interface, which has to keep throwing #}
+{%- let results_as_values = config.results_as_values && !obj.has_callback_interface() %}
 
 {%- include "Interface.java" %}
 
@@ -120,12 +124,14 @@ import com.sun.jna.Pointer;
 import java.util.concurrent.CompletableFuture;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 
-{%- call java::docstring(obj, 0) %}
+{%- call java::deprecated_docstring(obj, 0, "") %}
 {% if (is_error) %}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class {{ impl_class_name }} extends Exception implements AutoCloseable, {{ interface_name }} {
 {% else -%}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }} {
 {%- endif %}
   protected Pointer pointer;
@@ -133,6 +139,10 @@ public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }
 
   private AtomicBoolean wasDestroyed = new AtomicBoolean(false);
   private AtomicLong callCounter = new AtomicLong(1);
+  {%- if config.enforces_thread_affinity(name) %}
+  // Listed in `enforce_thread_affinity`, the Rust object can't be used from other threads.
+  private final Thread {{ "OwnerThread"|internal(config) }} = Thread.currentThread();
+  {%- endif %}
 
   public {{ impl_class_name }}(Pointer pointer) {
     this.pointer = pointer;
@@ -152,9 +162,10 @@ public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }
   {% match obj.primary_constructor() %}
   {%- when Some(cons) %}
   {%-     if cons.is_async() %}
-  // Note no constructor generated for this object as it is async.
+  // Java constructors can't be async, so this one is a static factory like the named constructors.
+  {% call java::func_decl("public static", "", cons, 4, config.results_as_values, name) %}
   {%-     else %}
-  {%- call java::docstring(cons, 4) %}
+  {%- call java::deprecated_docstring(cons, 4, name) %}
   public {{ impl_class_name }}({% call java::arg_list(cons, true) -%}) {% match cons.throws_type() %}{% when Some(throwable) %}throws {{ throwable|type_name(ci, config) }}{% else %}{% endmatch %}{
     this((Pointer){%- call java::to_ffi_call(cons) -%});
   }
@@ -175,6 +186,13 @@ public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }
   }
 
   public <R> R callWithPointer(Function<Pointer, R> block) {
+    {%- if config.enforces_thread_affinity(name) %}
+    if (Thread.currentThread() != {{ "OwnerThread"|internal(config) }}) {
+      throw new IllegalStateException(
+        "{{ impl_class_name }} can only be used from " + {{ "OwnerThread"|internal(config) }}.getName()
+          + ", the thread that created it, not from " + Thread.currentThread().getName());
+    }
+    {%- endif %}
     // Check and increment the call counter, to keep the object alive.
     // This needs a compare-and-set retry loop in case of concurrent updates.
     long c;
@@ -189,7 +207,7 @@ public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }
     } while (! this.callCounter.compareAndSet(c, c + 1L));
     // Now we can safely do the method call without the pointer being freed concurrently.
     try {
-      return block.apply(this.uniffiClonePointer());
+      return block.apply(this.{{ "ClonePointer"|internal(config) }}());
     } finally {
       // This decrement always matches the increment we performed above.
       if (this.callCounter.decrementAndGet() == 0L) {
@@ -222,8 +240,21 @@ public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }
       }
     }
   }
+  {%- if config.expose_raw_pointers %}
+
+  /**
+   * Get a new reference to the underlying Rust object, for handing it to another native library.
+   *
+   * This increments the Rust `Arc` refcount. Every pointer returned here must be given back to
+   * Rust exactly once, either by the native code receiving it, through {@link #fromRawPointer}, or
+   * through {@link #{{ "FreePointer"|internal(config) }}}. Leaking it leaks the object, releasing it twice is
+   * undefined behaviour.
+   */
+  public Pointer {{ "ClonePointer"|internal(config) }}() {
+  {%- else %}
 
-  Pointer uniffiClonePointer() {
+  Pointer {{ "ClonePointer"|internal(config) }}() {
+  {%- endif %}
     return UniffiHelpers.uniffiRustCall(status -> {
       if (pointer == null) {
         throw new NullPointerException();
@@ -231,9 +262,43 @@ public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }
       return UniffiLib.getInstance().{{ obj.ffi_object_clone().name() }}(pointer, status);
     });
   }
+  {%- if config.expose_raw_pointers %}
+
+  /**
+   * The raw pointer to the underlying Rust object, without touching its refcount.
+   *
+   * The pointer is only valid as long as this object is alive and not closed. It must not be
+   * released, use {@link #{{ "ClonePointer"|internal(config) }}} to get a reference that can be handed over.
+   */
+  public Pointer {{ "RawPointer"|internal(config) }}() {
+    return this.pointer;
+  }
+
+  /**
+   * Wrap a pointer to a Rust `{{ obj.name() }}` obtained from native code.
+   *
+   * The new object takes over one reference, which is released when it's closed or cleaned up.
+   * Passing a pointer that isn't an owned reference to a `{{ obj.name() }}` is undefined behaviour.
+   */
+  public static {{ impl_class_name }} fromRawPointer(Pointer pointer) {
+    return new {{ impl_class_name }}(pointer);
+  }
+
+  /**
+   * Release a reference obtained from {@link #{{ "ClonePointer"|internal(config) }}} that won't be wrapped again.
+   *
+   * Releasing a reference more than once, or using the pointer afterwards, is undefined behaviour.
+   */
+  public static void {{ "FreePointer"|internal(config) }}(Pointer pointer) {
+    UniffiHelpers.uniffiRustCall(status -> {
+      UniffiLib.getInstance().{{ obj.ffi_object_free().name() }}(pointer, status);
+      return null;
+    });
+  }
+  {%- endif %}
 
   {% for meth in obj.methods() -%}
-  {%- call java::func_decl("public", "Override", meth, 4) %}
+  {%- call java::func_decl("public", "Override", meth, 4, results_as_values, name) %}
   {% endfor %}
 
   {%- for tm in obj.uniffi_traits() %}
@@ -244,29 +309,61 @@ public class {{ impl_class_name }} implements AutoCloseable, {{ interface_name }
       return {{ fmt.return_type().unwrap()|lift_fn(config, ci) }}({% call java::to_ffi_call(fmt) %});
   }
   {%         when UniffiTrait::Eq { eq, ne } %}
-  {# only equals used #}
+  {#- only equals used. The binding has to be named `other`, like the argument of `eq` #}
   @Override
-  public Boolean equals(Object other) {
-      if (this === other) {
+  public boolean equals(Object obj) {
+      if (this == obj) {
         return true;
       }
-      if (!(other instanceof {{ impl_class_name}})) {
+      if (!(obj instanceof {{ impl_class_name }} other)) {
         return false;
       }
       return {{ eq.return_type().unwrap()|lift_fn(config, ci) }}({% call java::to_ffi_call(eq) %});
   }
+  {%-            if !self::exports_uniffi_hash(obj) %}
+
+  // Rust doesn't export `Hash`, so objects which are equal can only share a hash code per class.
+  @Override
+  public int hashCode() {
+      return {{ impl_class_name }}.class.hashCode();
+  }
+  {%-            endif %}
   {%         when UniffiTrait::Hash { hash } %}
   @Override
-  public Integer hashCode() {
-      return {{ hash.return_type().unwrap()|lift_fn(config, ci) }}({%- call java::to_ffi_call(hash) %}).toInt();
+  public int hashCode() {
+      return Long.hashCode({{ hash.return_type().unwrap()|lift_fn(config, ci) }}({%- call java::to_ffi_call(hash) %}));
   }
   {%-         else %}
   {%-     endmatch %}
   {%- endfor %}
 
+  {%- if self::has_identity_equals(config, obj) %}
+
+  @Override
+  public boolean equals(Object other) {
+      if (this == other) {
+        return true;
+      }
+      if (!(other instanceof {{ impl_class_name }})) {
+        return false;
+      }
+      {{ impl_class_name }} that = ({{ impl_class_name }}) other;
+      // Destroyed wrappers, and fakes made with `NoPointer`, no longer refer to a Rust instance.
+      if (this.pointer == null || that.pointer == null || this.wasDestroyed.get() || that.wasDestroyed.get()) {
+        return false;
+      }
+      return this.pointer.equals(that.pointer);
+  }
+
+  @Override
+  public int hashCode() {
+      return pointer == null ? 0 : pointer.hashCode();
+  }
+  {%- endif %}
+
   {% if !obj.alternate_constructors().is_empty() -%}
   {% for cons in obj.alternate_constructors() -%}
-  {% call java::func_decl("public static", "", cons, 4) %}
+  {% call java::func_decl("public static", "", cons, 4, config.results_as_values, name) %}
   {% endfor %}
   {% endif %}
 }
@@ -276,9 +373,10 @@ package {{ config.package_name() }};{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class {{ impl_class_name }}ErrorHandler implements UniffiRustCallStatusErrorHandler<{{ impl_class_name }}> {
     @Override
-    public {{ impl_class_name }} lift(RustBuffer.ByValue error_buf) {
+    public {{ impl_class_name }} lift({{ "RustBuffer"|runtime_type(config) }}.ByValue error_buf) {
         // Due to some mismatches in the ffi converter mechanisms, errors are a RustBuffer.
         var bb = error_buf.asByteBuffer();
         if (bb == null) {
@@ -303,6 +401,7 @@ import com.sun.jna.Pointer;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum {{ ffi_converter_name }} implements FfiConverter<{{ type_name }}, Pointer> {
     INSTANCE;
 
@@ -315,7 +414,7 @@ public enum {{ ffi_converter_name }} implements FfiConverter<{{ type_name }}, Po
         {%- if obj.has_callback_interface() %}
         return new Pointer(handleMap.insert(value));
         {%- else %}
-        return value.uniffiClonePointer();
+        return value.{{ "ClonePointer"|internal(config) }}();
         {%- endif %}
     }
 
@@ -1,4 +1,3 @@
-{%- let inner_type_name = inner_type|type_name(ci, config) %}
 package {{ config.package_name() }};
 
 import java.nio.ByteBuffer;
@@ -12,34 +11,98 @@ import java.util.Map;
   Kotlin and C# bindings use their type system to directly express nullability with `?`.
   Java has an `Optional` type that some FP Java folks use, but we'll lean on the more straightforward Java way here
   and have it be invisibly nullable, because that's the normal Java way.
+
+  Nested optionals can't all be `null`, that would make `None` and `Some(None)` the same. The outer one
+  is nullable and the inner ones are `java.util.Optional`, so `Option<Option<T>>` is a nullable
+  `Optional<T>`: `null` is `None`, `Optional.empty()` is `Some(None)`, and `Optional.of(v)` is
+  `Some(Some(v))`.
+#}
+{%- let nested = self::is_optional(inner_type) %}
+{%- match self::primitive_optional(config, inner_type) %}
+{%- when Some((optional_class, getter)) %}
+{#-
+  With `primitive_optionals`, optionals of ints, longs and doubles are an `OptionalInt` and the like,
+  empty for `None`. A `null` one is written as `None` too.
 #}
-public enum {{ ffi_converter_name }} implements FfiConverterRustBuffer<{{ inner_type_name }}> {
+{%- call java::suppress_warnings() %}
+public enum {{ ffi_converter_name }} implements FfiConverterRustBuffer<{{ type_name }}> {
+  INSTANCE;
+
+  @Override
+  public {{ type_name }} read(ByteBuffer buf) {
+    if (buf.get() == (byte)0) {
+      return {{ optional_class }}.empty();
+    }
+    return {{ optional_class }}.of({{ inner_type|read_fn(config, ci) }}(buf));
+  }
+
+  @Override
+  public long allocationSize({{ type_name }} value) {
+    if (value == null || value.isEmpty()) {
+      return 1L;
+    } else {
+      return 1L + {{ inner_type|allocation_size_fn(config, ci) }}(value.{{ getter }}());
+    }
+  }
+
+  @Override
+  public void write({{ type_name }} value, ByteBuffer buf) {
+    if (value == null || value.isEmpty()) {
+      buf.put((byte)0);
+    } else {
+      buf.put((byte)1);
+      {{ inner_type|write_fn(config, ci) }}(value.{{ getter }}(), buf);
+    }
+  }
+  {%- if self::grows_write_buffer(config, ci, type_) %}
+
+  @Override
+  public {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer({{ type_name }} value) {
+    return {{ "LowerIntoGrowingBuffer"|internal(config) }}(value);
+  }
+  {%- endif %}
+}
+{%- when None %}
+{%- call java::suppress_warnings() %}
+public enum {{ ffi_converter_name }} implements FfiConverterRustBuffer<{{ type_name }}> {
   INSTANCE;
 
   @Override
-  public {{ inner_type_name }} read(ByteBuffer buf) {
+  public {{ type_name }} read(ByteBuffer buf) {
     if (buf.get() == (byte)0) {
       return null;
     }
+    {%- if nested %}
+    return java.util.Optional.ofNullable({{ inner_type|read_fn(config, ci) }}(buf));
+    {%- else %}
     return {{ inner_type|read_fn(config, ci) }}(buf);
+    {%- endif %}
   }
 
   @Override
-  public long allocationSize({{ inner_type_name }} value) {
+  public long allocationSize({{ type_name }} value) {
     if (value == null) {
       return 1L;
     } else {
-      return 1L + {{ inner_type|allocation_size_fn(config, ci) }}(value);
+      return 1L + {{ inner_type|allocation_size_fn(config, ci) }}(value{% if nested %}.orElse(null){% endif %});
     }
   }
 
   @Override
-  public void write({{ inner_type_name }} value, ByteBuffer buf) {
+  public void write({{ type_name }} value, ByteBuffer buf) {
     if (value == null) {
       buf.put((byte)0);
     } else {
       buf.put((byte)1);
-      {{ inner_type|write_fn(config, ci) }}(value, buf);
+      {{ inner_type|write_fn(config, ci) }}(value{% if nested %}.orElse(null){% endif %}, buf);
     }
   }
+  {%- if self::grows_write_buffer(config, ci, type_) %}
+
+  @Override
+  public {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer({{ type_name }} value) {
+    return {{ "LowerIntoGrowingBuffer"|internal(config) }}(value);
+  }
+  {%- endif %}
 }
+{%- endmatch %}
@@ -0,0 +1,29 @@
+package {{ config.package_name() }};
+
+import jakarta.enterprise.context.ApplicationScoped;
+import jakarta.enterprise.inject.Disposes;
+import jakarta.enterprise.inject.Produces;
+
+/**
+ * CDI producers for the objects with a constructor taking no arguments, so they can be injected.
+ *
+ * <p>Each object is an application scoped bean, injected through its interface, and closed when
+ * the application shuts down.
+ */
+@ApplicationScoped
+{%- call java::suppress_warnings() %}
+public class {{ ci.namespace()|class_name(ci) }}Producers {
+    {%- for obj in producible_objects %}
+    {%- let (interface_name, impl_class_name) = obj|object_names(ci, config) %}
+
+    @Produces
+    @ApplicationScoped
+    public {{ interface_name }} {{ obj.name()|var_name(config) }}() {% match obj.primary_constructor() %}{% when Some(cons) %}{% match cons.throws_type() %}{% when Some(throwable) %}throws {{ throwable|type_name(ci, config) }} {% else %}{% endmatch %}{% when None %}{% endmatch %}{
+        return new {{ impl_class_name }}();
+    }
+
+    public void close{{ impl_class_name }}(@Disposes {{ interface_name }} value) {
+        (({{ impl_class_name }}) value).close();
+    }
+    {%- endfor %}
+}
@@ -1,60 +1,139 @@
 {%- let rec = ci.get_record_definition(name).unwrap() %}
+{%- let interfaces = self::implemented_interfaces(config, ci, type_) %}
+{%- let equality_fields = self::equality_fields(config, rec) %}
 package {{ config.package_name() }};
 
 import java.util.List;
 import java.util.Map;
 import java.nio.ByteBuffer;
-import java.util.Objects;
+import java.util.Objects;{% if config.record_comparators || config.is_comparable(name) %}
+import java.util.Comparator;{%- endif %}{% if config.unmodifiable_collections %}
+import java.util.Collections;{%- endif %}
+{%- for import_name in config.implements_imports(name, ci) %}
+import {{ import_name }};
+{%- endfor %}
 
-{%- call java::docstring(rec, 0) %}
+{%- call java::deprecated_docstring_value(self::record_docstring(config, rec), 0, "", rec.name()) %}
 {%- if rec.has_fields() %}
-{%- if config.generate_immutable_records() %}
+{%- if config.generate_immutable_records() || self::is_primitive_newtype(config, rec) %}
+{%- call java::suppress_warnings() %}
 public record {{ type_name }}(
     {%- for field in rec.fields() %}
-    {%- call java::docstring(field, 4) %}
-    {{ field|type_name(ci, config) }} {{ field.name()|var_name -}}
+    {{ self::record_field_type(ci, config, rec, field) }} {{ field.name()|var_name(config) -}}
     {% if !loop.last %}, {% endif %}
     {%- endfor %}
-) {% if contains_object_references %}implements AutoCloseable {% endif %}{
+) {% if !interfaces.is_empty() %}implements {{ interfaces|join(", ") }} {% endif %}{
+    {%- match self::constructor_properties(config, rec) %}
+    {%- when Some(annotation) %}
+
+    {{ annotation }}
+    public {{ type_name }} {}
+    {%- when None %}
+    {%- endmatch %}
     {% if contains_object_references %}
     @Override
     public void close() {
         {% call java::destroy_fields(rec) %}
     }
     {% endif %}
+    {%- for field in rec.fields() %}
+    {%- match self::unmodifiable_getter_value(config, field) %}
+    {%- when Some(value) %}
+
+    public {{ field|type_name(ci, config) }} {{ field.name()|var_name(config) }}() {
+        return {{ value }};
+    }
+    {%- when None %}
+    {%- endmatch %}
+    {%- endfor %}
+    {%- if equality_fields.len() != rec.fields().len() || self::uses_deep_equality(config, equality_fields) || config.stable_record_hash() %}
+
+    // Fields in `equals_ignore_fields` are left out of the value semantics, byte arrays are
+    // compared by content with `deep_equals`, and `record_hash` can make the hash stable.
+    @Override
+    public boolean equals(Object other) {
+        return other instanceof {{ type_name }} t{% for field in equality_fields %}{% let field_var_name = field.name()|var_name(config) %}
+            && {% call java::field_equals(field) %}{% endfor %};
+    }
+
+    @Override
+    public int hashCode() {
+        return {% call java::fields_hash(equality_fields) %};
+    }
+    {%- endif %}
+    {%- if config.has_tostring_template() %}
+
+    @Override
+    public String toString() {
+        return {{ self::tostring_expr(config, type_name, rec.fields()) }};
+    }
+    {%- endif %}
+    {%- call java::parcelable(type_, type_name, false) %}
+    {%- call java::record_comparators(rec, type_name) %}
+    {%- call java::record_compare_to(rec, type_name) %}
+    {%- call java::record_conversions(rec, type_name) %}
+    {%- call java::record_builder(rec, type_name) %}
+    {%- call java::record_map_conversion(rec, type_name) %}
+    {%- call java::mapstruct(rec) %}
 }
 {% else %}
-public class {{ type_name }} {% if contains_object_references %}implements AutoCloseable {% endif %}{
+{%- call java::suppress_warnings() %}
+public class {{ type_name }} {% if !interfaces.is_empty() %}implements {{ interfaces|join(", ") }} {% endif %}{
     {%- for field in rec.fields() %}
-    {%- call java::docstring(field, 4) %}
-    private {{ field|type_name(ci, config) }} {{ field.name()|var_name -}};
+    private {{ field|type_name(ci, config) }} {{ field.name()|var_name(config) -}};
     {%- endfor %}
+    {%- match self::params_docstring(None, rec.fields(), config) %}
+    {%- when Some(params) %}
+
+{{ params|docstring(4, ci, config) }}
+    {% when None %}
 
+    {% endmatch -%}
+    {% match self::constructor_properties(config, rec) -%}
+    {%- when Some(annotation) -%}
+    {{ annotation }}
+    {% when None -%}
+    {%- endmatch -%}
     public {{ type_name }}(
         {%- for field in rec.fields() %}
-        {{ field|type_name(ci, config) }} {{ field.name()|var_name -}}
+        {{ field|type_name(ci, config) }} {{ field.name()|var_name(config) -}}
         {% if !loop.last %}, {% endif %}
         {%- endfor %}
     ) {
         {%- for field in rec.fields() %}
-        {% let field_var_name = field.name()|var_name  %}
+        {% let field_var_name = field.name()|var_name(config)  %}
         this.{{ field_var_name }} = {{ field_var_name -}};
         {%- endfor %}
     }
 
     {%- for field in rec.fields() %}
-    {% let field_var_name = field.name()|var_name %}
+    {% let field_var_name = field.name()|var_name(config) %}
+    {%- call java::docstring(field, 4) %}
     public {{ field|type_name(ci, config) }} {{ field_var_name }}() {
-        return this.{{ field_var_name }};
+        return {% match self::unmodifiable_getter_value(config, field) %}{% when Some(value) %}{{ value }}{% when None %}this.{{ field_var_name }}{% endmatch %};
     }
     {%- endfor %}
 
     {%- for field in rec.fields() %}
-    {%- let field_var_name = field.name()|var_name %}
-    public void {{ field.name()|setter}}({{ field|type_name(ci, config) }} {{ field_var_name }}) {
+    {%- let field_var_name = field.name()|var_name(config) %}
+    public void {{ field.name()|setter(config)}}({{ field|type_name(ci, config) }} {{ field_var_name }}) {
         this.{{ field_var_name }} = {{ field_var_name }};
     }
     {%- endfor %}
+    {%- if config.generates_record_copy() %}
+
+    /**
+     * A deep copy of this record, so changing either doesn't change the other. Nested records,
+     * lists, maps and byte arrays are copied, objects and enums are shared.
+     */
+    public {{ type_name }} copy() {
+        return new {{ type_name }}(
+            {%- for field in rec.fields() %}
+            {{ self::field_copy_value(ci, config, field) }}{% if !loop.last %},{% endif %}
+            {%- endfor %}
+        );
+    }
+    {%- endif %}
 
     {% if contains_object_references %}
     @Override
@@ -67,9 +146,9 @@ public class {{ type_name }} {% if contains_object_references %}implements AutoC
     public boolean equals(Object other) {
         if (other instanceof {{ type_name }}) {
             {{ type_name }} t = ({{ type_name }}) other;
-            return ({% for field in rec.fields() %}{% let field_var_name = field.name()|var_name %}
+            return ({% if equality_fields.is_empty() %}true{% endif %}{% for field in equality_fields %}{% let field_var_name = field.name()|var_name(config) %}
               {#- currently all primitives are already referenced by their boxed values in generated code, so `.equals` works for everything #}
-              Objects.equals({{ field_var_name }}, t.{{ field_var_name }}){% if !loop.last%} && {% endif %}
+              {% call java::field_equals(field) %}{% if !loop.last%} && {% endif %}
               {% endfor %}
             );
         };
@@ -78,12 +157,33 @@ public class {{ type_name }} {% if contains_object_references %}implements AutoC
 
     @Override
     public int hashCode() {
-        return Objects.hash({% for field in rec.fields() %}{{ field.name()|var_name }}{% if !loop.last%}, {% endif %}{% endfor %});
+        return {% call java::fields_hash(equality_fields) %};
+    }
+
+    @Override
+    public String toString() {
+        return {{ self::tostring_expr(config, type_name, rec.fields()) }};
     }
+    {%- call java::parcelable(type_, type_name, false) %}
+    {%- call java::record_comparators(rec, type_name) %}
+    {%- call java::record_compare_to(rec, type_name) %}
+    {%- call java::record_conversions(rec, type_name) %}
+    {%- call java::record_builder(rec, type_name) %}
+    {%- call java::record_map_conversion(rec, type_name) %}
+    {%- call java::mapstruct(rec) %}
 }
 {% endif %}
 {%- else %}
-public class {{ type_name }} {
+{%- call java::suppress_warnings() %}
+public class {{ type_name }} {% if !interfaces.is_empty() %}implements {{ interfaces|join(", ") }} {% endif %}{
+    {%- if config.singleton_empty_records %}
+    /**
+     * The only instance of {@code {{ type_name }}}, it has no fields so all instances would be equal.
+     */
+    public static final {{ type_name }} INSTANCE = new {{ type_name }}();
+
+    private {{ type_name }}() {}
+{% endif %}
     @Override
     public boolean equals(Object other) {
         return other instanceof {{ type_name }};
@@ -93,6 +193,12 @@ public class {{ type_name }} {
     public int hashCode() {
         return getClass().hashCode();
     }
+
+    @Override
+    public String toString() {
+        return {{ self::tostring_expr(config, type_name, rec.fields()) }};
+    }
+    {%- call java::parcelable(type_, type_name, false) %}
 }
 {%- endif %}
 
@@ -100,6 +206,7 @@ package {{ config.package_name() }};
 
 import java.nio.ByteBuffer;
 
+{%- call java::suppress_warnings() %}
 public enum {{ rec|ffi_converter_name }} implements FfiConverterRustBuffer<{{ type_name }}> {
   INSTANCE;
 
@@ -111,6 +218,8 @@ public enum {{ rec|ffi_converter_name }} implements FfiConverterRustBuffer<{{ ty
       {{ field|read_fn(config, ci) }}(buf){% if !loop.last %},{% else %}{% endif %}
     {%- endfor %}
     );
+    {%- else if config.singleton_empty_records %}
+    return {{ type_name }}.INSTANCE;
     {%- else %}
     return new {{ type_name }}();
     {%- endif %}
@@ -121,7 +230,7 @@ public enum {{ rec|ffi_converter_name }} implements FfiConverterRustBuffer<{{ ty
       {%- if rec.has_fields() %}
       return (
         {%- for field in rec.fields() %}
-            {{ field|allocation_size_fn(config, ci) }}(value.{{ field.name()|var_name }}()){% if !loop.last %} +{% endif %}
+            {{ field|allocation_size_fn(config, ci) }}(value.{{ field.name()|var_name(config) }}()){% if !loop.last %} +{% endif %}
         {%- endfor %}
       ); 
       {%- else %}
@@ -132,7 +241,14 @@ public enum {{ rec|ffi_converter_name }} implements FfiConverterRustBuffer<{{ ty
   @Override
   public void write({{ type_name }} value, ByteBuffer buf) {
     {%- for field in rec.fields() %}
-      {{ field|write_fn(config, ci) }}(value.{{ field.name()|var_name }}(), buf);
+      {{ field|write_fn(config, ci) }}(value.{{ field.name()|var_name(config) }}(), buf);
     {%- endfor %}
   }
+  {%- if self::grows_write_buffer(config, ci, type_) %}
+
+  @Override
+  public {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer({{ type_name }} value) {
+    return {{ "LowerIntoGrowingBuffer"|internal(config) }}(value);
+  }
+  {%- endif %}
 }
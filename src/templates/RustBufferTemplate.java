@@ -1,4 +1,4 @@
-package {{ config.package_name() }};
+package {{ config.runtime_package() }};
 
 import com.sun.jna.Structure;
 import com.sun.jna.Pointer;{% if config.quarkus %}
@@ -12,6 +12,7 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
  */{% if config.quarkus %}
 @RegisterForReflection(registerFullHierarchy = true){%- endif %}
 @Structure.FieldOrder({ "capacity", "len", "data" })
+{%- call java::suppress_warnings() %}
 public class RustBuffer extends Structure {
     public long capacity;
     public long len;
@@ -22,30 +23,24 @@ public class RustBuffer extends Structure {
     @RegisterForReflection(registerFullHierarchy = true){%- endif %}
     public static class ByReference extends RustBuffer implements Structure.ByReference {}
 
-    void setValue(RustBuffer other) {
+    public void setValue(RustBuffer other) {
         this.capacity = other.capacity;
         this.len = other.len;
         this.data = other.data;
     }
 
-    public static RustBuffer.ByValue alloc(long size) {
-        RustBuffer.ByValue buffer = UniffiHelpers.uniffiRustCall((UniffiRustCallStatus status) -> {
-            return (RustBuffer.ByValue) UniffiLib.getInstance().{{ ci.ffi_rustbuffer_alloc().name() }}(size, status);
-        });
-        if (buffer.data == null) {
-            throw new RuntimeException("RustBuffer.alloc() returned null data pointer (size=" + size + ")");
-        }
-        return buffer;
-    }
-
-    public static void free(RustBuffer.ByValue buffer) {
-        UniffiHelpers.uniffiRustCall((status) -> {
-            UniffiLib.getInstance().{{ ci.ffi_rustbuffer_free().name() }}(buffer, status);
-            return null;
-        });
-    }
-
     public java.nio.ByteBuffer asByteBuffer() {
+        {%- if config.safe_buffer_reads %}
+        if (this.len < 0 || this.len > this.capacity || (this.data == null && this.len != 0)) {
+            throw new IndexOutOfBoundsException(
+                "RustBuffer with length " + this.len + " doesn't fit its capacity " + this.capacity);
+        }
+        {%- endif %}
+        // A `ByteBuffer` is indexed by `int`, `getByteBuffer` would truncate a longer buffer.
+        if (this.len > Integer.MAX_VALUE) {
+            throw new IllegalStateException(
+                "RustBuffer with length " + this.len + " is larger than the " + Integer.MAX_VALUE + " bytes it can be read with");
+        }
         if (this.data != null) {
             java.nio.ByteBuffer byteBuffer = this.data.getByteBuffer(0, this.len);
             byteBuffer.order(java.nio.ByteOrder.BIG_ENDIAN);
@@ -55,7 +50,7 @@ public class RustBuffer extends Structure {
     }
 }
 
-package {{ config.package_name() }};
+package {{ config.runtime_package() }};
 
 import com.sun.jna.Structure;
 import com.sun.jna.Pointer;{% if config.quarkus %}
@@ -68,6 +63,7 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
  * Size is the sum of all values in the struct.
  */{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class RustBufferByReference extends Structure implements Structure.ByReference {
     public RustBufferByReference() {
         super(16);
@@ -98,7 +94,7 @@ public class RustBufferByReference extends Structure implements Structure.ByRefe
     }
 }
 
-package {{ config.package_name() }};
+package {{ config.runtime_package() }};
 
 import com.sun.jna.Structure;
 import com.sun.jna.Pointer;{% if config.quarkus %}
@@ -112,6 +108,7 @@ import io.quarkus.runtime.annotations.RegisterForReflection;
 // completeness.{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
 @Structure.FieldOrder({ "len", "data" })
+{%- call java::suppress_warnings() %}
 public class ForeignBytes extends Structure {
     public int len;
     public Pointer data;
@@ -0,0 +1,48 @@
+package {{ package_name }}
+
+import scala.jdk.CollectionConverters._
+
+// Scala wrappers of the records and enums of the {{ namespace }} bindings, generated with
+// `scala_interop`. Each converts to the Java type with `asJava`, and back with `fromJava`.
+// Enums without fields, objects and callback interfaces are used as the Java types.
+{%- if !skipped.is_empty() %}
+// Not wrapped, they hold custom types or nested optionals: {{ skipped|join(", ") }}.
+{%- endif %}
+{%- for rec in records %}
+
+final case class {{ rec.name }}({{ rec.params }}) {
+  def asJava: {{ rec.java_name }} = {{ rec.java_value }}
+}
+
+object {{ rec.name }} {
+  def fromJava(value: {{ rec.java_name }}): {{ rec.name }} = {{ rec.name }}({{ rec.scala_args }})
+}
+{%- endfor %}
+{%- for e in enums %}
+
+sealed trait {{ e.name }} {
+  def asJava: {{ e.java_name }}
+}
+
+object {{ e.name }} {
+  {%- for variant in e.variants %}
+  {%- if variant.params.is_empty() %}
+  case object {{ variant.name }} extends {{ e.name }} {
+  {%- else %}
+  final case class {{ variant.name }}({{ variant.params }}) extends {{ e.name }} {
+  {%- endif %}
+    def asJava: {{ e.java_name }} = {{ variant.java_value }}
+  }
+  {%- endfor %}
+
+  def fromJava(value: {{ e.java_name }}): {{ e.name }} = value match {
+    {%- for variant in e.variants %}
+    {%- if variant.params.is_empty() %}
+    case _: {{ variant.java_name }} => {{ variant.name }}
+    {%- else %}
+    case variant: {{ variant.java_name }} => {{ variant.name }}({{ variant.scala_args }})
+    {%- endif %}
+    {%- endfor %}
+  }
+}
+{%- endfor %}
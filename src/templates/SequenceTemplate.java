@@ -1,4 +1,5 @@
 {%- let inner_type_name = inner_type|type_name(ci, config) %}
+{%- let bulk_put = self::bulk_put_method(inner_type) %}
 /* This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
@@ -11,25 +12,53 @@ import java.util.stream.Stream;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public enum {{ ffi_converter_name }} implements FfiConverterRustBuffer<List<{{ inner_type_name }}>> {
   INSTANCE;
 
   @Override
   public List<{{ inner_type_name }}> read(ByteBuffer buf) {
-    int len = buf.getInt();
+    int len = {% call java::read_length(0) %};
+    // An empty list is read as an empty list, never `null`, only an optional sequence is `null`.
+    // `toList` keeps `null` elements, the `None`s of a `Vec<Option<T>>`, where `List.of`,
+    // `List.copyOf` and `Collectors.toUnmodifiableList` throw on them.
     return IntStream.range(0, len).mapToObj(_i -> {{ inner_type|read_fn(config, ci) }}(buf)).toList();
   }
 
   @Override
   public long allocationSize(List<{{ inner_type_name }}> value) {
+    // Sizing comes before writing, so this catches a `null` before anything is written. Only an
+    // optional sequence can be `null`, `OptionalTemplate.java` writes it without calling this.
+    if (value == null) {
+      throw new NullPointerException("a `{{ inner_type_name }}` list that isn't optional can't be null, pass an empty list instead");
+    }
     long sizeForLength = 4L;
+    {%- if bulk_put.is_some() %}
+    long sizeForItems = (long) value.size() * {{ inner_type_name }}.BYTES;
+    {%- else %}
     long sizeForItems = value.stream().mapToLong(inner -> {{ inner_type|allocation_size_fn(config, ci) }}(inner)).sum();
+    {%- endif %}
     return sizeForLength + sizeForItems;
   }
 
   @Override
   public void write(List<{{ inner_type_name }}> value, ByteBuffer buf) {
     buf.putInt(value.size());
+    {%- match bulk_put %}
+    {%- when Some(put) %}
+    // Fixed size numbers are written directly, without a converter call per element.
+    for ({{ inner_type_name }} inner : value) {
+      buf.{{ put }}(inner);
+    }
+    {%- when None %}
     value.forEach(inner -> {{ inner_type|write_fn(config, ci) }}(inner, buf));
+    {%- endmatch %}
   }
+  {%- if self::grows_write_buffer(config, ci, type_) %}
+
+  @Override
+  public {{ "RustBuffer"|runtime_type(config) }}.ByValue lowerIntoRustBuffer(List<{{ inner_type_name }}> value) {
+    return {{ "LowerIntoGrowingBuffer"|internal(config) }}(value);
+  }
+  {%- endif %}
 }
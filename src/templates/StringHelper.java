@@ -9,34 +9,34 @@ import java.nio.charset.StandardCharsets;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
-public enum FfiConverterString implements FfiConverter<String, RustBuffer.ByValue> {
+{%- call java::suppress_warnings() %}
+public enum FfiConverterString implements FfiConverter<String, {{ "RustBuffer"|runtime_type(config) }}.ByValue> {
     INSTANCE;
 
+    static final java.nio.charset.Charset CHARSET = {{ config.string_charset() }};
+
     // Note: we don't inherit from FfiConverterRustBuffer, because we use a
     // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
     // store our length and avoid writing it out to the buffer.
     @Override
-    public String lift(RustBuffer.ByValue value) {
+    public String lift({{ "RustBuffer"|runtime_type(config) }}.ByValue value) {
         try {
             byte[] byteArr = new byte[(int) value.len];
             value.asByteBuffer().get(byteArr);
-            return new String(byteArr, StandardCharsets.UTF_8);
+            return new String(byteArr, CHARSET);
         } finally {
-            RustBuffer.free(value);
+            UniffiHelpers.uniffiRustBufferFree(value);
         }
     }
 
     @Override
     public String read(ByteBuffer buf) {
-        int len = buf.getInt();
-        byte[] byteArr = new byte[len];
-        buf.get(byteArr);
-        return new String(byteArr, StandardCharsets.UTF_8);
+        return RustBufferReader.readString(buf);
     }
 
-    private ByteBuffer toUtf8(String value) {
+    private ByteBuffer encode(String value) {
         // Make sure we don't have invalid UTF-16, check for lone surrogates.
-        CharsetEncoder encoder = StandardCharsets.UTF_8.newEncoder();
+        CharsetEncoder encoder = CHARSET.newEncoder();
         encoder.onMalformedInput(CodingErrorAction.REPORT);
         try {
             return encoder.encode(CharBuffer.wrap(value));
@@ -46,15 +46,25 @@ public enum FfiConverterString implements FfiConverter<String, RustBuffer.ByValu
     }
 
     @Override
-    public RustBuffer.ByValue lower(String value) {
-        ByteBuffer byteBuf = toUtf8(value);
+    public {{ "RustBuffer"|runtime_type(config) }}.ByValue lower(String value) {
+        ByteBuffer byteBuf = encode(value);
         // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
         // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
-        RustBuffer.ByValue rbuf = RustBuffer.alloc((long) byteBuf.limit());
+        {{ "RustBuffer"|runtime_type(config) }}.ByValue rbuf = UniffiHelpers.uniffiRustBufferAlloc((long) byteBuf.limit());
         rbuf.asByteBuffer().put(byteBuf);
         return rbuf;
     }
 
+    {%- if config.has_string_charset() %}
+    // We aren't sure exactly how many bytes our string will be once it's encoded. Allocate the
+    // most bytes the charset encodes a UTF-16 code unit to, for each of them and a byte order mark.
+    @Override
+    public long allocationSize(String value) {
+        long sizeForLength = 4L;
+        long sizeForString = (long) Math.ceil((value.length() + 1) * (double) CHARSET.newEncoder().maxBytesPerChar());
+        return sizeForLength + sizeForString;
+    }
+    {%- else %}
     // We aren't sure exactly how many bytes our string will be once it's UTF-8
     // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
     // enough.
@@ -64,10 +74,11 @@ public enum FfiConverterString implements FfiConverter<String, RustBuffer.ByValu
         long sizeForString = (long) value.length() * 3L;
         return sizeForLength + sizeForString;
     }
+    {%- endif %}
 
     @Override
     public void write(String value, ByteBuffer buf) {
-        ByteBuffer byteBuf = toUtf8(value);
+        ByteBuffer byteBuf = encode(value);
         buf.putInt(byteBuf.limit());
         buf.put(byteBuf);
     }
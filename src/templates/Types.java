@@ -8,54 +8,36 @@ import java.util.stream.Stream;{% if config.quarkus %}
 import io.quarkus.runtime.annotations.RegisterForProxy;{%- endif %}
 {% if config.quarkus %}
 @RegisterForProxy{%- endif %}
+{%- call java::suppress_warnings() %}
 public interface AutoCloseableHelper {
+    /**
+     * Close every argument that is {@link AutoCloseable}, and every closeable value inside the
+     * lists, sets and maps among them, including nested ones. Objects lifted inside a collection
+     * each own their Rust reference, so this frees them without waiting for the GC.
+     */
     static void close(Object... args) {
         Stream
             .of(args)
-            .forEach(obj -> {
-                // this is all to avoid the problem reported in uniffi-rs#2467
-                if (obj instanceof AutoCloseable) {
-                    try {
-                        ((AutoCloseable) obj).close();
-                    } catch (Exception e) {
-                        throw new RuntimeException(e);
-                    }
-                }
-                if (obj instanceof List<?>) {
-                    for (int i = 0; i < ((List) obj).size(); i++) {
-                        Object element = ((List) obj).get(i);
-                        if (element instanceof AutoCloseable) {
-                            try {
-                                ((AutoCloseable) element).close();
-                            } catch (Exception e) {
-                                throw new RuntimeException(e);
-                            }
-                        }
-                    }
-                }
-                if (obj instanceof Map<?, ?>) {
-                    for (var value : ((Map) obj).values()) {
-                        if (value instanceof AutoCloseable) {
-                            try {
-                                ((AutoCloseable) value).close();
-                            } catch (Exception e) {
-                                throw new RuntimeException(e);
-                            }
-                        }
-                    }
-                }
-                if (obj instanceof Iterable<?>) {
-                    for (var value : ((Iterable) obj)) {
-                        if (value instanceof AutoCloseable) {
-                            try {
-                                ((AutoCloseable) value).close();
-                            } catch (Exception e) {
-                                throw new RuntimeException(e);
-                            }
-                        }
-                    }
-                }
-            });
+            .forEach(AutoCloseableHelper::closeValue);
+    }
+
+    private static void closeValue(Object obj) {
+        // this is all to avoid the problem reported in uniffi-rs#2467
+        if (obj instanceof AutoCloseable) {
+            try {
+                ((AutoCloseable) obj).close();
+            } catch (Exception e) {
+                throw new RuntimeException(e);
+            }
+        } else if (obj instanceof Map<?, ?>) {
+            for (var value : ((Map<?, ?>) obj).values()) {
+                closeValue(value);
+            }
+        } else if (obj instanceof Iterable<?>) {
+            for (var value : ((Iterable<?>) obj)) {
+                closeValue(value);
+            }
+        }
     }
 }
 package {{ config.package_name() }};
@@ -63,6 +45,7 @@ package {{ config.package_name() }};
 import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 {% if config.quarkus %}
 @RegisterForReflection{%- endif %}
+{%- call java::suppress_warnings() %}
 public class NoPointer {
     // Private constructor to prevent instantiation
     private NoPointer() {}
@@ -71,7 +54,424 @@ public class NoPointer {
     public static final NoPointer INSTANCE = new NoPointer();
 }
 
+package {{ config.package_name() }};
+
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.nio.charset.StandardCharsets;
+
+/**
+ * Typed reads of the values in a {@code RustBuffer}, the same reads the FfiConverters do, for
+ * reading a buffer by hand.
+ *
+ * <p>Values are big-endian, the byte order UniFFI writes them in whatever the platform's is.
+ * Strings and byte arrays are prefixed with their length as a 4 byte {@code int}, strings are
+ * {% if config.has_string_charset() %}in the charset of {@code string_charset}{% else %}UTF-8{% endif %}, and booleans are a byte that's {@code 0} for {@code false}. Unsigned numbers are read
+ * into the signed type of the same size. A buffer doesn't say what's in it, so values have to be
+ * read in the order they were written.
+ */
+{%- call java::suppress_warnings() %}
+public final class RustBufferReader {
+    private static final java.nio.charset.Charset STRING_CHARSET = {{ config.string_charset() }};
+
+    private final ByteBuffer buf;
+
+    /**
+     * Read from {@code buf}, from its current position. The buffer is switched to big-endian.
+     */
+    public RustBufferReader(ByteBuffer buf) {
+        this.buf = buf.order(ByteOrder.BIG_ENDIAN);
+    }
+
+    /**
+     * Read the contents of {@code rbuf}. It isn't freed, that's still up to the caller once
+     * they're done reading.
+     */
+    public RustBufferReader({{ "RustBuffer"|runtime_type(config) }}.ByValue rbuf) {
+        this(rbuf.len == 0 ? ByteBuffer.allocate(0) : rbuf.asByteBuffer());
+    }
+
+    public byte readByte() {
+        return buf.get();
+    }
+
+    public short readShort() {
+        return buf.getShort();
+    }
+
+    public int readInt() {
+        return buf.getInt();
+    }
+
+    public long readLong() {
+        return buf.getLong();
+    }
+
+    public float readFloat() {
+        return buf.getFloat();
+    }
+
+    public double readDouble() {
+        return buf.getDouble();
+    }
+
+    public boolean readBoolean() {
+        return buf.get() != 0;
+    }
+
+    public String readString() {
+        return readString(buf);
+    }
+
+    public byte[] readBytes() {
+        return readBytes(buf);
+    }
+
+    /** The number of bytes left to read. */
+    public int remaining() {
+        return buf.remaining();
+    }
+
+    public boolean hasRemaining() {
+        return buf.hasRemaining();
+    }
+
+    static String readString(ByteBuffer buf) {
+        return new String(readBytes(buf), STRING_CHARSET);
+    }
+
+    static byte[] readBytes(ByteBuffer buf) {
+        int len = {% call java::read_length(1) %};
+        byte[] bytes = new byte[len];
+        buf.get(bytes);
+        return bytes;
+    }
+}
+{%- if config.safe_buffer_reads %}
+
+package {{ config.package_name() }};
+
+import java.nio.ByteBuffer;
+
+/**
+ * Bounds checks on what is read from a `RustBuffer`, generated with `safe_buffer_reads`.
+ */
+{%- call java::suppress_warnings() %}
+public interface RustBufferChecks {
+    /**
+     * Read a length prefix, checking that {@code len} items of at least {@code minItemSize} bytes
+     * fit in the rest of the buffer.
+     */
+    static int readLength(ByteBuffer buf, int minItemSize) {
+        int len = buf.getInt();
+        if (len < 0 || (long) len * minItemSize > buf.remaining()) {
+            throw new IndexOutOfBoundsException(
+                "length " + len + " read from RustBuffer doesn't fit in the " + buf.remaining() + " remaining bytes");
+        }
+        return len;
+    }
+}
+{%- endif %}
+{%- if config.deep_equals %}
+
+package {{ config.package_name() }};
+
+import java.util.Arrays;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Equality by content for values holding byte arrays, which Java compares by identity, generated
+ * with `deep_equals`. Lists and map values are compared recursively.
+ */
+{%- call java::suppress_warnings() %}
+public final class UniffiDeepEquality {
+    private UniffiDeepEquality() {}
+
+    public static boolean equals(Object a, Object b) {
+        if (a instanceof byte[] x && b instanceof byte[] y) {
+            return Arrays.equals(x, y);
+        }
+        if (a instanceof List<?> x && b instanceof List<?> y) {
+            if (x.size() != y.size()) {
+                return false;
+            }
+            Iterator<?> it = y.iterator();
+            for (Object item : x) {
+                if (!equals(item, it.next())) {
+                    return false;
+                }
+            }
+            return true;
+        }
+        if (a instanceof Map<?, ?> x && b instanceof Map<?, ?> y) {
+            if (x.size() != y.size()) {
+                return false;
+            }
+            for (Map.Entry<?, ?> entry : x.entrySet()) {
+                if (!y.containsKey(entry.getKey()) || !equals(entry.getValue(), y.get(entry.getKey()))) {
+                    return false;
+                }
+            }
+            return true;
+        }
+        return Objects.equals(a, b);
+    }
+
+    // Follows the `List.hashCode` and `Map.hashCode` contracts, with byte arrays hashed by content.
+    public static int hashCode(Object a) {
+        if (a instanceof byte[] x) {
+            return Arrays.hashCode(x);
+        }
+        if (a instanceof List<?> x) {
+            int hash = 1;
+            for (Object item : x) {
+                hash = 31 * hash + hashCode(item);
+            }
+            return hash;
+        }
+        if (a instanceof Map<?, ?> x) {
+            int hash = 0;
+            for (Map.Entry<?, ?> entry : x.entrySet()) {
+                hash += hashCode(entry.getKey()) ^ hashCode(entry.getValue());
+            }
+            return hash;
+        }
+        return Objects.hashCode(a);
+    }
+}
+{%- endif %}
+{%- if config.generates_record_copy() %}
+
+package {{ config.package_name() }};
+
+import java.util.ArrayList;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.function.UnaryOperator;
+
+/**
+ * Copies of the lists and maps in records, for their {@code copy()} generated with
+ * {@code record_copy}. The copies are mutable, like the records, and keep {@code null}.
+ */
+{%- call java::suppress_warnings() %}
+public final class UniffiDeepCopy {
+    private UniffiDeepCopy() {}
+
+    public static <T> List<T> list(List<T> value, UnaryOperator<T> copyItem) {
+        if (value == null) {
+            return null;
+        }
+        List<T> copy = new ArrayList<>(value.size());
+        for (T item : value) {
+            copy.add(copyItem.apply(item));
+        }
+        return copy;
+    }
+
+    public static <K, V> Map<K, V> map(Map<K, V> value, UnaryOperator<V> copyValue) {
+        if (value == null) {
+            return null;
+        }
+        Map<K, V> copy = new LinkedHashMap<>();
+        for (Map.Entry<K, V> entry : value.entrySet()) {
+            copy.put(entry.getKey(), copyValue.apply(entry.getValue()));
+        }
+        return copy;
+    }
+}
+{%- endif %}
+{%- if config.record_map_conversion %}
+
+package {{ config.package_name() }};
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.function.Function;
+
+/**
+ * Reads the fields of records from maps, for their {@code fromMap} generated with
+ * {@code record_map_conversion}. A missing or {@code null} value is {@code null} for optional
+ * fields, and an {@code IllegalArgumentException} naming the field for the others, like a value of
+ * the wrong type.
+ */
+{%- call java::suppress_warnings() %}
+public final class UniffiRecordMaps {
+    private UniffiRecordMaps() {}
+
+    public static <T> T get(Map<String, ?> map, String record, String key, Class<T> type, boolean optional) {
+        Object value = map.get(key);
+        if (value == null) {
+            if (optional) {
+                return null;
+            }
+            throw new IllegalArgumentException(record + "." + key + " is missing");
+        }
+        if (!type.isInstance(value)) {
+            throw new IllegalArgumentException(
+                record + "." + key + " has to be a " + type.getName() + ", not a " + value.getClass().getName());
+        }
+        return type.cast(value);
+    }
+
+    public static <T> T record(
+        Map<String, ?> map, String record, String key, Function<Map<String, Object>, T> fromMap, boolean optional
+    ) {
+        Map<String, Object> value = get(map, record, key, Map.class, optional);
+        return value == null ? null : fromMap.apply(value);
+    }
+
+    public static <T> List<T> records(
+        Map<String, ?> map, String record, String key, Function<Map<String, Object>, T> fromMap
+    ) {
+        List<?> value = get(map, record, key, List.class, false);
+        List<T> records = new ArrayList<>(value.size());
+        for (Object item : value) {
+            if (item != null && !(item instanceof Map)) {
+                throw new IllegalArgumentException(
+                    record + "." + key + " has to be a list of maps, not of " + item.getClass().getName());
+            }
+            records.add(item == null ? null : fromMap.apply((Map<String, Object>) item));
+        }
+        return records;
+    }
+}
+{%- endif %}
+{%- if config.stable_record_hash() %}
+
+package {{ config.package_name() }};
+
+import java.nio.charset.StandardCharsets;
+import java.time.Duration;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Record hashes that are the same on every JVM, generated with {@code record_hash = "fnv"}. They
+ * are FNV-1a over the field values, where {@code Objects.hash} isn't stable across JVMs for values
+ * like enums, whose {@code hashCode()} is their identity.
+ *
+ * <p>Strings are hashed by their UTF-8 bytes, numbers by their value, enums by their name, and
+ * lists and maps by their content. Other values, like records, use their own {@code hashCode()}.
+ */
+{%- call java::suppress_warnings() %}
+public final class UniffiStableHash {
+    private static final int OFFSET_BASIS = 0x811c9dc5;
+    private static final int PRIME = 0x01000193;
+
+    private UniffiStableHash() {}
+
+    public static int hashAll(Object... values) {
+        int hash = OFFSET_BASIS;
+        for (Object value : values) {
+            hash = mixInt(hash, hash(value));
+        }
+        return hash;
+    }
+
+    public static int hash(Object value) {
+        if (value == null) {
+            return 0;
+        }
+        if (value instanceof String x) {
+            return mixBytes(OFFSET_BASIS, x.getBytes(StandardCharsets.UTF_8));
+        }
+        if (value instanceof byte[] x) {
+            return mixBytes(OFFSET_BASIS, x);
+        }
+        if (value instanceof Boolean x) {
+            return mixLong(OFFSET_BASIS, x ? 1 : 0);
+        }
+        if (value instanceof Float || value instanceof Double) {
+            return mixLong(OFFSET_BASIS, Double.doubleToLongBits(((Number) value).doubleValue()));
+        }
+        if (value instanceof Number x) {
+            return mixLong(OFFSET_BASIS, x.longValue());
+        }
+        if (value instanceof Enum<?> x) {
+            return hash(x.name());
+        }
+        if (value instanceof Instant x) {
+            return mixLong(mixLong(OFFSET_BASIS, x.getEpochSecond()), x.getNano());
+        }
+        if (value instanceof Duration x) {
+            return mixLong(mixLong(OFFSET_BASIS, x.getSeconds()), x.getNano());
+        }
+        if (value instanceof List<?> x) {
+            int hash = OFFSET_BASIS;
+            for (Object item : x) {
+                hash = mixInt(hash, hash(item));
+            }
+            return hash;
+        }
+        if (value instanceof Map<?, ?> x) {
+            // Summed, so it doesn't depend on the iteration order
+            int hash = 0;
+            for (Map.Entry<?, ?> entry : x.entrySet()) {
+                hash += mixInt(mixInt(OFFSET_BASIS, hash(entry.getKey())), hash(entry.getValue()));
+            }
+            return hash;
+        }
+        return value.hashCode();
+    }
+
+    private static int mixBytes(int hash, byte[] bytes) {
+        for (byte b : bytes) {
+            hash = (hash ^ (b & 0xff)) * PRIME;
+        }
+        return hash;
+    }
+
+    // The bytes of `value` are mixed in little-endian order.
+    private static int mixInt(int hash, int value) {
+        for (int i = 0; i < 4; i++) {
+            hash = (hash ^ ((value >>> (8 * i)) & 0xff)) * PRIME;
+        }
+        return hash;
+    }
+
+    private static int mixLong(int hash, long value) {
+        return mixInt(mixInt(hash, (int) value), (int) (value >>> 32));
+    }
+}
+{%- endif %}
+{%- if config.results_as_values %}
+
+package {{ config.package_name() }};
+
+/**
+ * The outcome of a fallible call, returned instead of throwing when generated with
+ * `results_as_values`.
+ */
+{%- call java::suppress_warnings() %}
+public sealed interface UniffiResult<T, E extends Exception> {
+    record Ok<T, E extends Exception>(T value) implements UniffiResult<T, E> {}
+
+    record Err<T, E extends Exception>(E error) implements UniffiResult<T, E> {}
+
+    default boolean isOk() {
+        return this instanceof Ok;
+    }
+
+    /**
+     * The value of an {@code Ok}, or throw the error of an {@code Err}.
+     */
+    default T getOrThrow() throws E {
+        if (this instanceof Ok<T, E> ok) {
+            return ok.value();
+        }
+        throw ((Err<T, E>) this).error();
+    }
+}
+{%- endif %}
+
 {%- for type_ in ci.iter_local_types() %}
+{%- if !self::is_excluded_type(config, ci, type_) %}
 {%- let type_name = type_|type_name(ci, config) %}
 {%- let ffi_converter_name = type_|ffi_converter_name %}
 {%- let ffi_converter_instance = type_|ffi_converter_instance(config, ci) %}
@@ -155,4 +555,5 @@ public class NoPointer {
 
 {%- else %}
 {%- endmatch %}
+{%- endif %}
 {%- endfor %}
@@ -0,0 +1,39 @@
+{%- if !config.package_name().is_empty() %}
+package {{ config.package_name() }};
+
+{% endif -%}
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNotEquals;
+
+import org.junit.jupiter.api.Test;
+
+/**
+ * Checks that the records and enums of the {{ namespace }} bindings behave as values: two instances
+ * made out of the same field values are equal both ways, hash the same and print the same.
+ *
+ * <p>Generated with {@code emit_value_tests}, each instance has the zero value of its fields.
+ * Byte arrays print by identity, so values holding them aren't checked to print the same.
+{%- if !skipped.is_empty() %}
+ * Not covered, no value could be made up for them: {{ skipped|join(", ") }}.
+{%- endif %}
+ */
+class {{ class_name }} {
+  {%- for (test_name, sample, prints_same) in samples %}
+
+  @Test
+  void {{ test_name }}() {
+    assertValueSemantics({{ sample }}, {{ sample }}, {{ prints_same }});
+  }
+  {%- endfor %}
+
+  private static void assertValueSemantics(Object value, Object sameValue, boolean printsSame) {
+    assertEquals(value, value, "equals isn't reflexive");
+    assertEquals(value, sameValue);
+    assertEquals(sameValue, value, "equals isn't symmetric");
+    assertEquals(value.hashCode(), sameValue.hashCode(), "equal values hash differently");
+    if (printsSame) {
+      assertEquals(value.toString(), sameValue.toString(), "equal values print differently");
+    }
+    assertNotEquals(null, value);
+  }
+}
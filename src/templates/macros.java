@@ -36,20 +36,77 @@
     })
 {%- endmacro -%}
 
-{%- macro func_decl(func_decl, annotation, callable, indent) %}
+{%- macro func_decl(func_decl, annotation, callable, indent, results_as_values, owner) %}
     {%- if self::can_render_callable(callable, ci) %}
-    {%- call docstring(callable, indent) %}
+    {%- let as_result = results_as_values && callable.throws_type().is_some() %}
+    {%- call deprecated_docstring(callable, indent, owner) %}
     {%- if annotation != "" %}
     @{{ annotation }}
     {% endif %}
-    {%- if callable.is_async() %}
-    {{ func_decl }} CompletableFuture<{% match callable.return_type() -%}{%- when Some with (return_type) -%}{{ return_type|type_name(ci, config) }}{%- when None %}Void{%- endmatch %}> {{ callable.name()|fn_name }}(
+    {%- if config.contract_annotations %}
+    {%- match self::contract_annotation(callable) %}
+    {%- when Some(contract) %}
+    @org.jetbrains.annotations.Contract("{{ contract }}")
+    {% else %}
+    {%- endmatch %}
+    {%- endif %}
+    {%- if callable.is_async() && as_result %}
+    {{ func_decl }} CompletableFuture<{{ callable|result_type(ci, config) }}> {{ callable.name()|callable_name(owner, config) }}(
+        {%- call arg_list(callable, !callable.takes_self()) -%}
+    ){
+        return {% call call_async(callable) %}.<{{ callable|result_type(ci, config) }}>handle((value, error) -> {
+            Throwable cause = error instanceof java.util.concurrent.CompletionException ? error.getCause() : error;
+            if (cause == null) {
+                return new UniffiResult.Ok<>(value);
+            }
+            {%- match callable.throws_type() %}
+            {%- when Some(throwable) %}
+            if (cause instanceof {{ throwable|type_name(ci, config) }} err) {
+                return new UniffiResult.Err<>(err);
+            }
+            {%- else %}
+            {%- endmatch %}
+            throw new java.util.concurrent.CompletionException(cause);
+        });
+    }
+    {%- else if callable.is_async() %}
+    {{ func_decl }} CompletableFuture<{% match callable.return_type() -%}{%- when Some with (return_type) -%}{{ return_type|type_name(ci, config) }}{%- when None %}Void{%- endmatch %}> {{ callable.name()|callable_name(owner, config) }}(
         {%- call arg_list(callable, !callable.takes_self()) -%}
     ){
         return {% call call_async(callable) %};
     }
+    {%- else if as_result -%}
+    {{ func_decl }} {{ callable|result_type(ci, config) }} {{ callable.name()|callable_name(owner, config) }}(
+        {%- call arg_list(callable, !callable.takes_self()) -%}
+    ) {
+            try {
+                {% match callable.return_type() -%}{%- when Some with (return_type) -%}return new UniffiResult.Ok<>({{ return_type|lift_fn(config, ci) }}({% call to_ffi_call(callable) %})){%- when None %}{% call to_ffi_call(callable) %};
+                return new UniffiResult.Ok<>(null){%- endmatch %};
+            {#- Methods get the error wrapped by `callWithPointer`, other calls throw it as is #}
+            {%- if !callable.takes_self() %}
+            {%- match callable.throws_type() %}
+            {%- when Some(throwable) %}
+            } catch ({{ throwable|type_name(ci, config) }} _err) {
+                return new UniffiResult.Err<>(_err);
+            {%- else %}
+            {%- endmatch %}
+            {%- endif %}
+            } catch (RuntimeException _e) {
+                {%- match callable.throws_type() %}
+                {%- when Some(throwable) %}
+                if ({{ throwable|type_name(ci, config) }}.class.isInstance(_e.getCause())) {
+                    return new UniffiResult.Err<>(({{ throwable|type_name(ci, config) }})_e.getCause());
+                }
+                {%- else %}
+                {%- endmatch %}
+                if (InternalException.class.isInstance(_e.getCause())) {
+                    throw (InternalException)_e.getCause();
+                }
+                throw _e;
+            }
+    }
     {%- else -%}
-    {{ func_decl }} {% match callable.return_type() -%}{%- when Some with (return_type) -%}{{ return_type|type_name(ci, config) }}{%- when None %}void{%- endmatch %} {{ callable.name()|fn_name }}(
+    {{ func_decl }} {% match callable.return_type() -%}{%- when Some with (return_type) -%}{{ return_type|type_name(ci, config) }}{%- when None %}void{%- endmatch %} {{ callable.name()|callable_name(owner, config) }}(
         {%- call arg_list(callable, !callable.takes_self()) -%}
     ) {% match callable.throws_type() -%}
         {%-     when Some(throwable) -%}
@@ -73,11 +130,460 @@
             }
     }
     {% endif %}
+    {%- if !callable.takes_self() %}
+    {%- call blocking_async(func_decl, callable, as_result, owner) %}
+    {%- call default_overloads(func_decl, callable, indent, as_result, owner) %}
+    {%- endif %}
+    {%- if config.sequence_varargs %}
+    {%- call varargs_overload(func_decl, callable, indent, as_result, owner) %}
+    {%- endif %}
     {%- else %}
     // Sorry, the callable "{{ callable.name() }}" isn't supported.
     {%- endif %}
 {% endmacro %}
 
+{#-
+// `@SuppressWarnings` for the `suppress_warnings` of generated top-level types, on its own line.
+#}
+{%- macro suppress_warnings() %}
+{%- match config.suppress_warnings_annotation() %}
+{%- when Some(annotation) %}
+{{ annotation }}
+{%- when None %}
+{%- endmatch %}
+{%- endmacro %}
+
+{#-
+// Static `Comparator` factories for the fields of a record with a natural order, generated with
+// `record_comparators`.
+#}
+{%- macro record_comparators(rec, type_name) %}
+    {%- if config.record_comparators %}
+    {%- for field in rec.fields() %}
+    {%- match self::field_comparator(config, ci, type_name, field) %}
+    {%- when Some(comparator) %}
+
+    /**
+     * Compares {@code {{ type_name }}} by {@code {{ field.name()|var_name(config) }}}.
+     */
+    public static Comparator<{{ type_name }}> {{ self::comparator_method_name(config, field) }}() {
+        return {{ comparator }};
+    }
+    {%- else %}
+    {%- endmatch %}
+    {%- endfor %}
+    {%- endif %}
+{%- endmacro %}
+
+{#-
+// `compareTo` of records listed in `comparable`, by their ordering fields in turn.
+#}
+{%- macro record_compare_to(rec, type_name) %}
+    {%- match self::natural_order(config, ci, type_name, rec) %}
+    {%- when Some(natural_order) %}
+
+    private static final Comparator<{{ type_name }}> {{ "NaturalOrder"|internal(config) }} = {{ natural_order }};
+
+    @Override
+    public int compareTo({{ type_name }} other) {
+        return {{ "NaturalOrder"|internal(config) }}.compare(this, other);
+    }
+    {%- else %}
+    {%- endmatch %}
+{%- endmacro %}
+
+{#-
+// The discriminant `Comparator` of flat enums listed in `comparable`. Their `compareTo` is final.
+#}
+{%- macro enum_discriminant_order(e, type_name) %}
+  {%- match self::discriminant_order(config, ci, e) %}
+  {%- when Some(discriminant_order) %}
+
+  /**
+   * Orders variants by their discriminant, like the derived {@code Ord} of the Rust enum.
+   */
+  public static Comparator<{{ type_name }}> discriminantOrder() {
+    return {{ discriminant_order }};
+  }
+  {%- else %}
+  {%- endmatch %}
+{%- endmacro %}
+
+{#-
+// Compare a record field with the one of `t` in `equals`, by content for byte arrays with
+// `deep_equals`.
+#}
+{%- macro field_equals(field) -%}
+{%- let field_var_name = field.name()|var_name(config) -%}
+{%- if self::needs_deep_equality(config, field) %}UniffiDeepEquality.equals{% else %}Objects.equals{% endif %}({{ field_var_name }}, t.{{ field_var_name }})
+{%- endmacro -%}
+
+{#-
+// A record field as passed to `Objects.hash` in `hashCode`, hashed by content for byte arrays with
+// `deep_equals`.
+#}
+{%- macro field_hash(field) -%}
+{%- if self::needs_deep_equality(config, field) %}UniffiDeepEquality.hashCode({{ field.name()|var_name(config) }}){% else %}{{ field.name()|var_name(config) }}{% endif %}
+{%- endmacro -%}
+
+{#-
+// The `hashCode()` of a record, from `Objects.hash` or `UniffiStableHash` with `record_hash`.
+#}
+{%- macro fields_hash(fields) -%}
+{%- if config.stable_record_hash() %}UniffiStableHash.hashAll{% else %}Objects.hash{% endif %}({% for field in fields %}{% call field_hash(field) %}{% if !loop.last%}, {% endif %}{% endfor %})
+{%- endmacro -%}
+
+{#-
+// Static `from(Other)` factories converting records with the same fields, generated with
+// `record_conversions`.
+#}
+{%- macro record_conversions(rec, type_name) %}
+    {%- for source_type_name in rec|conversion_sources(ci, config) %}
+
+    /**
+     * Convert a {@code {{ source_type_name }}}, which has the same fields.
+     */
+    public static {{ type_name }} from({{ source_type_name }} other) {
+        return new {{ type_name }}({% for field in rec.fields() %}other.{{ field.name()|var_name(config) }}(){% if !loop.last %}, {% endif %}{% endfor %});
+    }
+    {%- endfor %}
+{%- endmacro %}
+
+{#-
+// `builder()` and `toBuilder()`, generated with `record_builders`.
+#}
+{%- macro record_builder(rec, type_name) %}
+    {%- if config.record_builders && rec.has_fields() %}
+
+    /**
+     * A {@link Builder} of {@code {{ type_name }}}, starting from the defaults of the Rust record.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * A {@link Builder} starting from the field values of this record, to build a copy with some of
+     * them changed.
+     */
+    public Builder toBuilder() {
+        return new Builder()
+            {%- for field in rec.fields() %}
+            .{{ self::builder_method_name(config, field) }}(this.{{ field.name()|var_name(config) }}){% if loop.last %};{% endif %}
+            {%- endfor %}
+    }
+
+    /**
+     * Builds a {@code {{ type_name }}} one field at a time. Fields without a Rust default start out
+     * {@code null}.
+     */
+    public static final class Builder {
+        {%- for field in rec.fields() %}
+        private {{ field|type_name(ci, config) }} {{ field.name()|var_name(config) }}{% match self::builder_default(ci, config, field) %}{% when Some(default) %} = {{ default }}{% when None %}{% endmatch %};
+        {%- endfor %}
+
+        private Builder() {}
+        {%- for field in rec.fields() %}
+        {%- let field_var_name = field.name()|var_name(config) %}
+
+        public Builder {{ self::builder_method_name(config, field) }}({{ field|type_name(ci, config) }} {{ field_var_name }}) {
+            this.{{ field_var_name }} = {{ field_var_name }};
+            return this;
+        }
+        {%- endfor %}
+
+        public {{ type_name }} build() {
+            return new {{ type_name }}({% for field in rec.fields() %}{{ field.name()|var_name(config) }}{% if !loop.last %}, {% endif %}{% endfor %});
+        }
+    }
+    {%- endif %}
+{%- endmacro %}
+
+{#-
+// Bean getters and property name constants for MapStruct, generated with `mapstruct`. It doesn't
+// take `name()` accessors of classes as getters.
+#}
+{%- macro record_map_conversion(rec, type_name) %}
+    {%- if config.record_map_conversion && rec.has_fields() %}
+
+    /**
+     * The fields of this record by name, with nested records as maps too, see {@link #fromMap}.
+     */
+    public Map<String, Object> toMap() {
+        Map<String, Object> map = new java.util.LinkedHashMap<>();
+        {%- for field in rec.fields() %}
+        map.put("{{ self::map_key(config, field) }}", {{ self::to_map_value(ci, config, field) }});
+        {%- endfor %}
+        return map;
+    }
+
+    /**
+     * A {@code {{ type_name }}} of the fields in {@code map}, as {@link #toMap} puts them. Optional
+     * fields missing from it are {@code null}.
+     *
+     * @throws IllegalArgumentException if a field that isn't optional is missing, or a value doesn't
+     *     have the type of its field
+     */
+    public static {{ type_name }} fromMap(Map<String, Object> map) {
+        return new {{ type_name }}(
+            {%- for field in rec.fields() %}
+            {{ self::from_map_value(ci, config, type_name, field) }}{% if !loop.last %},{% endif %}
+            {%- endfor %}
+        );
+    }
+    {%- endif %}
+{%- endmacro %}
+{%- macro mapstruct(rec) %}
+    {%- if config.mapstruct && rec.has_fields() %}
+    {%- for field in rec.fields() %}
+
+    public {{ self::record_field_type(ci, config, rec, field) }} {{ self::bean_getter_name(config, field) }}() {
+        return {{ field.name()|var_name(config) }}();
+    }
+    {%- endfor %}
+
+    /**
+     * The property names MapStruct maps, as constants for {@code @Mapping(target = ...)}.
+     */
+    public static final class Fields {
+        {%- for (constant, property) in self::property_name_constants(config, rec) %}
+        public static final String {{ constant }} = "{{ property }}";
+        {%- endfor %}
+
+        private Fields() {}
+    }
+    {%- endif %}
+{%- endmacro %}
+
+{#-
+// `android.os.Parcelable` for records and enums with `android_parcelable`. The value is parceled as
+// the bytes it's serialized to for Rust, `Parcel` has nothing for most field types. In the sealed
+// interface of an enum with fields the methods are `default` ones, for each variant.
+#}
+{%- macro parcelable(type_, type_name, in_interface) %}
+  {%- if self::is_parcelable(config, ci, type_) %}
+  {%- let converter = type_|ffi_converter_instance(config, ci) %}
+
+    {% if in_interface %}default{% else %}@Override
+    public{% endif %} int describeContents() {
+        return 0;
+    }
+
+    {% if in_interface %}default{% else %}@Override
+    public{% endif %} void writeToParcel(android.os.Parcel dest, int flags) {
+        java.nio.ByteBuffer buf = java.nio.ByteBuffer.allocate((int) {{ converter }}.allocationSize(this));
+        {{ converter }}.write(this, buf);
+        dest.writeByteArray(java.util.Arrays.copyOf(buf.array(), buf.position()));
+    }
+
+    public static final android.os.Parcelable.Creator<{{ type_name }}> CREATOR = new android.os.Parcelable.Creator<{{ type_name }}>() {
+        @Override
+        public {{ type_name }} createFromParcel(android.os.Parcel source) {
+            return {{ converter }}.read(java.nio.ByteBuffer.wrap(source.createByteArray()));
+        }
+
+        @Override
+        public {{ type_name }}[] newArray(int size) {
+            return new {{ type_name }}[size];
+        }
+    };
+  {%- endif %}
+{%- endmacro %}
+
+{#-
+// `VARIANT_COUNT` and `VARIANT_NAMES` of an enum, so its variants can be enumerated without
+// `values()` copying them.
+#}
+{%- macro enum_variant_constants(e) %}
+  {%- let variant_names = self::enum_::variant_names(e, ci) %}
+
+  /**
+   * The number of variants of the Rust enum.
+   */
+  public static final int VARIANT_COUNT = {{ variant_names.len() }};
+
+  /**
+   * The names of the variants, in declaration order.
+   */
+  public static final List<String> VARIANT_NAMES = List.of(
+    {%- for variant_name in variant_names %}"{{ variant_name }}"{% if !loop.last %}, {% endif %}{% endfor -%}
+  );
+{%- endmacro %}
+
+{#-
+// The `kind()` of a variant record of an enum with fields, generated with `enum_with_tag`.
+#}
+{%- macro enum_tag(variant, type_name) %}
+    {%- if config.enum_with_tag %}
+
+    @Override
+    public {{ type_name }}Kind kind() {
+      return {{ type_name }}Kind.{{ variant|variant_name }};
+    }
+    {%- endif %}
+{%- endmacro %}
+
+{#-
+// A `Visitor` with a method per variant of a flat enum and an `accept` calling the right one,
+// generated with `enum_visitor`. Java enums can't be sealed, this gets them checked exhaustively.
+#}
+{%- macro enum_visitor(e, type_name) %}
+  {%- if config.enum_visitor %}
+  {%- let visitor_methods = self::enum_::visitor_methods(e, config) %}
+
+  /**
+   * A method for each variant of {@link {{ type_name }}}, see {@link #accept(Visitor)}. A variant
+   * added to the Rust enum adds a method, so visitors that don't handle it stop compiling.
+   */
+  public interface Visitor<R> {
+    {%- for (variant, method) in visitor_methods %}
+    R {{ method }}();
+    {%- endfor %}
+  }
+
+  /**
+   * Call the method of {@code visitor} for this variant, and return what it returns.
+   */
+  public <R> R accept(Visitor<R> visitor) {
+    switch (this) {
+      {%- for (variant, method) in visitor_methods %}
+      case {{ variant }}:
+        return visitor.{{ method }}();
+      {%- endfor %}
+      default:
+        throw new AssertionError(this);
+    }
+  }
+  {%- endif %}
+{%- endmacro %}
+
+{#-
+// Blocking counterpart of an async callable, generated with `emit_blocking_async`. It waits for the
+// future and rethrows what it failed with, instead of the `CompletionException` wrapping it.
+#}
+{%- macro blocking_async(func_decl, callable, as_result, owner) %}
+    {%- if config.emit_blocking_async && callable.is_async() %}
+
+    /**
+     * Blocking version of {@code {{ callable.name()|callable_name(owner, config) }}}, waiting for the future it returns.
+     *
+     * <p>Don't call this on a thread Rust calls back into Java on, such as from a callback
+     * interface implementation, it can deadlock waiting for work that needs that thread.
+     */
+    {{ func_decl }} {% if as_result %}{{ callable|result_type(ci, config) }}{% else %}{% match callable.return_type() -%}{%- when Some with (return_type) -%}{{ return_type|type_name(ci, config) }}{%- when None %}void{%- endmatch %}{% endif %} {{ callable.name()|callable_name(owner, config) }}Blocking(
+        {%- call arg_list(callable, !callable.takes_self()) -%}
+    ) {% if !as_result %}{% match callable.throws_type() -%}
+        {%-     when Some(throwable) -%}
+        throws {{ throwable|type_name(ci, config) }}
+        {%-     else -%}
+        {%- endmatch %}{% endif %} {
+        try {
+            {% if as_result || callable.return_type().is_some() %}return {% endif %}{{ callable.name()|callable_name(owner, config) }}(
+                {%- for arg in callable.arguments() -%}
+                {{ arg.name()|var_name(config) }}{% if !loop.last %}, {% endif -%}
+                {%- endfor -%}
+            ).join();
+        } catch (java.util.concurrent.CompletionException e) {
+            {%- match callable.throws_type() %}
+            {%- when Some(throwable) %}
+            {%- if !as_result %}
+            if (e.getCause() instanceof {{ throwable|type_name(ci, config) }} cause) {
+                throw cause;
+            }
+            {%- endif %}
+            {%- else %}
+            {%- endmatch %}
+            if (e.getCause() instanceof RuntimeException cause) {
+                throw cause;
+            }
+            if (e.getCause() instanceof Error cause) {
+                throw cause;
+            }
+            throw e;
+        }
+    }
+    {%- endif %}
+{%- endmacro %}
+
+{#-
+// Overload taking a trailing sequence argument as varargs, delegating to the `List` version.
+// `Arrays.asList` is used instead of `List.of` because sequences may contain nulls.
+#}
+{%- macro varargs_overload(func_decl, callable, indent, as_result, owner) %}
+    {%- match self::varargs_element_type(callable) %}
+    {%- when Some(element_type) %}
+    {%- call deprecated_docstring(callable, indent, owner) %}
+    {%- let safe_varargs = self::needs_safe_varargs(element_type, ci, config) %}
+    {%- if safe_varargs %}
+    @SafeVarargs
+    {%- endif %}
+    {{ func_decl }}{% if safe_varargs && !func_decl.contains("static") %} final{% endif %} {% call return_type(callable, as_result) %} {{ callable.name()|callable_name(owner, config) }}(
+        {%- for arg in callable.arguments() -%}
+        {%- if loop.last -%}
+        {{ element_type|type_name(ci, config) }}... {{ arg.name()|var_name(config) }}
+        {%- else -%}
+        {{ arg|param_type_name(ci, config) }} {{ arg.name()|var_name(config) }}, {% endif -%}
+        {%- endfor -%}
+    ) {% call overload_throws(callable, as_result) %} {
+        {% if callable.is_async() || as_result || callable.return_type().is_some() %}return {% endif %}{{ callable.name()|callable_name(owner, config) }}(
+            {%- for arg in callable.arguments() -%}
+            {%- if loop.last -%}
+            java.util.Arrays.asList({{ arg.name()|var_name(config) }})
+            {%- else -%}
+            {{ arg.name()|var_name(config) }}, {% endif -%}
+            {%- endfor -%}
+        );
+    }
+    {%- when None %}
+    {%- endmatch %}
+{%- endmacro %}
+
+{#-
+// Overloads leaving out the trailing arguments that have defaults, calling the full method with
+// the default values.
+#}
+{%- macro default_overloads(func_decl, callable, indent, as_result, owner) %}
+    {%- for (kept, defaulted) in self::default_overload_args(callable, config) %}
+    {%- call deprecated_docstring(callable, indent, owner) %}
+    {{ func_decl }} {% call return_type(callable, as_result) %} {{ callable.name()|callable_name(owner, config) }}(
+        {%- for arg in kept -%}
+        {{ arg|param_type_name(ci, config) }} {{ arg.name()|var_name(config) }}{% if !loop.last %}, {% endif -%}
+        {%- endfor -%}
+    ) {% call overload_throws(callable, as_result) %} {
+        {%- match self::unsupported_default(defaulted) %}
+        {%- when Some(unsupported) %}
+        throw new UnsupportedOperationException("the default of `{{ unsupported.name()|var_name(config) }}` can't be written in Java");
+        {%- when None %}
+        {% if callable.is_async() || as_result || callable.return_type().is_some() %}return {% endif %}{{ callable.name()|callable_name(owner, config) }}(
+            {%- for arg in kept -%}
+            {{ arg.name()|var_name(config) }}, {% endfor -%}
+            {%- for arg in defaulted -%}
+            {{ arg|default_literal(ci, config) }}{% if !loop.last %}, {% endif -%}
+            {%- endfor -%}
+        );
+        {%- endmatch %}
+    }
+    {%- endfor %}
+{%- endmacro %}
+
+{#-
+// The return type of an overload delegating to `callable`.
+#}
+{%- macro return_type(callable, as_result) -%}
+{% if callable.is_async() && as_result %}CompletableFuture<{{ callable|result_type(ci, config) }}>{% else if as_result %}{{ callable|result_type(ci, config) }}{% else if callable.is_async() %}CompletableFuture<{% match callable.return_type() -%}{%- when Some with (return_type) -%}{{ return_type|type_name(ci, config) }}{%- when None %}Void{%- endmatch %}>{% else %}{% match callable.return_type() -%}{%- when Some with (return_type) -%}{{ return_type|type_name(ci, config) }}{%- when None %}void{%- endmatch %}{% endif %}
+{%- endmacro -%}
+
+{#-
+// The `throws` clause of an overload delegating to `callable`.
+#}
+{%- macro overload_throws(callable, as_result) -%}
+{%- if !callable.is_async() && !as_result %}{% match callable.throws_type() -%}
+{%-     when Some(throwable) -%}
+throws {{ throwable|type_name(ci, config) }}
+{%-     else -%}
+{%- endmatch %}{% endif %}
+{%- endmacro -%}
+
 {%- macro call_async(callable) -%}
     UniffiAsyncHelpers.uniffiRustCallAsync(
 {%- if callable.takes_self() %}
@@ -112,7 +618,7 @@
 
 {%- macro arg_list_lowered(func) %}
     {%- for arg in func.arguments() %}
-        {{- arg|lower_fn(config, ci) }}({{ arg.name()|var_name }})
+        {{- arg|lower_fn(config, ci) }}({{ arg.name()|var_name(config) }}{% if self::is_char_sequence_param(arg, config) %}.toString(){% endif %})
     {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro -%}
@@ -120,12 +626,12 @@
 {#-
 // Arglist as used in Java declarations of methods, functions and constructors.
 // even if `is_decl` there won't be default values, Java doesn't support them in any reasonable way.
-// Note the var_name and type_name filters.
+// Note the var_name and param_type_name filters.
 -#}
 
 {% macro arg_list(func, is_decl) %}
 {%- for arg in func.arguments() -%}
-        {{ arg|type_name(ci, config) }} {{ arg.name()|var_name }}
+        {{ arg|param_type_name(ci, config) }} {{ arg.name()|var_name(config) }}
 {%-     if !loop.last %}, {% endif -%}
 {%- endfor %}
 {%- endmacro %}
@@ -136,16 +642,16 @@
 -#}
 {%- macro arg_list_ffi_decl(func) %}
     {%- for arg in func.arguments() %}
-        {{- arg.type_().borrow()|ffi_type_name_by_value(config, ci) }} {{arg.name()|var_name -}}{%- if !loop.last %}, {% endif -%}
+        {{- arg.type_().borrow()|ffi_type_name_by_value(config, ci) }} {{arg.name()|var_name(config) -}}{%- if !loop.last %}, {% endif -%}
     {%- endfor %}
-    {%- if func.has_rust_call_status_arg() %}{% if func.arguments().len() != 0 %}, {% endif %}UniffiRustCallStatus uniffi_out_errmk{% endif %}
+    {%- if func.has_rust_call_status_arg() %}{% if func.arguments().len() != 0 %}, {% endif %}{{ "UniffiRustCallStatus"|runtime_type(config) }} uniffi_out_errmk{% endif %}
 {%- endmacro -%}
 
 {% macro field_name(field, field_num) %}
 {%- if field.name().is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name }}
+{{ field.name()|var_name(config) }}
 {%- endif -%}
 {%- endmacro %}
 
@@ -153,7 +659,19 @@ v{{- field_num -}}
 {%- if field.name().is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name|unquote }}
+{{ field.name()|var_name(config)|unquote }}
+{%- endif -%}
+{%- endmacro %}
+
+{#-
+// Read the length prefix of a string, bytes, sequence or map. `min_item_size` is the smallest
+// number of bytes an item can take, 0 when items can be empty.
+#}
+{%- macro read_length(min_item_size) -%}
+{%- if config.safe_buffer_reads -%}
+RustBufferChecks.readLength(buf, {{ min_item_size }})
+{%- else -%}
+buf.getInt()
 {%- endif -%}
 {%- endmacro %}
 
@@ -161,14 +679,14 @@ v{{- field_num -}}
 {%- macro destroy_fields(member) %}
     AutoCloseableHelper.close(
     {%- for field in member.fields() %}
-        this.{{ field.name()|var_name }}{%- if !loop.last %}, {% endif -%}
+        this.{{ field.name()|var_name(config) }}{%- if !loop.last %}, {% endif -%}
     {% endfor -%});
 {%- endmacro -%}
 
 {%- macro docstring_value(maybe_docstring, indent_spaces) %}
 {%- match maybe_docstring %}
 {%- when Some(docstring) %}
-{{ docstring|docstring(indent_spaces) }}
+{{ docstring|docstring(indent_spaces, ci, config) }}
 {%- else %}
 {%- endmatch %}
 {%- endmacro %}
@@ -176,3 +694,24 @@ v{{- field_num -}}
 {%- macro docstring(defn, indent_spaces) %}
 {%- call docstring_value(defn.docstring(), indent_spaces) %}
 {%- endmacro %}
+
+{#-
+// A docstring with the javadoc `@since` tag of items in `api_since`, and for items listed in
+// `deprecated` the `@deprecated` tag, followed by the `@Deprecated` annotation. `owner` is the type of
+// methods and constructors, and empty for everything else.
+#}
+{%- macro deprecated_docstring_value(maybe_docstring, indent_spaces, owner, name) %}
+{%- call docstring_value(self::tagged_docstring(maybe_docstring.as_deref(), config, owner, name), indent_spaces) %}
+{%- match self::deprecation(config, owner, name) %}
+{%- when Some(deprecation) %}
+{{ deprecation|deprecated_annotation(indent_spaces) }}
+{%- else %}
+{%- endmatch %}
+{%- endmacro %}
+
+{#-
+// `docstring` for items that can be listed in `api_since` and `deprecated`.
+#}
+{%- macro deprecated_docstring(defn, indent_spaces, owner) %}
+{%- call deprecated_docstring_value(defn.docstring(), indent_spaces, owner, defn.name()) %}
+{%- endmacro %}
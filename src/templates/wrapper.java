@@ -39,10 +39,164 @@ import io.quarkus.runtime.annotations.RegisterForReflection;{%- endif %}
 
 {%- call java::docstring_value(ci.namespace_docstring(), 0) %}{% if config.quarkus %}
 @RegisterForReflection{%- endif %}
-public class {{ ci.namespace()|class_name(ci) }} {
+{%- call java::suppress_warnings() %}
+public class {{ config.namespace_class_name(ci) }} {
   {%- for func in ci.function_definitions() %}
-  {% call java::func_decl("public static", "", func, 4) %}
+  {%- if !config.excludes_item(func.name()) %}
+  {% call java::func_decl("public static", "", func, 4, config.results_as_values, "") %}
+  {%- endif %}
   {%- endfor %}
+
+  /**
+   * Load the native library and call into it, so deployment problems like a missing library or
+   * one built for another platform show up here, e.g. in a health check, rather than at the first
+   * real call.
+   *
+   * @throws UnsatisfiedLinkError naming the library and platform, if the library can't be loaded
+   * @throws RuntimeException if the library doesn't match these bindings
+   */
+  public static void {{ "SelfTest"|internal(config) }}() {
+    UniffiLib.getInstance().{{ ci.ffi_uniffi_contract_version().name() }}();
+  }
+  {%- match config.initialization_entry_point() %}
+  {%- when Some with (entry_point) %}
+
+  /**
+   * Load the native library and run the initialization of these bindings, like registering the
+   * callback interfaces with Rust, if it hasn't run yet. Calling it again does nothing.
+   *
+   * <p>Otherwise this happens on the first call into the library. Call it from the application
+   * startup to initialize at a known point instead, e.g. at run time of a GraalVM native image.
+   *
+   * @throws UnsatisfiedLinkError naming the library and platform, if the library can't be loaded
+   * @throws RuntimeException if the library doesn't match these bindings
+   */
+  public static void {{ entry_point }}() {
+    UniffiLib.getInstance();
+  }
+  {%- when None %}
+  {%- endmatch %}
+
+  /**
+   * Load the native library again and re-run the initialization of these bindings, including the
+   * contract version and checksum checks.
+   *
+   * <p>This is meant for test suites that need a fresh library between tests. Don't use it in
+   * production: objects created before reinitializing keep pointing into the old instance, and
+   * calls from other threads while reinitializing can see either instance.
+   *
+   * @throws UnsatisfiedLinkError naming the library and platform, if the library can't be loaded
+   * @throws RuntimeException if the library doesn't match these bindings
+   */
+  public static void {{ "Reinitialize"|internal(config) }}() {
+    UniffiLib.reinitialize();
+  }
+  {%- if ci.contains_object_types() && !config.android_cleaner() %}
+
+  /**
+   * Free the Rust side of garbage collected objects through {@code cleaner}, instead of a cleaner
+   * these bindings create with a daemon thread of their own. Passing the same one to all bindings
+   * keeps them on a single thread, which can be named through {@code Cleaner.create(ThreadFactory)}.
+   *
+   * <p>Call this before creating any object: objects keep the cleaner they were registered with.
+   * It has no effect on JVMs without {@code java.lang.ref.Cleaner}, where JNA's cleaner is used.
+   */
+  public static void {{ "SetCleaner"|internal(config) }}(java.lang.ref.Cleaner cleaner) {
+    JavaLangRefCleaner.setCleaner(cleaner);
+  }
+  {%- endif %}
+  {%- if config.instrumentable_ffi %}
+
+  /**
+   * Have every call into the native library go through {@code instrumentation}, or call it
+   * directly again with {@code null}. Without one there's no overhead.
+   *
+   * <p>It applies to the calls that start after it's installed, from any thread.
+   */
+  public static void {{ "SetFfiInstrumentation"|internal(config) }}(UniffiFfiInstrumentation instrumentation) {
+    UniffiLib.setInstrumentation(instrumentation);
+  }
+  {%- endif %}
+  {%- if config.shutdown_hook %}
+
+  /**
+   * Free the native resources this library still holds: cancel the futures of Rust calls that
+   * haven't completed, dropping the Rust futures, and free the objects that haven't been closed or
+   * garbage collected yet.
+   *
+   * <p>This is meant for environments that reload classes without unloading the native library,
+   * like Quarkus dev mode. Objects can't be used after this, so only call it once nothing is
+   * calling into the library anymore.
+   *
+   * @return the number of futures and objects that were freed
+   */
+  public static int {{ "Shutdown"|internal(config) }}() {
+    int freed = 0;
+    {%- if ci.has_async_fns() %}
+    freed += UniffiAsyncHelpers.uniffiCancelPendingFutures();
+    {%- endif %}
+    {%- if ci.contains_object_types() %}
+    freed += UniffiLib.CLEANER.cleanAll();
+    {%- endif %}
+    return freed;
+  }
+  {%- endif %}
+}
+
+
+package {{ config.package_name() }};
+
+/**
+ * The versions these bindings were generated for, to log or check against the native library when
+ * debugging version skew.
+ */
+{%- call java::suppress_warnings() %}
+public final class {{ ci.namespace()|class_name(ci) }}Lib {
+  /** The UniFFI contract version these bindings were generated for. */
+  public static final int UNIFFI_CONTRACT_VERSION = {{ ci.uniffi_contract_version() }};
+  /** The Rust crate these bindings were generated from. */
+  public static final String CRATE_NAME = {{ self::java_string_literal(ci.crate_name()) }};
+  /** The version of uniffi-bindgen-java that generated these bindings. */
+  public static final String BINDGEN_VERSION = {{ self::java_string_literal(self::bindgen_version()) }};
+
+  private {{ ci.namespace()|class_name(ci) }}Lib() {}
+
+  /**
+   * The UniFFI contract version of the loaded native library, loading it if it isn't yet.
+   * Loading already fails if it doesn't match {@link #UNIFFI_CONTRACT_VERSION}.
+   */
+  public static int scaffoldingContractVersion() {
+    return UniffiLib.getInstance().{{ ci.ffi_uniffi_contract_version().name() }}();
+  }
+}
+{%- if config.emit_constants %}
+
+package {{ config.package_name() }};
+
+/**
+ * The constants of the Rust crate, generated with {@code emit_constants}: the discriminants of the
+ * enums declaring a repr, named after the enum and variant.
+ */
+{%- call java::suppress_warnings() %}
+public final class {{ ci.namespace()|class_name(ci) }}Constants {
+  {%- for (java_type, name, literal, variant) in self::enum_discriminant_constants(config, ci) %}
+  /** The discriminant of {@link {{ variant }}}. */
+  public static final {{ java_type }} {{ name }} = {{ literal }};
+  {%- endfor %}
+
+  private {{ ci.namespace()|class_name(ci) }}Constants() {}
 }
+{%- endif %}
+{%- if config.quarkus && config.quarkus_producers %}
+{%- let producible_objects = self::cdi_producible_objects(config, ci) %}
+{%- if !producible_objects.is_empty() %}
+
+{% include "QuarkusProducersTemplate.java" %}
+{%- endif %}
+{%- endif %}
+{%- for (listener, interfaces) in ci|listeners(config) %}
+
+{% include "ListenerTemplate.java" %}
+{%- endfor %}
 
 {% import "macros.java" as java %}
@@ -0,0 +1,177 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Things the Java bindings handle specially that none of the UniFFI fixtures have.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The sum of `values`, a trailing sequence of a type that isn't generic in Java.
+#[uniffi::export]
+pub fn sum(values: Vec<i32>) -> i32 {
+    values.iter().sum()
+}
+
+/// The length of each of `lists`, a trailing sequence of a type that's generic in Java.
+#[uniffi::export]
+pub fn lengths(lists: Vec<Vec<i32>>) -> Vec<u32> {
+    lists.iter().map(|list| list.len() as u32).collect()
+}
+
+/// The sum of the values of `counts`. Its default is an empty sequence rather than an empty map,
+/// which Java has no literal for.
+#[uniffi::export(default(counts = []))]
+pub fn total(counts: HashMap<String, u32>) -> u32 {
+    counts.values().sum()
+}
+
+#[derive(uniffi::Object)]
+pub struct EntryCounter;
+
+#[uniffi::export]
+impl EntryCounter {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+
+    /// The number of entries in all of `maps`, a trailing sequence of a type that's generic in
+    /// Java.
+    pub fn count(&self, maps: Vec<HashMap<String, i32>>) -> u32 {
+        maps.iter().map(|map| map.len() as u32).sum()
+    }
+
+    /// The largest of the values of `counts`, with the same default as `total`.
+    #[uniffi::method(default(counts = []))]
+    pub fn largest(&self, counts: HashMap<String, u32>) -> u32 {
+        counts.values().copied().max().unwrap_or_default()
+    }
+}
+
+/// A flat enum with many variants, its values are lifted by indexing a cached array of them.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LargeFlatEnum {
+    Variant0,
+    Variant1,
+    Variant2,
+    Variant3,
+    Variant4,
+    Variant5,
+    Variant6,
+    Variant7,
+    Variant8,
+    Variant9,
+    Variant10,
+    Variant11,
+    Variant12,
+    Variant13,
+    Variant14,
+    Variant15,
+    Variant16,
+    Variant17,
+    Variant18,
+    Variant19,
+    Variant20,
+    Variant21,
+    Variant22,
+    Variant23,
+    Variant24,
+    Variant25,
+    Variant26,
+    Variant27,
+    Variant28,
+    Variant29,
+    Variant30,
+    Variant31,
+    Variant32,
+    Variant33,
+    Variant34,
+    Variant35,
+    Variant36,
+    Variant37,
+    Variant38,
+    Variant39,
+    Variant40,
+    Variant41,
+    Variant42,
+    Variant43,
+    Variant44,
+    Variant45,
+    Variant46,
+    Variant47,
+    Variant48,
+    Variant49,
+    Variant50,
+    Variant51,
+    Variant52,
+    Variant53,
+    Variant54,
+    Variant55,
+    Variant56,
+    Variant57,
+    Variant58,
+    Variant59,
+    Variant60,
+    Variant61,
+    Variant62,
+    Variant63,
+}
+
+impl LargeFlatEnum {
+    const ALL: [LargeFlatEnum; 64] = {
+        use LargeFlatEnum::*;
+        [
+            Variant0, Variant1, Variant2, Variant3, Variant4, Variant5, Variant6, Variant7,
+            Variant8, Variant9, Variant10, Variant11, Variant12, Variant13, Variant14, Variant15,
+            Variant16, Variant17, Variant18, Variant19, Variant20, Variant21, Variant22, Variant23,
+            Variant24, Variant25, Variant26, Variant27, Variant28, Variant29, Variant30, Variant31,
+            Variant32, Variant33, Variant34, Variant35, Variant36, Variant37, Variant38, Variant39,
+            Variant40, Variant41, Variant42, Variant43, Variant44, Variant45, Variant46, Variant47,
+            Variant48, Variant49, Variant50, Variant51, Variant52, Variant53, Variant54, Variant55,
+            Variant56, Variant57, Variant58, Variant59, Variant60, Variant61, Variant62, Variant63,
+        ]
+    };
+}
+
+/// Every variant of `LargeFlatEnum`, in declaration order.
+#[uniffi::export]
+pub fn large_flat_enum_values() -> Vec<LargeFlatEnum> {
+    LargeFlatEnum::ALL.to_vec()
+}
+
+/// The variant declared after `value`, the first one after the last.
+#[uniffi::export]
+pub fn next_large_flat_enum(value: LargeFlatEnum) -> LargeFlatEnum {
+    LargeFlatEnum::ALL[(value as usize + 1) % LargeFlatEnum::ALL.len()]
+}
+
+/// A flat enum with a repr narrower than Java's `int`, with a discriminant beyond its signed range.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Priority {
+    Low = 1,
+    High = 200,
+}
+
+/// A flat enum with a repr wider than Java's `int`.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i64)]
+pub enum Offset {
+    Before = -1,
+    After = 4_000_000_000,
+}
+
+/// The discriminant of `value`.
+#[uniffi::export]
+pub fn priority_discriminant(value: Priority) -> u8 {
+    value as u8
+}
+
+/// The discriminant of `value`.
+#[uniffi::export]
+pub fn offset_discriminant(value: Offset) -> i64 {
+    value as i64
+}
+
+uniffi::setup_scaffolding!("java_fixture");
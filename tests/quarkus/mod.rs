@@ -85,21 +85,23 @@ fn wait_until_endpoint_responds(port: u16) {
 }
 
 ///
-/// Tests the output on the Quarkus endpoint
+/// Tests the output on the Quarkus endpoints, the one calling the function and the one calling the
+/// object injected through the generated CDI producer
 fn get_true_or_false_value_from_http_endpoint(port: u16) {
-    let url = format!("http://127.0.0.1:{}/?trueOrNot=true", port);
-
     let client = reqwest::blocking::Client::new();
-    let response = client.get(&url).send().expect("Failed to send GET request");
-    assert!(response.status().is_success());
-    let body = response.text().expect("Failed to get response body");
-    assert_eq!(body, "true");
-
-    let url = format!("http://127.0.0.1:{}/?trueOrNot=false", port);
-    let response = client.get(&url).send().expect("Failed to send GET request");
-    assert!(response.status().is_success());
-    let body = response.text().expect("Failed to get response body");
-    assert_eq!(body, "false");
+    for path in ["", "truth-teller"] {
+        let url = format!("http://127.0.0.1:{}/{}?trueOrNot=true", port, path);
+        let response = client.get(&url).send().expect("Failed to send GET request");
+        assert!(response.status().is_success());
+        let body = response.text().expect("Failed to get response body");
+        assert_eq!(body, "true");
+
+        let url = format!("http://127.0.0.1:{}/{}?trueOrNot=false", port, path);
+        let response = client.get(&url).send().expect("Failed to send GET request");
+        assert!(response.status().is_success());
+        let body = response.text().expect("Failed to get response body");
+        assert_eq!(body, "false");
+    }
 }
 
 ///
@@ -4,4 +4,20 @@ uniffi::setup_scaffolding!();
 pub fn say_true_or_not(true_or_not: bool) -> bool {
     println!("say_true_or_not: {}", true_or_not);
     true_or_not
+}
+
+/// Says what it's told, injected into the service through the generated CDI producer.
+#[derive(uniffi::Object)]
+pub struct TruthTeller;
+
+#[uniffi::export]
+impl TruthTeller {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        TruthTeller
+    }
+
+    pub fn say_true_or_not(&self, true_or_not: bool) -> bool {
+        say_true_or_not(true_or_not)
+    }
 }
\ No newline at end of file
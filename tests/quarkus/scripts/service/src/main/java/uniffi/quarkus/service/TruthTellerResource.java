@@ -0,0 +1,24 @@
+package uniffi.quarkus.service;
+
+import io.smallrye.mutiny.Uni;
+import jakarta.inject.Inject;
+import jakarta.ws.rs.GET;
+import jakarta.ws.rs.Path;
+import jakarta.ws.rs.Produces;
+import jakarta.ws.rs.QueryParam;
+import jakarta.ws.rs.core.MediaType;
+import uniffi.quarkus.TruthTellerInterface;
+
+@Path("/truth-teller")
+public class TruthTellerResource {
+
+    // Produced by the generated `SayTrueProducers`
+    @Inject
+    TruthTellerInterface truthTeller;
+
+    @GET
+    @Produces(MediaType.APPLICATION_JSON)
+    public Uni<Boolean> sayYes(@QueryParam("trueOrNot") Boolean trueOrNot) {
+        return Uni.createFrom().item(truthTeller.sayTrueOrNot(Boolean.TRUE.equals(trueOrNot)));
+    }
+}
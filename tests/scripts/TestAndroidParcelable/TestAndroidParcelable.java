@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import android.os.Parcel;
+import android.os.Parcelable;
+import uniffi.rondpoint.*;
+
+public class TestAndroidParcelable {
+  public static void main(String[] args) throws Exception {
+    // Records, flat enums and enums with fields come back equal from a parcel
+    var dico = new Dictionnaire(Enumeration.DEUX, true, (byte) 0, 123456789L);
+    assert fromParcel(dico, Dictionnaire.CREATOR).equals(dico);
+    assert fromParcel(Enumeration.TROIS, Enumeration.CREATOR) == Enumeration.TROIS;
+    for (EnumerationAvecDonnees value : new EnumerationAvecDonnees[] {
+        new EnumerationAvecDonnees.Zero(),
+        new EnumerationAvecDonnees.Un(1),
+        new EnumerationAvecDonnees.Deux(2, "deux")}) {
+      assert fromParcel(value, EnumerationAvecDonnees.CREATOR).equals(value) : value;
+    }
+
+    assert Dictionnaire.CREATOR.newArray(3).length == 3;
+    assert dico.describeContents() == 0;
+  }
+
+  static <T extends Parcelable> T fromParcel(T value, Parcelable.Creator<? extends T> creator) {
+    var parcel = new Parcel();
+    value.writeToParcel(parcel, 0);
+    return creator.createFromParcel(parcel);
+  }
+}
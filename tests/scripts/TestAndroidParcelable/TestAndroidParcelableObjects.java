@@ -0,0 +1,22 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import android.os.Parcel;
+import android.os.Parcelable;
+import uniffi.coverall.*;
+
+public class TestAndroidParcelableObjects {
+  public static void main(String[] args) throws Exception {
+    // A native handle can't be parceled, so records holding objects aren't Parcelable
+    assert !Parcelable.class.isAssignableFrom(SimpleDict.class);
+    assert !Parcelable.class.isAssignableFrom(Repair.class);
+
+    // while the ones without are
+    var dict = new DictWithDefaults("this", "that", 42L);
+    var parcel = new Parcel();
+    dict.writeToParcel(parcel, 0);
+    assert DictWithDefaults.CREATOR.createFromParcel(parcel).equals(dict);
+    assert Parcelable.class.isAssignableFrom(Color.class);
+  }
+}
@@ -0,0 +1,24 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package android.os;
+
+import java.util.ArrayDeque;
+import java.util.Queue;
+
+/**
+ * A stand-in for Android's class, which isn't on the test classpath. It only keeps the byte arrays
+ * written to it, in memory, and reads them back in the same order.
+ */
+public final class Parcel {
+  private final Queue<byte[]> byteArrays = new ArrayDeque<>();
+
+  public void writeByteArray(byte[] value) {
+    byteArrays.add(value.clone());
+  }
+
+  public byte[] createByteArray() {
+    return byteArrays.remove();
+  }
+}
@@ -0,0 +1,18 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package android.os;
+
+/** A stand-in for Android's interface, which isn't on the test classpath. */
+public interface Parcelable {
+  int describeContents();
+
+  void writeToParcel(Parcel dest, int flags);
+
+  interface Creator<T> {
+    T createFromParcel(Parcel source);
+
+    T[] newArray(int size);
+  }
+}
@@ -0,0 +1,69 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import com.sun.source.doctree.DocCommentTree;
+import com.sun.source.doctree.DocTree;
+import com.sun.source.doctree.SinceTree;
+import com.sun.source.tree.ClassTree;
+import com.sun.source.tree.CompilationUnitTree;
+import com.sun.source.tree.MethodTree;
+import com.sun.source.tree.Tree;
+import com.sun.source.util.DocTrees;
+import com.sun.source.util.JavacTask;
+import com.sun.source.util.TreePath;
+import java.nio.file.Path;
+import java.util.List;
+import java.util.stream.Collectors;
+import javax.tools.ToolProvider;
+
+public class TestApiSince {
+  public static void main(String[] args) throws Exception {
+    // Functions get the `"*"` version unless they have their own
+    assert since(docComment("uniffi/arithmetic/Arithmetic.java", "add")).equals("1.0");
+    assert since(docComment("uniffi/arithmetic/Arithmetic.java", "sub")).equals("1.2");
+    assert since(docComment("uniffi/arithmetic/ArithmeticException.java", "ArithmeticException")).equals("1.1");
+
+    // `@since` comes before the other block tags
+    List<DocTree.Kind> tags = docComment("uniffi/arithmetic/Arithmetic.java", "div").getBlockTags().stream()
+        .map(DocTree::getKind)
+        .toList();
+    assert tags.get(0) == DocTree.Kind.SINCE : tags;
+    assert tags.contains(DocTree.Kind.DEPRECATED) : tags;
+  }
+
+  // The version of the only `@since` tag of `doc`
+  static String since(DocCommentTree doc) {
+    List<SinceTree> tags = doc.getBlockTags().stream()
+        .filter(tag -> tag.getKind() == DocTree.Kind.SINCE)
+        .map(SinceTree.class::cast)
+        .toList();
+    assert tags.size() == 1 : tags;
+    return tags.get(0).getBody().stream().map(Object::toString).collect(Collectors.joining()).strip();
+  }
+
+  // The javadoc of the class or method `name` in the generated source `resource`, which is on the
+  // classpath next to the package directory
+  static DocCommentTree docComment(String resource, String name) throws Exception {
+    var compiler = ToolProvider.getSystemJavaCompiler();
+    var fileManager = compiler.getStandardFileManager(null, null, null);
+    var file = fileManager.getJavaFileObjects(Path.of(ClassLoader.getSystemResource(resource).toURI()));
+    var task = (JavacTask) compiler.getTask(null, fileManager, null, List.of("-proc:none"), null, file);
+    var docTrees = DocTrees.instance(task);
+    for (CompilationUnitTree unit : task.parse()) {
+      for (Tree type : unit.getTypeDecls()) {
+        var classTree = (ClassTree) type;
+        var classPath = new TreePath(new TreePath(unit), classTree);
+        if (classTree.getSimpleName().contentEquals(name)) {
+          return docTrees.getDocCommentTree(classPath);
+        }
+        for (Tree member : classTree.getMembers()) {
+          if (member instanceof MethodTree method && method.getName().contentEquals(name)) {
+            return docTrees.getDocCommentTree(new TreePath(classPath, method));
+          }
+        }
+      }
+    }
+    throw new AssertionError(name + " isn't in " + resource);
+  }
+}
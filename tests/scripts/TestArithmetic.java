@@ -2,6 +2,25 @@ import uniffi.arithmetic.*;
 
 public class TestArithmetic {
   public static void main(String[] args) throws Exception {
+    // The self test names the library and platform when the library can't be loaded. This has to
+    // come first, the library is only loaded once it loaded successfully.
+    System.setProperty("uniffi.component.arithmetic.libraryOverride", "missing_arithmetic");
+    try {
+      Arithmetic.uniffiSelfTest();
+      throw new RuntimeException("Should have failed to load the library");
+    } catch (UnsatisfiedLinkError e) {
+      assert e.getMessage().contains("'missing_arithmetic'") : e.getMessage();
+      assert e.getMessage().contains(com.sun.jna.Platform.RESOURCE_PREFIX) : e.getMessage();
+    }
+    System.clearProperty("uniffi.component.arithmetic.libraryOverride");
+    Arithmetic.uniffiSelfTest();
+
+    // The versions the bindings were generated for
+    assert ArithmeticLib.UNIFFI_CONTRACT_VERSION == 29;
+    assert ArithmeticLib.scaffoldingContractVersion() == ArithmeticLib.UNIFFI_CONTRACT_VERSION;
+    assert !ArithmeticLib.CRATE_NAME.isEmpty();
+    assert !ArithmeticLib.BINDGEN_VERSION.isEmpty();
+
     assert Arithmetic.add(2L, 4L) == 6L;   
     assert Arithmetic.add(4L, 8L) == 12L;
 
@@ -32,5 +51,32 @@ public class TestArithmetic {
     assert Arithmetic.equal(4L, 4L);
     assert !Arithmetic.equal(2L, 4L);
     assert !Arithmetic.equal(4L, 8L);
+
+    // `ensureInitialized()` loads and initializes the library only once
+    var getInstance = Class.forName("uniffi.arithmetic.UniffiLib").getMethod("getInstance");
+    getInstance.setAccessible(true);
+    Arithmetic.ensureInitialized();
+    var initialized = getInstance.invoke(null);
+    Arithmetic.ensureInitialized();
+    Arithmetic.ensureInitialized();
+    assert getInstance.invoke(null) == initialized;
+
+    // The library is loaded with the default `jna_options`
+    var library = ((com.sun.jna.Library.Handler) java.lang.reflect.Proxy.getInvocationHandler(initialized)).getNativeLibrary();
+    assert "UTF-8".equals(library.getOptions().get(com.sun.jna.Library.OPTION_STRING_ENCODING));
+    assert Integer.valueOf(com.sun.jna.Structure.ALIGN_DEFAULT).equals(library.getOptions().get(com.sun.jna.Library.OPTION_STRUCTURE_ALIGNMENT));
+
+    // `uniffiReinitialize()` loads the library again, so a bad override now fails to load
+    System.setProperty("uniffi.component.arithmetic.libraryOverride", "uniffi_does_not_exist");
+    try {
+      Arithmetic.uniffiReinitialize();
+      throw new RuntimeException("Should have failed to load the overridden library");
+    } catch (UnsatisfiedLinkError e) {
+      // It's okay!
+    }
+    System.clearProperty("uniffi.component.arithmetic.libraryOverride");
+    // and the real library passes the contract and checksum checks again
+    Arithmetic.uniffiReinitialize();
+    assert Arithmetic.add(2L, 4L) == 6L;
   }
 }
@@ -0,0 +1,56 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.arithmetic.*;
+
+import java.lang.reflect.Method;
+import java.util.Arrays;
+import org.jetbrains.annotations.Contract;
+
+public class TestArithmeticOptions {
+  public static void main(String[] args) throws Exception {
+    // `namespace_class` is set in uniffi-extras.toml, there's no `Arithmetic` class
+    try {
+      Class.forName("uniffi.arithmetic.Arithmetic");
+      throw new RuntimeException("Should have been generated as ArithmeticFunctions");
+    } catch (ClassNotFoundException e) {
+      // It's okay!
+    }
+
+    // `init()` replaces `ensureInitialized()`
+    ArithmeticFunctions.init();
+    var methods = Arrays.stream(ArithmeticFunctions.class.getMethods()).map(Method::getName).toList();
+    assert !methods.contains("ensureInitialized");
+
+    // `div` is excluded, and `equal` isn't included
+    assert !methods.contains("div");
+    assert !methods.contains("equal");
+
+    // `add` is renamed to `plus`, `sub` to `default`, which is escaped
+    assert !methods.contains("add");
+    assert !methods.contains("sub");
+
+    // Results are returned rather than thrown, and the error is renamed to `MathException`
+    UniffiResult<Long, MathException> sum = ArithmeticFunctions.plus(2L, 4L);
+    assert sum.isOk();
+    assert sum.getOrThrow() == 6L;
+    UniffiResult<Long, MathException> difference = ArithmeticFunctions._default(0L, 2L);
+    assert !difference.isOk();
+    assert difference instanceof UniffiResult.Err<Long, MathException> err
+        && err.error() instanceof MathException.IntegerOverflow;
+    try {
+      difference.getOrThrow();
+      throw new RuntimeException("Should have thrown an IntegerOverflow exception!");
+    } catch (MathException.IntegerOverflow e) {
+      // It's okay!
+    }
+
+    // `@Contract` has one `_` per argument
+    var plus = Arrays.stream(ArithmeticFunctions.class.getMethods())
+        .filter(method -> method.getName().equals("plus"))
+        .findFirst()
+        .orElseThrow();
+    assert plus.getAnnotation(Contract.class).value().equals("_, _ -> !null");
+  }
+}
@@ -0,0 +1,34 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.coverall.*;
+
+import java.nio.charset.StandardCharsets;
+import java.util.HexFormat;
+
+// Coverall with `bytes_type` in uniffi-extras.toml, which maps `bytes` to hex strings.
+public class TestBytesType {
+  public static void main(String[] args) throws Exception {
+    try (var d = Coverall.createSomeDict()) {
+      assert d.someBytes().equals(HexFormat.of().formatHex("some_bytes".getBytes(StandardCharsets.UTF_8))) : d.someBytes();
+      assert d.maybeSomeBytes().equals(HexFormat.of().formatHex("maybe_some_bytes".getBytes(StandardCharsets.UTF_8)));
+    }
+
+    try (var d = Coverall.createNoneDict()) {
+      assert d.maybeSomeBytes() == null;
+    }
+
+    try (Coveralls coveralls = new Coveralls("test_bytes_type")) {
+      assert coveralls.reverse("010203").equals("030201");
+      assert coveralls.reverse("").isEmpty();
+      // A value the lower expression can't convert fails before reaching Rust
+      try {
+        coveralls.reverse("not hex");
+        throw new RuntimeException("Should have failed to lower");
+      } catch (RuntimeException e) {
+        assert e.getCause() instanceof IllegalArgumentException : e;
+      }
+    }
+  }
+}
@@ -0,0 +1,74 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.coverall.*;
+
+import java.lang.reflect.Method;
+import java.lang.reflect.Modifier;
+import java.util.HashMap;
+import org.junit.jupiter.api.Test;
+
+public class TestCoverallOptions {
+  public static void main(String[] args) throws Exception {
+    try (var coveralls = new Coveralls("test_coverall_options")) {
+      // `singleton_empty_records` is set in uniffi-extras.toml, lifted values are the `INSTANCE`
+      assert coveralls.setAndGetEmptyStruct(EmptyStruct.INSTANCE) == EmptyStruct.INSTANCE;
+      assert Modifier.isPrivate(EmptyStruct.class.getDeclaredConstructor().getModifiers());
+
+      // `get_name` is renamed to `label`
+      assert coveralls.label().equals("test_coverall_options");
+      try {
+        Coveralls.class.getMethod("getName");
+        throw new RuntimeException("Should have been renamed");
+      } catch (NoSuchMethodException e) {
+        // It's okay!
+      }
+
+      // Internal identifiers use the `internal_prefix`
+      Coveralls.class.getDeclaredMethod("generatedClonePointer");
+      try {
+        Coveralls.class.getDeclaredMethod("uniffiClonePointer");
+        throw new RuntimeException("Should have been prefixed with `generated`");
+      } catch (NoSuchMethodException e) {
+        // It's okay!
+      }
+    }
+
+    try (var d = Coverall.createNoneDict(); var same = Coverall.createNoneDict()) {
+      // `deep_equals` compares `someBytes` by content
+      assert d.someBytes() != same.someBytes();
+      assert d.equals(same);
+      assert d.hashCode() == same.hashCode();
+
+      // Missing optional fields are `null` in `fromMap`, other missing fields throw
+      var map = new HashMap<>(d.toMap());
+      map.remove("maybeText");
+      try (var fromMap = SimpleDict.fromMap(map)) {
+        assert fromMap.maybeText() == null;
+        assert fromMap.equals(d);
+      }
+      map.remove("text");
+      try {
+        SimpleDict.fromMap(map);
+        throw new RuntimeException("Should have thrown for the missing `text`");
+      } catch (IllegalArgumentException e) {
+        assert e.getMessage().contains("text") : e.getMessage();
+      }
+    }
+
+    // With `deep_equals` the value tests cover records holding byte arrays too, and pass
+    Class<?> valueTests = Class.forName("uniffi.coverall.CoverallValueTest");
+    var constructor = valueTests.getDeclaredConstructor();
+    constructor.setAccessible(true);
+    Object instance = constructor.newInstance();
+    Method simpleDict = valueTests.getDeclaredMethod("simpleDictHasValueSemantics");
+    assert simpleDict.isAnnotationPresent(Test.class);
+    for (Method method : valueTests.getDeclaredMethods()) {
+      if (method.isAnnotationPresent(Test.class)) {
+        method.setAccessible(true);
+        method.invoke(instance);
+      }
+    }
+  }
+}
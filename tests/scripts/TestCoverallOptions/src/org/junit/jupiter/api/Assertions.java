@@ -0,0 +1,32 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package org.junit.jupiter.api;
+
+import java.util.Objects;
+
+/** A stand-in for the JUnit assertions the value tests use, which aren't on the test classpath. */
+public final class Assertions {
+  private Assertions() {}
+
+  public static void assertEquals(Object expected, Object actual) {
+    assertEquals(expected, actual, "values differ");
+  }
+
+  public static void assertEquals(Object expected, Object actual, String message) {
+    if (!Objects.equals(expected, actual)) {
+      throw new AssertionError(message + ": expected " + expected + " but was " + actual);
+    }
+  }
+
+  public static void assertEquals(int expected, int actual, String message) {
+    assertEquals((Object) expected, (Object) actual, message);
+  }
+
+  public static void assertNotEquals(Object unexpected, Object actual) {
+    if (Objects.equals(unexpected, actual)) {
+      throw new AssertionError("expected a value other than " + unexpected);
+    }
+  }
+}
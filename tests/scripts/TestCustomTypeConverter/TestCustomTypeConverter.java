@@ -0,0 +1,30 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import com.example.HandleConverter;
+import customtypes.*;
+
+public class TestCustomTypeConverter {
+  public static void main(String[] args) throws Exception {
+    // No converter is generated for `Handle`, the configured one is used instead
+    try {
+      Class.forName("customtypes.FfiConverterTypeHandle");
+      throw new RuntimeException("Should have no generated converter for Handle");
+    } catch (ClassNotFoundException e) {
+      // It's okay!
+    }
+
+    CustomTypesDemo demo = CustomTypes.getCustomTypesDemo(null);
+    assert demo.handle().equals(new Handle(123L));
+    int lifted = HandleConverter.LIFTED.get();
+    assert lifted > 0;
+
+    // and round-trips handles through Rust
+    demo.setHandle(new Handle(456L));
+    CustomTypesDemo roundTripped = CustomTypes.getCustomTypesDemo(demo);
+    assert roundTripped.handle().equals(new Handle(456L));
+    assert HandleConverter.LOWERED.get() > 0;
+    assert HandleConverter.LIFTED.get() > lifted;
+  }
+}
@@ -0,0 +1,45 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package com.example;
+
+import customtypes.FfiConverter;
+import customtypes.Handle;
+import java.nio.ByteBuffer;
+import java.util.concurrent.atomic.AtomicInteger;
+
+/** A hand-written converter for `Handle`, counting its calls so the test can tell it's used. */
+public enum HandleConverter implements FfiConverter<Handle, Long> {
+  INSTANCE;
+
+  public static final AtomicInteger LIFTED = new AtomicInteger();
+  public static final AtomicInteger LOWERED = new AtomicInteger();
+
+  @Override
+  public Handle lift(Long value) {
+    LIFTED.incrementAndGet();
+    return new Handle(value);
+  }
+
+  @Override
+  public Long lower(Handle value) {
+    LOWERED.incrementAndGet();
+    return value.value();
+  }
+
+  @Override
+  public Handle read(ByteBuffer buf) {
+    return lift(buf.getLong());
+  }
+
+  @Override
+  public long allocationSize(Handle value) {
+    return 8L;
+  }
+
+  @Override
+  public void write(Handle value, ByteBuffer buf) {
+    buf.putLong(lower(value));
+  }
+}
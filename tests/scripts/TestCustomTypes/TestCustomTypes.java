@@ -7,7 +7,7 @@ import customtypes.*;
 
 public class TestCustomTypes {
 
-  public static void main(String[] args) throws MalformedURLException, URISyntaxException {
+  public static void main(String[] args) throws MalformedURLException, URISyntaxException, ClassNotFoundException {
     // Get the custom types and check their data
     CustomTypesDemo demo = CustomTypes.getCustomTypesDemo(null);
     // URL is customized on the bindings side
@@ -19,5 +19,59 @@ public class TestCustomTypes {
     demo.setUrl(new Url(new URI("http://new.example.com/").toURL()));
     demo.setHandle(new Handle(456L));
     assert demo.equals(CustomTypes.getCustomTypesDemo(demo));
+
+    // `handle` is ignored by equals/hashCode through `equals_ignore_fields` in uniffi-extras.toml
+    CustomTypesDemo other = CustomTypes.getCustomTypesDemo(demo);
+    other.setHandle(new Handle(789L));
+    assert demo.equals(other);
+    assert demo.hashCode() == other.hashCode();
+
+    // CustomTypesDemo has `java.io.Serializable` added through `implements` in uniffi-extras.toml
+    assert demo instanceof java.io.Serializable;
+
+    // `toString()` follows the `tostring_template` in uniffi-extras.toml
+    assert demo.toString().startsWith("CustomTypesDemo(url=" + demo.url() + ", handle=") : demo.toString();
+    assert demo.toString().endsWith(")") : demo.toString();
+
+    // Rust's `url` rejects the port, so lifting the argument panics, which `panic_exception` turns
+    // into a RustPanicException
+    CustomTypesDemo badUrl = CustomTypes.getCustomTypesDemo(null);
+    badUrl.setUrl(new Url(URI.create("http://example.com:99999/").toURL()));
+    try {
+      CustomTypes.getCustomTypesDemo(badUrl);
+      throw new RuntimeException("Should have thrown");
+    } catch (RustPanicException e) {
+      assert e instanceof InternalException;
+    }
+
+    // `deep_equals` compares byte arrays by content, also as map values
+    var bytesByName = java.util.Map.of("a", new byte[] { 1, 2 }, "b", new byte[0]);
+    var sameBytesByName = java.util.Map.of("a", new byte[] { 1, 2 }, "b", new byte[0]);
+    assert !bytesByName.equals(sameBytesByName);
+    assert UniffiDeepEquality.equals(bytesByName, sameBytesByName);
+    assert UniffiDeepEquality.hashCode(bytesByName) == UniffiDeepEquality.hashCode(sameBytesByName);
+    assert !UniffiDeepEquality.equals(bytesByName, java.util.Map.of("a", new byte[] { 1, 3 }, "b", new byte[0]));
+    assert !UniffiDeepEquality.equals(bytesByName, java.util.Map.of("a", new byte[] { 1, 2 }, "c", new byte[0]));
+
+    // `safe_buffer_reads` turns malformed buffers into exceptions
+    try {
+      FfiConverterString.INSTANCE.read(java.nio.ByteBuffer.wrap(new byte[] { 0, 0, 0, 10, 'a' }));
+      throw new RuntimeException("Should have thrown");
+    } catch (IndexOutOfBoundsException e) {
+      // Expected
+    }
+    var buffer = new customtypes.runtime.RustBuffer.ByValue();
+    buffer.capacity = 1;
+    buffer.len = 10;
+    try {
+      buffer.asByteBuffer();
+      throw new RuntimeException("Should have thrown");
+    } catch (IndexOutOfBoundsException e) {
+      // Expected
+    }
+
+    // The JNA runtime types are generated in the configured `runtime_package`
+    Class.forName("customtypes.runtime.RustBuffer");
+    Class.forName("customtypes.runtime.UniffiRustCallStatus");
   }
 }
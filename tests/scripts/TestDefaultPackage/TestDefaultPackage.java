@@ -0,0 +1,23 @@
+public class TestDefaultPackage {
+  public static void main(String[] args) throws Exception {
+    // The bindings live in the default package, so they're used without an import
+    assert Arithmetic.class.getPackageName().isEmpty();
+    assert Arithmetic.add(2L, 4L) == 6L;
+    assert Arithmetic.sub(4L, 2L) == 2L;
+
+    // `initialization_entry_point` is empty, so there's no `ensureInitialized()`
+    try {
+      Arithmetic.class.getMethod("ensureInitialized");
+      throw new RuntimeException("Should have been left out");
+    } catch (NoSuchMethodException e) {
+      // It's okay!
+    }
+
+    try {
+      Arithmetic.sub(0L, 2L);
+      throw new RuntimeException("Should have thrown an IntegerOverflow exception!");
+    } catch (ArithmeticException.IntegerOverflow e) {
+      // It's okay!
+    }
+  }
+}
@@ -0,0 +1,109 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import com.sun.source.doctree.DeprecatedTree;
+import com.sun.source.doctree.DocCommentTree;
+import com.sun.source.doctree.DocTree;
+import com.sun.source.doctree.LinkTree;
+import com.sun.source.tree.ClassTree;
+import com.sun.source.tree.CompilationUnitTree;
+import com.sun.source.tree.MethodTree;
+import com.sun.source.tree.Tree;
+import com.sun.source.util.DocTrees;
+import com.sun.source.util.JavacTask;
+import com.sun.source.util.TreePath;
+import java.net.URI;
+import java.nio.file.Path;
+import java.util.List;
+import java.util.Optional;
+import javax.tools.Diagnostic;
+import javax.tools.DiagnosticCollector;
+import javax.tools.JavaFileObject;
+import javax.tools.SimpleJavaFileObject;
+import javax.tools.ToolProvider;
+import uniffi.arithmetic.*;
+
+public class TestDeprecated {
+  public static void main(String[] args) throws Exception {
+    // `sub` is deprecated for removal since 1.2, `add` isn't deprecated
+    Deprecated sub = Arithmetic.class.getMethod("sub", Long.class, Long.class).getAnnotation(Deprecated.class);
+    assert sub != null;
+    assert sub.since().equals("1.2") : sub.since();
+    assert sub.forRemoval();
+    assert !Arithmetic.class.getMethod("add", Long.class, Long.class).isAnnotationPresent(Deprecated.class);
+
+    // `ArithmeticError` is deprecated without a version. It's only looked up by name, referring to
+    // it would be a deprecation warning.
+    Deprecated error = Class.forName("uniffi.arithmetic.ArithmeticException").getAnnotation(Deprecated.class);
+    assert error != null;
+    assert error.since().isEmpty();
+    assert !error.forRemoval();
+
+    // Callers of `sub` get a removal warning, callers of `add` don't
+    assert compileWarnings("Arithmetic.sub(4L, 2L);").contains("compiler.warn.has.been.deprecated.for.removal");
+    assert compileWarnings("Arithmetic.add(4L, 2L);").isEmpty();
+
+    // and its javadoc points them to the replacement
+    DocCommentTree subDoc = docComment("uniffi/arithmetic/Arithmetic.java", "sub");
+    DeprecatedTree subTag = deprecatedTag(subDoc).orElseThrow();
+    assert subTag.getBody().stream()
+        .anyMatch(tree -> tree instanceof LinkTree link && link.getReference().getSignature().equals("#add"))
+        : subTag;
+    assert deprecatedTag(docComment("uniffi/arithmetic/Arithmetic.java", "add")).isEmpty();
+    assert deprecatedTag(docComment("uniffi/arithmetic/ArithmeticException.java", "ArithmeticException")).isPresent();
+  }
+
+  // The codes of the warnings compiling `statement` against the bindings
+  static List<String> compileWarnings(String statement) {
+    var source = new SimpleJavaFileObject(URI.create("string:///Caller.java"), JavaFileObject.Kind.SOURCE) {
+      @Override
+      public CharSequence getCharContent(boolean ignoreEncodingErrors) {
+        return "import uniffi.arithmetic.*;\nclass Caller {\n  void call() throws Exception {\n    " + statement + "\n  }\n}\n";
+      }
+    };
+    var diagnostics = new DiagnosticCollector<JavaFileObject>();
+    var compiler = ToolProvider.getSystemJavaCompiler();
+    var task = compiler.getTask(null, null, diagnostics,
+        List.of("-classpath", System.getProperty("java.class.path"), "-proc:none"), null, List.of(source));
+    boolean compiled = task.call();
+    assert compiled : diagnostics.getDiagnostics();
+    return diagnostics.getDiagnostics().stream()
+        .filter(diagnostic -> diagnostic.getKind() != Diagnostic.Kind.NOTE)
+        .map(diagnostic -> diagnostic.getCode())
+        .toList();
+  }
+
+  // The javadoc of the class or method `name` in the generated source `resource`, which is on the
+  // classpath next to the package directory
+  static DocCommentTree docComment(String resource, String name) throws Exception {
+    var compiler = ToolProvider.getSystemJavaCompiler();
+    var fileManager = compiler.getStandardFileManager(null, null, null);
+    var file = fileManager.getJavaFileObjects(Path.of(ClassLoader.getSystemResource(resource).toURI()));
+    var task = (JavacTask) compiler.getTask(null, fileManager, null, List.of("-proc:none"), null, file);
+    var docTrees = DocTrees.instance(task);
+    for (CompilationUnitTree unit : task.parse()) {
+      for (Tree type : unit.getTypeDecls()) {
+        var classTree = (ClassTree) type;
+        var classPath = new TreePath(new TreePath(unit), classTree);
+        if (classTree.getSimpleName().contentEquals(name)) {
+          return docTrees.getDocCommentTree(classPath);
+        }
+        for (Tree member : classTree.getMembers()) {
+          if (member instanceof MethodTree method && method.getName().contentEquals(name)) {
+            return docTrees.getDocCommentTree(new TreePath(classPath, method));
+          }
+        }
+      }
+    }
+    throw new AssertionError(name + " isn't in " + resource);
+  }
+
+  static Optional<DeprecatedTree> deprecatedTag(DocCommentTree doc) {
+    return Optional.ofNullable(doc).stream()
+        .flatMap(tree -> tree.getBlockTags().stream())
+        .filter(tag -> tag.getKind() == DocTree.Kind.DEPRECATED)
+        .map(DeprecatedTree.class::cast)
+        .findFirst();
+  }
+}
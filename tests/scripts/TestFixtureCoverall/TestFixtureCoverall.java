@@ -15,12 +15,30 @@ import java.text.MessageFormat;
 
 public class TestFixtureCoverall {
   public static void main(String[] args) throws Exception {
+    // Objects are freed through the cleaner set before creating any of them
+    Coverall.uniffiSetCleaner(java.lang.ref.Cleaner.create(runnable -> {
+      Thread thread = new Thread(runnable, "shared-cleaner");
+      thread.setDaemon(true);
+      return thread;
+    }));
+
     // Test some_dict()
     try (var d = Coverall.createSomeDict()) {
       assert d.text().equals("text");
       assert d.maybeText().equals("maybe_text");
       assert Arrays.equals(d.someBytes(), "some_bytes".getBytes(StandardCharsets.UTF_8));
       assert Arrays.equals(d.maybeSomeBytes(), "maybe_some_bytes".getBytes(StandardCharsets.UTF_8));
+
+      // `record_copy` in uniffi-extras.toml copies byte arrays, also optional ones, rather than sharing them
+      var copy = d.copy();
+      assert copy.text().equals(d.text());
+      assert copy.someBytes() != d.someBytes();
+      assert copy.maybeSomeBytes() != d.maybeSomeBytes();
+      copy.someBytes()[0] = 0;
+      copy.maybeSomeBytes()[0] = 0;
+      assert Arrays.equals(d.someBytes(), "some_bytes".getBytes(StandardCharsets.UTF_8));
+      assert Arrays.equals(d.maybeSomeBytes(), "maybe_some_bytes".getBytes(StandardCharsets.UTF_8));
+
       assert d.aBool();
       assert d.maybeABool() == false;
       assert d.unsigned8() == (byte)1;
@@ -174,6 +192,40 @@ public class TestFixtureCoverall {
       }
     }
     
+    // Every object has an interface, which can be stubbed without a Rust object behind it.
+    CoverallsInterface stub = (CoverallsInterface) java.lang.reflect.Proxy.newProxyInstance(
+      CoverallsInterface.class.getClassLoader(),
+      new Class<?>[] { CoverallsInterface.class },
+      (proxy, method, methodArgs) -> method.getName().equals("getName") ? "stubbed" : null);
+    assert stub.getName().equals("stubbed");
+
+    // Named constructors are static factory methods with their own names, next to the primary constructor.
+    assert Coveralls.class.getConstructor(String.class) != null;
+    assert java.lang.reflect.Modifier.isStatic(Coveralls.class.getMethod("fallibleNew", String.class, Boolean.class).getModifiers());
+    assert java.lang.reflect.Modifier.isStatic(Coveralls.class.getMethod("panickingNew", String.class).getModifiers());
+    try (var coveralls = Coveralls.fallibleNew("test_named_constructors", false)) {
+      assert coveralls.getName().equals("test_named_constructors");
+    }
+    try {
+      Coveralls.fallibleNew("test_named_constructors", true);
+      throw new RuntimeException("Expected constructor to throw exception");
+    } catch (CoverallException.TooManyHoles e) {
+      // Expected result
+    }
+    try {
+      Coveralls.panickingNew("oops");
+      throw new RuntimeException("Expected constructor to throw exception");
+    } catch (InternalException e) {
+      // Expected result
+    }
+
+    // `throws` clauses name the exact generated exception, with `Error` mapped to `Exception`.
+    for (Class<?> cls : List.of(Coveralls.class, CoverallsInterface.class)) {
+      assert Arrays.equals(cls.getMethod("maybeThrow", Boolean.class).getExceptionTypes(), new Class<?>[] { CoverallException.class });
+      assert Arrays.equals(cls.getMethod("maybeThrowComplex", Byte.class).getExceptionTypes(), new Class<?>[] { ComplexException.class });
+      assert cls.getMethod("panic", String.class).getExceptionTypes().length == 0;
+    }
+
     // Test complex errors.
     try (var coveralls = new Coveralls("test_complex_errors")) {
       assert coveralls.maybeThrowComplex((byte)0);
@@ -401,11 +453,48 @@ public class TestFixtureCoverall {
       Coverall.testRoundTripThroughForeign(new JavaGetters());
     }
 
+    // `CoverallListener` from `listeners` in uniffi-extras.toml is both a Getters and a NodeTrait
+    {
+      class JavaListener extends JavaGetters implements CoverallListener {
+        NodeTrait currentParent;
+
+        @Override
+        public String name() {
+          return "listener";
+        }
+
+        @Override
+        public void setParent(NodeTrait parent) {
+          this.currentParent = parent;
+        }
+
+        @Override
+        public NodeTrait getParent() {
+          return currentParent;
+        }
+
+        @Override
+        public Long strongCount() {
+          return 0L;
+        }
+      }
+
+      var listener = new JavaListener();
+      Coverall.testGetters(listener);
+      listener.setParent(new JavaNode());
+      assert Coverall.ancestorNames(listener).equals(Arrays.asList("node-kt"));
+    }
+
     // Test StringUtil
     {
       var traits = Coverall.getStringUtilTraits();
       assert traits.get(0).concat("cow", "boy").equals("cowboy");
       assert traits.get(1).concat("cow", "boy").equals("cowboy");
+
+      // The `Arc<dyn StringUtil>`s are usable through the generated interface
+      for (StringUtilInterface util : traits) {
+        assert util.concat("cow", "girl").equals("cowgirl");
+      }
     }
 
     // This tests that the UniFFI-generated scaffolding doesn't introduce any unexpected locking.
@@ -414,6 +503,13 @@ public class TestFixtureCoverall {
     // not be blocked on the first, and should reliably observe the first thread being busy.
     // If it does not, that suggests UniFFI is accidentally serializing the two threads on access
     // to the shared counter object.
+    // Objects that don't export Rust's `Eq` and `Hash` keep identity equality
+    try (ThreadsafeCounter first = new ThreadsafeCounter(); ThreadsafeCounter second = new ThreadsafeCounter()) {
+      assert first.equals(first);
+      assert !first.equals(second);
+      assert first.hashCode() == System.identityHashCode(first);
+    }
+
     try (ThreadsafeCounter counter = new ThreadsafeCounter()) {
       ExecutorService executor = Executors.newFixedThreadPool(3);
       try {
@@ -451,9 +547,44 @@ public class TestFixtureCoverall {
     assert d.category().equals("that");
     assert d.integer() == 42L;
 
+    // Objects lifted inside a collection each own their reference, closing the collection frees all of them.
+    try (Coveralls coveralls = new Coveralls("test_collection_cleanup")) {
+      for (int i = 0; i < 100; i++) {
+        coveralls.addRepair(new Repair(Instant.now(), new Patch(Color.RED)));
+      }
+      List<Repair> repairs = coveralls.getRepairs();
+      assert repairs.size() == 100;
+      AutoCloseableHelper.close(repairs);
+      for (Repair repair : repairs) {
+        try {
+          repair.patch().getColor();
+          throw new RuntimeException("Expected the patch to be freed");
+        } catch (IllegalStateException e) {
+          // Expected
+        }
+      }
+    }
+
     // Test bytes
     try (Coveralls coveralls = new Coveralls("test_bytes")) {
       assert new String(coveralls.reverse("123".getBytes(StandardCharsets.UTF_8)), StandardCharsets.UTF_8).equals("321");
+      assert coveralls.reverse(new byte[0]).length == 0;
+    }
+
+    // Empty and non-empty byte arrays written back to back, the way map values are, read back intact.
+    // No fixture has a `Map<String, Bytes>`, so this goes through the converter directly.
+    {
+      List<byte[]> values = List.of(new byte[0], "abc".getBytes(StandardCharsets.UTF_8), new byte[0]);
+      java.nio.ByteBuffer buf = java.nio.ByteBuffer.allocate((int) values.stream().mapToLong(FfiConverterByteArray.INSTANCE::allocationSize).sum());
+      values.forEach(v -> FfiConverterByteArray.INSTANCE.write(v, buf));
+      assert !buf.hasRemaining();
+      buf.flip();
+      for (byte[] expected : values) {
+        byte[] actual = FfiConverterByteArray.INSTANCE.read(buf);
+        assert Arrays.equals(actual, expected);
+        assert actual != expected;
+      }
+      assert !buf.hasRemaining();
     }
 
     // Test fakes using open classes
@@ -551,6 +682,11 @@ public class TestFixtureCoverall {
     }
 
     assert Coverall.getNumAlive() <= 1L : MessageFormat.format("Num alive is {0}. GC/Cleaner thread has starved", Coverall.getNumAlive());
+
+    // The cleaner set at the start is the only one the bindings use
+    var threadNames = Thread.getAllStackTraces().keySet().stream().map(Thread::getName).toList();
+    assert threadNames.contains("shared-cleaner");
+    assert threadNames.stream().noneMatch(name -> name.startsWith("Cleaner-")) : threadNames;
   }
   
   public static boolean almostEquals(float a, float b) {
@@ -586,6 +722,12 @@ public class TestFixtureCoverall {
     assert getters.getList(Arrays.asList(1, 2, 3), true).equals(Arrays.asList(1, 2, 3));
     assert getters.getList(Arrays.asList(1, 2, 3), false).equals(Arrays.asList());
 
+    // Large numeric sequences take the bulk write path, which writes every value as is
+    List<Integer> million = java.util.stream.IntStream.range(-500_000, 500_000).boxed().toList();
+    assert getters.getList(million, true).equals(million);
+    List<Integer> extremes = List.of(Integer.MIN_VALUE, -1, 0, 1, Integer.MAX_VALUE);
+    assert getters.getList(extremes, true).equals(extremes);
+
     // void function returns nothing, compiler won't let us write this test
     // assert getters.getNothing("hello") == Unit;
 
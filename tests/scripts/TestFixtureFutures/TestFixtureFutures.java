@@ -46,7 +46,51 @@ public class TestFixtureFutures {
   }
 
   public static void main(String[] args) throws Exception {
+    // Async calls run on the executor set here, rather than the common pool
+    var asyncThreads = Executors.newCachedThreadPool(runnable -> {
+      var thread = new Thread(runnable, "uniffi-async");
+      thread.setDaemon(true);
+      return thread;
+    });
+    var asyncTasks = new java.util.concurrent.atomic.AtomicInteger();
+    java.util.concurrent.Executor countingExecutor = task -> {
+      asyncTasks.incrementAndGet();
+      asyncThreads.execute(task);
+    };
+    UniffiAsyncHelpers.setExecutor(countingExecutor);
+    assert UniffiAsyncHelpers.getExecutor() == countingExecutor;
     try {
+      UniffiAsyncHelpers.setExecutor(null);
+      assert false : "a null executor should be refused";
+    } catch (NullPointerException e) {
+      // expected
+    }
+
+    try {
+      // the FFI callbacks and structs use the `ffi_name_prefix` from uniffi-extras.toml
+      {
+        Class.forName("uniffi.fixture.futures.UniffiFixtureFuturesRustFutureContinuationCallback");
+        Class.forName("uniffi.fixture.futures.UniffiFixtureFuturesForeignFuture");
+        try {
+          Class.forName("uniffi.fixture.futures.UniffiRustFutureContinuationCallback");
+          assert false : "the default FFI name prefix should not be used";
+        } catch (ClassNotFoundException e) {
+          // expected
+        }
+      }
+
+      // blocking variants from `emit_blocking_async` in uniffi-extras.toml
+      {
+        assert Futures.sayAfterBlocking((short)1, "Alice").equals("Hello, Alice!");
+        assert Futures.newMegaphone().sayAfterBlocking((short)1, "hi").equals("HELLO, HI!");
+        try {
+          Futures.fallibleMeBlocking(true);
+          assert false : "should have thrown";
+        } catch (Exception e) {
+          assert !(e instanceof java.util.concurrent.CompletionException) : "the cause should be rethrown";
+        }
+      }
+
       // init UniFFI to get good measurements after that
       {
         var time = measureTimeMillis(() -> {
@@ -135,12 +179,21 @@ public class TestFixtureFutures {
         assertApproximateTime(time, 200, "async methods");
       }
 
+      // The executor set at the start drove the calls so far
+      assert asyncTasks.get() > 0;
+
       // Test async constructors
       {
         var megaphone = Megaphone.secondary().get();
         assert megaphone.sayAfter((short)1, "hi").get().equals("HELLO, HI!");
       }
 
+      // An async primary constructor is a static factory, `new` is escaped like other reserved words
+      {
+        var megaphone = Megaphone._new().get();
+        assert megaphone.sayAfter((short)1, "hi").get().equals("HELLO, HI!");
+      }
+
       // Test async method returning optional object
       {
         var megaphone = Futures.asyncMaybeNewMegaphone(true).get();
@@ -162,6 +215,9 @@ public class TestFixtureFutures {
         });
 
         assertApproximateTime(time, 200, "async trait methods");
+
+        // single method traits with foreign implementations are functional interfaces
+        assert SayAfterTrait.class.isAnnotationPresent(FunctionalInterface.class);
       }
 
       // Test async methods in UDL-defined trait interfaces
@@ -198,6 +254,14 @@ public class TestFixtureFutures {
                 f.completeExceptionally(new RuntimeException("UnexpectedException"));
                 return f;
               }
+              if (value.equals("throw-number-format")) {
+                // Not caught, `callback_exception_mapping` maps it to `ParserError::NotAnInt`
+                return CompletableFuture.completedFuture(Integer.parseInt(value));
+              }
+              if (value.equals("throw-illegal-argument")) {
+                // The class NumberFormatException extends, mapped to `ParserError::UnexpectedError`
+                throw new IllegalArgumentException(value);
+              }
               try {
                 f.complete(Integer.parseInt(value));
               } catch (NumberFormatException e) {
@@ -254,6 +318,26 @@ public class TestFixtureFutures {
              throw e;
           }
         }
+        try {
+          Futures.tryFromStringUsingTrait(traitObj, 1, "throw-number-format").get();
+          throw new RuntimeException("Expected last statement to throw");
+        } catch (ExecutionException e) {
+          if (e.getCause() instanceof ParserException.NotAnInt) {
+            // Expected
+          } else {
+            throw e;
+          }
+        }
+        try {
+          Futures.tryFromStringUsingTrait(traitObj, 1, "throw-illegal-argument").get();
+          throw new RuntimeException("Expected last statement to throw");
+        } catch (ExecutionException e) {
+          if (e.getCause() instanceof ParserException.UnexpectedException) {
+            // Expected
+          } else {
+            throw e;
+          }
+        }
         Futures.delayUsingTrait(traitObj, 1).get();
         try {
           Futures.tryDelayUsingTrait(traitObj, "one").get();
@@ -411,6 +495,17 @@ public class TestFixtureFutures {
 
         System.out.println(MessageFormat.format("useSharedResource (not cancelled): {0}ms", time));
       }
+
+      // Test shutting down, which frees everything so it has to come last.
+      {
+        var megaphone = Futures.newMegaphone();
+        var pending = Futures.sleep((short)5000);
+
+        var freed = Futures.uniffiShutdown();
+        assert freed >= 2 : MessageFormat.format("only freed {0}", freed);
+        assert pending.isCancelled();
+        assert Futures.uniffiShutdown() == 0;
+      }
     } finally {
       // bring down the scheduler, if it's not shut down it'll hold the main thread open.
       scheduler.shutdown();
@@ -0,0 +1,52 @@
+import uniffi.geometry.*;
+
+public class TestGeometry {
+  public static void main(String[] args) throws Exception {
+    var ln1 = new Line(new Point(0.0,0.0), new Point(1.0,2.0));
+    var ln2 = new Line(new Point(1.0,1.0), new Point(2.0,2.0));
+
+    assert Geometry.gradient(ln1) == 2.0;
+    assert Geometry.gradient(ln2) == 1.0;
+
+    assert Geometry.intersection(ln1, ln2).equals(new Point(0.0, 0.0));
+    assert Geometry.intersection(ln1, ln1) == null;
+
+    // `record_copy` in uniffi-extras.toml copies nested records rather than sharing them
+    var copy = ln1.copy();
+    assert copy.equals(ln1);
+    copy.start().setCoordX(5.0);
+    assert ln1.start().equals(new Point(0.0, 0.0));
+
+    // `record_map_conversion` converts records to maps by field name and back, nested ones included
+    var map = ln1.toMap();
+    assert map.equals(java.util.Map.of(
+      "start", java.util.Map.of("coordX", 0.0, "coordY", 0.0),
+      "end", java.util.Map.of("coordX", 1.0, "coordY", 2.0))) : map;
+    assert Line.fromMap(map).equals(ln1);
+    try {
+      Point.fromMap(java.util.Map.of("coordX", 1.0));
+      throw new RuntimeException("Should have failed without coordY");
+    } catch (IllegalArgumentException e) {
+      assert e.getMessage().equals("Point.coordY is missing") : e.getMessage();
+    }
+    try {
+      Point.fromMap(java.util.Map.of("coordX", 1.0, "coordY", "2.0"));
+      throw new RuntimeException("Should have failed with a String coordY");
+    } catch (IllegalArgumentException e) {
+      assert e.getMessage().equals("Point.coordY has to be a java.lang.Double, not a java.lang.String") : e.getMessage();
+    }
+
+    // `instrumentable_ffi` has the calls into the library go through the installed instrumentation
+    var calls = new java.util.ArrayList<String>();
+    Geometry.uniffiSetFfiInstrumentation((function, call) -> {
+      calls.add(function);
+      return call.get();
+    });
+    assert Geometry.gradient(ln1) == 2.0;
+    assert calls.stream().anyMatch(function -> function.endsWith("_fn_func_gradient")) : calls;
+    Geometry.uniffiSetFfiInstrumentation(null);
+    calls.clear();
+    assert Geometry.gradient(ln2) == 1.0;
+    assert calls.isEmpty();
+  }
+}
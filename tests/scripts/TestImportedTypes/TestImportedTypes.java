@@ -7,6 +7,7 @@ import uniffi.imported_types_sublib.*;
 import uniffi.uniffi_one_ns.*;
 import uniffi.ext_types_custom.*;
 import uniffi.custom_types.*;
+import java.util.Arrays;
 import java.util.List;
 import java.util.stream.Stream;
 
@@ -20,6 +21,14 @@ public class TestImportedTypes {
     var ct2 = ImportedTypesLib.getCombinedType(null);
     assert ct.equals(ct2);
 
+    // `unmodifiable_collections` in uniffi-extras.toml makes collection getters read-only
+    try {
+      ct.uots().add(ct.uot());
+      throw new RuntimeException("Should have thrown");
+    } catch (UnsupportedOperationException e) {
+      // Expected
+    }
+
     assert ImportedTypesLib.getObjectsType(null).maybeInterface() == null;
     assert ImportedTypesLib.getObjectsType(null).maybeTrait() == null;
     assert ImportedTypesLib.getUniffiOneTrait(null) == null;
@@ -34,6 +43,12 @@ public class TestImportedTypes {
     assert ImportedTypesLib.getUrls(List.of(url)).equals(List.of(url));
     // List.of doesn't allow `null`, though `null` is allowed in `List` /shrug
     assert ImportedTypesLib.getMaybeUrls(Stream.of(url, null).toList()).equals(Stream.of(url, null).toList());
+    // A `Vec<Option<T>>` keeps its `None`s where they are, including a leading one or only `None`s
+    var maybeUrls = Arrays.asList(null, url, null);
+    assert ImportedTypesLib.getMaybeUrls(maybeUrls).equals(maybeUrls);
+    var noUrls = Arrays.<Url>asList(null, null);
+    assert ImportedTypesLib.getMaybeUrls(noUrls).equals(noUrls);
+    assert ImportedTypesLib.getMaybeUrls(List.of()).isEmpty();
 
     assert ExtTypesCustom.getGuid(new Guid("guid")).equals(new Guid("guid"));
     assert ExtTypesCustom.getOuid(new Ouid("ouid")).equals(new Ouid("ouid"));
@@ -41,16 +56,25 @@ public class TestImportedTypes {
     assert ImportedTypesLib.getImportedOuid(new Ouid("ouid")).equals(new Ouid("ouid"));
 
     var uot = new UniffiOneType("hello");
+    // `record_hash = "fnv"` in uniffi-extras.toml gives the same hash on every JVM
+    assert uot.hashCode() == -1202944376 : uot.hashCode();
+    assert UniffiOneType.from(new UniffiOneProcMacroType("hello")).equals(uot);
     assert ImportedTypesLib.getUniffiOneType(uot).equals(uot);
     assert ImportedTypesLib.getMaybeUniffiOneType(uot).equals(uot);
     assert ImportedTypesLib.getMaybeUniffiOneType(null) == null;
     assert ImportedTypesLib.getUniffiOneTypes(List.of(uot)).equals(List.of(uot));
     assert ImportedTypesLib.getMaybeUniffiOneTypes(Stream.of(uot, null).toList()).equals(Stream.of(uot, null).toList());
+    var maybeUots = Arrays.asList(null, uot, null);
+    assert ImportedTypesLib.getMaybeUniffiOneTypes(maybeUots).equals(maybeUots);
 
     var uopmt = new UniffiOneProcMacroType("hello from proc-macro world");
     assert ImportedTypesLib.getUniffiOneProcMacroType(uopmt).equals(uopmt);
     assert UniffiOneNs.getMyProcMacroType(uopmt).equals(uopmt);
 
+    // `record_comparators` is set in uniffi-extras.toml
+    var sorted = Stream.of("c", "a", "b").map(UniffiOneProcMacroType::new).sorted(UniffiOneProcMacroType.comparingBySval()).map(UniffiOneProcMacroType::sval).toList();
+    assert sorted.equals(List.of("a", "b", "c")) : sorted;
+
     var uoe = UniffiOneEnum.ONE;
     assert ImportedTypesLib.getUniffiOneEnum(uoe).equals(uoe);
     assert ImportedTypesLib.getMaybeUniffiOneEnum(uoe).equals(uoe);
@@ -58,6 +82,22 @@ public class TestImportedTypes {
     assert ImportedTypesLib.getUniffiOneEnums(List.of(uoe)).equals(List.of(uoe));
     assert ImportedTypesLib.getMaybeUniffiOneEnums(Stream.of(uoe, null).toList()).equals(Stream.of(uoe, null).toList());
 
+    // `enum_forward_compat` is set in uniffi-extras.toml, discriminants from newer Rust enums lift to `UNKNOWN`
+    var unknown = java.nio.ByteBuffer.allocate(4).putInt(0, 99);
+    assert FfiConverterTypeUniffiOneEnum.INSTANCE.read(unknown) == UniffiOneEnum.UNKNOWN;
+    try {
+      ImportedTypesLib.getUniffiOneEnum(UniffiOneEnum.UNKNOWN);
+      throw new RuntimeException("Should have thrown");
+    } catch (IllegalArgumentException e) {
+      // Expected
+    }
+
+    // Converters are public, values can be lowered and lifted outside of the generated code
+    var lowered = FfiConverterTypeUniffiOneProcMacroType.INSTANCE.lower(new UniffiOneProcMacroType("lowered"));
+    assert FfiConverterTypeUniffiOneProcMacroType.INSTANCE.lift(lowered).sval().equals("lowered");
+    var loweredEnum = FfiConverterTypeUniffiOneEnum.INSTANCE.lowerIntoRustBuffer(UniffiOneEnum.TWO);
+    assert FfiConverterTypeUniffiOneEnum.INSTANCE.liftFromRustBuffer(loweredEnum) == UniffiOneEnum.TWO;
+
     assert ct.ecd().sval().equals("ecd");
     assert ImportedTypesLib.getExternalCrateInterface("foo").value().equals("foo");
   }
@@ -0,0 +1,65 @@
+import java.lang.reflect.Method;
+import java.lang.reflect.Proxy;
+import java.net.URL;
+import java.net.URLClassLoader;
+import uniffi.arithmetic.*;
+
+public class TestIsolation {
+  // Sees JNA, which is shared, but not the bindings on the classpath, like an application server
+  // giving each deployment the bindings jar in a classloader of its own.
+  static class JnaOnlyClassLoader extends ClassLoader {
+    JnaOnlyClassLoader() {
+      super(ClassLoader.getPlatformClassLoader());
+    }
+
+    @Override
+    protected Class<?> findClass(String name) throws ClassNotFoundException {
+      if (name.startsWith("com.sun.jna.")) {
+        return ClassLoader.getSystemClassLoader().loadClass(name);
+      }
+      throw new ClassNotFoundException(name);
+    }
+  }
+
+  // The JNA library the bindings loaded in `loader` call into.
+  static Object nativeLibrary(ClassLoader loader) throws Exception {
+    Method getInstance = loader.loadClass("uniffi.arithmetic.UniffiLib").getDeclaredMethod("getInstance");
+    getInstance.setAccessible(true);
+    com.sun.jna.Library.Handler handler = (com.sun.jna.Library.Handler) Proxy.getInvocationHandler(getInstance.invoke(null));
+    return handler.getNativeLibrary();
+  }
+
+  static long add(ClassLoader loader, long a, long b) throws Exception {
+    Class<?> arithmetic = loader.loadClass("uniffi.arithmetic.Arithmetic");
+    assert arithmetic != Arithmetic.class;
+    assert arithmetic.getClassLoader() == loader;
+    return (Long) arithmetic.getMethod("add", long.class, long.class).invoke(null, a, b);
+  }
+
+  public static void main(String[] args) throws Exception {
+    URL bindingsJar = Arithmetic.class.getProtectionDomain().getCodeSource().getLocation();
+    assert bindingsJar.getPath().endsWith(".jar") : bindingsJar;
+    assert Arithmetic.add(2L, 3L) == 5L;
+
+    try (URLClassLoader first = new URLClassLoader(new URL[] {bindingsJar}, new JnaOnlyClassLoader());
+        URLClassLoader second = new URLClassLoader(new URL[] {bindingsJar}, new JnaOnlyClassLoader())) {
+      // The native library is found through each classloader, and works in all of them.
+      assert add(first, 2L, 3L) == 5L;
+      assert add(second, 4L, 5L) == 9L;
+
+      // JNA caches libraries by name and options, the classloader keeps them apart.
+      Object systemLibrary = nativeLibrary(ClassLoader.getSystemClassLoader());
+      Object firstLibrary = nativeLibrary(first);
+      Object secondLibrary = nativeLibrary(second);
+      assert firstLibrary != systemLibrary;
+      assert secondLibrary != systemLibrary;
+      assert firstLibrary != secondLibrary;
+
+      // Reinitializing the bindings in one classloader leaves the others alone.
+      first.loadClass("uniffi.arithmetic.Arithmetic").getMethod("uniffiReinitialize").invoke(null);
+      assert nativeLibrary(second) == secondLibrary;
+      assert nativeLibrary(ClassLoader.getSystemClassLoader()) == systemLibrary;
+      assert add(first, 1L, 1L) == 2L;
+    }
+  }
+}
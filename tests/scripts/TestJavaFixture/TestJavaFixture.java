@@ -0,0 +1,76 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.java_fixture.*;
+
+import java.lang.reflect.Modifier;
+import java.util.List;
+import java.util.Map;
+
+public class TestJavaFixture {
+  public static void main(String[] args) throws Exception {
+    // `sequence_varargs` is set in uniffi-extras.toml
+    assert JavaFixture.sum(1, 2, 3) == 6;
+    assert JavaFixture.sum() == 0;
+    // Plain element types can't pollute the varargs array, so there's no `@SafeVarargs`
+    assert !JavaFixture.class.getMethod("sum", Integer[].class).isAnnotationPresent(SafeVarargs.class);
+
+    // Generic ones can, the overload is `@SafeVarargs` so calling it doesn't warn
+    assert JavaFixture.lengths(List.of(1, 2), List.of()).equals(List.of(2, 0));
+    assert JavaFixture.lengths().isEmpty();
+    var lengths = JavaFixture.class.getMethod("lengths", List[].class);
+    assert lengths.isVarArgs();
+    assert lengths.isAnnotationPresent(SafeVarargs.class);
+
+    // `on_unsupported = "stub"` is set in uniffi-extras.toml, so the overload leaving out a default
+    // Java can't write is generated, and throws
+    assert JavaFixture.total(Map.of("a", 1, "b", 2)) == 3;
+    try {
+      JavaFixture.total();
+      throw new RuntimeException("Should have thrown");
+    } catch (UnsupportedOperationException e) {
+      assert e.getMessage().contains("`counts`") : e.getMessage();
+    }
+
+    try (var counter = new EntryCounter()) {
+      assert counter.count(Map.of("a", 1), Map.of("b", 2, "c", 3)) == 3;
+      assert counter.count() == 0;
+      // `@SafeVarargs` methods have to be `final`
+      var count = EntryCounter.class.getMethod("count", Map[].class);
+      assert count.isVarArgs();
+      assert count.isAnnotationPresent(SafeVarargs.class);
+      assert Modifier.isFinal(count.getModifiers());
+
+      // Methods get the stub too
+      assert counter.largest(Map.of("a", 1, "b", 5)) == 5;
+      try {
+        counter.largest();
+        throw new RuntimeException("Should have thrown");
+      } catch (UnsupportedOperationException e) {
+        assert e.getMessage().contains("`counts`") : e.getMessage();
+      }
+    }
+
+    // Every variant of a large flat enum is lifted and lowered as itself
+    LargeFlatEnum[] variants = LargeFlatEnum.values();
+    assert variants.length == 64;
+    assert JavaFixture.largeFlatEnumValues().equals(List.of(variants));
+    for (int i = 0; i < variants.length; i++) {
+      assert JavaFixture.nextLargeFlatEnum(variants[i]) == variants[(i + 1) % variants.length] : variants[i];
+    }
+
+    // `emit_constants` is set in uniffi-extras.toml, the constants have the Java type of the repr
+    // and the value of the Rust discriminant, which round-trips through the enum
+    assert JavaFixtureConstants.class.getField("PRIORITY_HIGH").getType() == byte.class;
+    assert JavaFixtureConstants.class.getField("OFFSET_AFTER").getType() == long.class;
+    assert JavaFixtureConstants.PRIORITY_LOW == 1;
+    assert Byte.toUnsignedInt(JavaFixtureConstants.PRIORITY_HIGH) == 200;
+    assert JavaFixtureConstants.OFFSET_BEFORE == -1L;
+    assert JavaFixtureConstants.OFFSET_AFTER == 4_000_000_000L;
+    assert JavaFixture.priorityDiscriminant(Priority.LOW) == JavaFixtureConstants.PRIORITY_LOW;
+    assert JavaFixture.priorityDiscriminant(Priority.HIGH) == JavaFixtureConstants.PRIORITY_HIGH;
+    assert JavaFixture.offsetDiscriminant(Offset.BEFORE) == JavaFixtureConstants.OFFSET_BEFORE;
+    assert JavaFixture.offsetDiscriminant(Offset.AFTER) == JavaFixtureConstants.OFFSET_AFTER;
+  }
+}
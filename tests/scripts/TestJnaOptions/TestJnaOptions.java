@@ -0,0 +1,19 @@
+import com.sun.jna.Library;
+import com.sun.jna.NativeLibrary;
+import com.sun.jna.Structure;
+import java.lang.reflect.Method;
+import java.lang.reflect.Proxy;
+import uniffi.arithmetic.*;
+
+public class TestJnaOptions {
+  public static void main(String[] args) throws Exception {
+    assert Arithmetic.add(2L, 4L) == 6L;
+
+    // The configured `jna_options` are the options the library was loaded with
+    Method getInstance = Class.forName("uniffi.arithmetic.UniffiLib").getMethod("getInstance");
+    getInstance.setAccessible(true);
+    NativeLibrary library = ((Library.Handler) Proxy.getInvocationHandler(getInstance.invoke(null))).getNativeLibrary();
+    assert "ISO-8859-1".equals(library.getOptions().get(Library.OPTION_STRING_ENCODING));
+    assert Integer.valueOf(Structure.ALIGN_MSVC).equals(library.getOptions().get(Library.OPTION_STRUCTURE_ALIGNMENT));
+  }
+}
@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import com.sun.jna.Platform;
+import java.io.InputStream;
+import java.nio.charset.StandardCharsets;
+import java.util.Arrays;
+import java.util.List;
+import java.util.jar.Attributes;
+import java.util.jar.Manifest;
+import java.util.regex.Pattern;
+import uniffi.arithmetic.*;
+
+public class TestOsgiManifest {
+  public static void main(String[] args) throws Exception {
+    // The manifest fragment is next to the package directory on the classpath
+    var resource = ClassLoader.getSystemResource("META-INF/uniffi.arithmetic.MF");
+    assert resource != null;
+    byte[] bytes;
+    try (InputStream in = resource.openStream()) {
+      bytes = in.readAllBytes();
+    }
+    for (String line : new String(bytes, StandardCharsets.UTF_8).split("\n")) {
+      assert line.getBytes(StandardCharsets.UTF_8).length <= 72 : line;
+    }
+    Attributes headers = new Manifest(resource.openStream()).getMainAttributes();
+
+    // The bundle exports the package of the bindings and imports JNA
+    String packageName = Arithmetic.class.getPackageName();
+    assert headers.getValue("Bundle-SymbolicName").equals(packageName);
+    assert headers.getValue("Export-Package").equals(packageName);
+    assert Arrays.asList(headers.getValue("Import-Package").split(",")).contains(Platform.class.getPackageName());
+
+    // The Java running the bindings satisfies the required execution environment
+    var version = Pattern.compile("version>=(\\d+)").matcher(headers.getValue("Require-Capability"));
+    assert headers.getValue("Require-Capability").startsWith("osgi.ee;filter:=");
+    assert version.find();
+    assert Runtime.version().feature() >= Integer.parseInt(version.group(1));
+
+    // The native code clause of this platform names the library where JNA loads it from in the jar
+    List<String> clauses = Arrays.asList(headers.getValue("Bundle-NativeCode").split(","));
+    assert clauses.size() == 6 : clauses;
+    var platformLibraries = clauses.stream()
+        .map(clause -> clause.split(";")[0])
+        .filter(path -> path.startsWith(Platform.RESOURCE_PREFIX + "/"))
+        .toList();
+    assert platformLibraries.size() == 1 : platformLibraries;
+    assert Arithmetic.class.getClassLoader().getResource(platformLibraries.get(0)) != null : platformLibraries;
+    assert Arithmetic.add(2L, 4L) == 6L;
+  }
+}
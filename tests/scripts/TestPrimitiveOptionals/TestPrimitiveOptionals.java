@@ -0,0 +1,32 @@
+import uniffi.rondpoint.*;
+
+import java.util.OptionalInt;
+
+public class TestPrimitiveOptionals {
+  public static void main(String[] args) throws Exception {
+    var op = new Optionneur();
+
+    // An `i32?` round trips as an `OptionalInt`, the default of the overload included
+    assert op.sinonZero().equals(OptionalInt.of(0));
+    assert op.sinonZero(OptionalInt.of(42)).equals(OptionalInt.of(42));
+    assert op.sinonZero(OptionalInt.of(-1)).equals(OptionalInt.of(-1));
+    assert op.sinonZero(OptionalInt.empty()).equals(OptionalInt.empty());
+    // `null` is sent as `None` too
+    assert op.sinonZero(null).equals(OptionalInt.empty());
+
+    // A `string?` has no primitive optional, it stays nullable
+    assert op.sinonNull() == null;
+    assert op.sinonNull("test").equals("test");
+
+    op.close();
+
+    // `newtype_optimization` makes a record holding a single `boolean` out of MinusculeMajusculeDict
+    var newtype = new MinusculeMajusculeDict(true);
+    assert newtype.minusculeMajusculeField();
+    assert MinusculeMajusculeDict.class.isRecord();
+    assert MinusculeMajusculeDict.class.getRecordComponents()[0].getType() == boolean.class;
+    // Records with more fields keep their boxed fields
+    assert !Dictionnaire.class.isRecord();
+    assert Dictionnaire.class.getDeclaredField("deux").getType() == Boolean.class;
+  }
+}
@@ -0,0 +1,37 @@
+import jakarta.enterprise.context.ApplicationScoped;
+import jakarta.enterprise.inject.Disposes;
+import jakarta.enterprise.inject.Produces;
+import java.lang.reflect.Method;
+import uniffi.rondpoint.*;
+
+public class TestQuarkusProducers {
+  public static void main(String[] args) throws Exception {
+    assert RondpointProducers.class.isAnnotationPresent(ApplicationScoped.class);
+
+    // Each object with a constructor taking no arguments is an application scoped bean, produced
+    // through its interface
+    Method produce = RondpointProducers.class.getMethod("retourneur");
+    assert produce.isAnnotationPresent(Produces.class);
+    assert produce.isAnnotationPresent(ApplicationScoped.class);
+    assert produce.getReturnType() == RetourneurInterface.class;
+    for (String name : new String[] {"stringifier", "optionneur"}) {
+      assert RondpointProducers.class.getMethod(name).isAnnotationPresent(Produces.class) : name;
+    }
+
+    var producers = new RondpointProducers();
+    RetourneurInterface retourneur = producers.retourneur();
+    assert retourneur instanceof Retourneur;
+    assert retourneur.identiqueI8((byte) 42) == (byte) 42;
+
+    // and closed when it's disposed of
+    Method dispose = RondpointProducers.class.getMethod("closeRetourneur", RetourneurInterface.class);
+    assert dispose.getParameters()[0].isAnnotationPresent(Disposes.class);
+    producers.closeRetourneur(retourneur);
+    try {
+      retourneur.identiqueI8((byte) 42);
+      throw new RuntimeException("Should have failed to call a closed object");
+    } catch (IllegalStateException e) {
+      // It's okay!
+    }
+  }
+}
@@ -0,0 +1,10 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package io.quarkus.runtime.annotations;
+
+/** A stand-in for Quarkus' annotation, which isn't on the test classpath. */
+public @interface RegisterForReflection {
+  boolean registerFullHierarchy() default false;
+}
@@ -0,0 +1,15 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package jakarta.enterprise.inject;
+
+import java.lang.annotation.Retention;
+import java.lang.annotation.RetentionPolicy;
+
+/**
+ * A stand-in for the CDI annotation, which isn't on the test classpath. It's kept at run time, like
+ * the real one, so the test can read it.
+ */
+@Retention(RetentionPolicy.RUNTIME)
+public @interface Disposes {}
@@ -1,4 +1,5 @@
 import java.text.MessageFormat;
+import java.util.Collections;
 import java.util.List;
 import java.util.Map;
 import java.util.function.BiFunction;
@@ -13,8 +14,81 @@ public class TestRondpoint {
     Dictionnaire copyDico = Rondpoint.copieDictionnaire(dico);
     assert dico.equals(copyDico);
     
+    // `toBuilder()` copies every field, so changing one leaves the others
+    Dictionnaire modifie = dico.toBuilder().withPetitNombre((byte)1).build();
+    assert modifie.equals(new Dictionnaire(Enumeration.DEUX, true, (byte)1, 123456789L));
+    assert dico.toBuilder().build().equals(dico);
+    // `builder()` starts from the Rust defaults
+    OptionneurDictionnaire parDefaut = OptionneurDictionnaire.builder().build();
+    assert parDefaut.i8Var() == -8;
+    assert parDefaut.stringVar().equals("default");
+
     assert Rondpoint.copieEnumeration(Enumeration.DEUX).equals(Enumeration.DEUX);
     assert Rondpoint.copieEnumerations(List.of(Enumeration.UN, Enumeration.DEUX)).equals(List.of(Enumeration.UN, Enumeration.DEUX));
+    assert Rondpoint.copieEnumerations(List.of(Enumeration.values())).equals(List.of(Enumeration.values()));
+    // `buffer_growth_factor` writes this into a buffer growing several times
+    List<Enumeration> beaucoup = Collections.nCopies(1000, Enumeration.TROIS);
+    assert Rondpoint.copieEnumerations(beaucoup).equals(beaucoup);
+
+    // Variant metadata matches the declaration order
+    assert Enumeration.VARIANT_COUNT == Enumeration.values().length;
+    assert Enumeration.VARIANT_NAMES.equals(List.of("UN", "DEUX", "TROIS"));
+    for (var variant : Enumeration.values()) {
+      assert Enumeration.VARIANT_NAMES.get(variant.ordinal()).equals(variant.name());
+    }
+
+    // `enum_visitor` dispatches each variant to its own method
+    Enumeration.Visitor<Integer> chiffre = new Enumeration.Visitor<>() {
+      @Override
+      public Integer visitUn() {
+        return 1;
+      }
+
+      @Override
+      public Integer visitDeux() {
+        return 2;
+      }
+
+      @Override
+      public Integer visitTrois() {
+        return 3;
+      }
+    };
+    assert Enumeration.UN.accept(chiffre) == 1;
+    assert Enumeration.DEUX.accept(chiffre) == 2;
+    assert Rondpoint.copieEnumeration(Enumeration.TROIS).accept(chiffre) == 3;
+
+    assert EnumerationAvecDonnees.VARIANT_COUNT == 3;
+    assert EnumerationAvecDonnees.VARIANT_NAMES.equals(List.of("Zero", "Un", "Deux"));
+    assert EnumerationAvecDonnees.variants().equals(List.of(
+      EnumerationAvecDonnees.Zero.class, EnumerationAvecDonnees.Un.class, EnumerationAvecDonnees.Deux.class));
+    for (var variant : EnumerationAvecDonnees.variants()) {
+      assert EnumerationAvecDonnees.VARIANT_NAMES.get(EnumerationAvecDonnees.variants().indexOf(variant)).equals(variant.getSimpleName());
+    }
+
+    // `enum_with_tag` gives each variant a tag to switch on, then cast to the variant record
+    EnumerationAvecDonnees avecDonnees = new EnumerationAvecDonnees.Deux(2, "deux");
+    String tagged = switch (avecDonnees.kind()) {
+      case ZERO -> "zero";
+      case UN -> "un " + ((EnumerationAvecDonnees.Un) avecDonnees).premier();
+      case DEUX -> "deux " + ((EnumerationAvecDonnees.Deux) avecDonnees).second();
+    };
+    assert tagged.equals("deux deux");
+    assert new EnumerationAvecDonnees.Zero().kind() == EnumerationAvecDonneesKind.ZERO;
+
+    // `comparable` orders Dictionnaire by its listed fields, comparing unsigned numbers unsigned
+    Dictionnaire petit = new Dictionnaire(Enumeration.TROIS, true, (byte)0, -1L);
+    Dictionnaire grand = new Dictionnaire(Enumeration.UN, false, (byte)1, 0L);
+    assert petit.compareTo(grand) < 0;
+    assert petit.compareTo(new Dictionnaire(Enumeration.UN, false, (byte)0, 1L)) > 0;
+    assert petit.compareTo(new Dictionnaire(Enumeration.UN, false, (byte)0, -1L)) == 0;
+    assert List.of(grand, petit).stream().sorted().collect(Collectors.toList()).equals(List.of(petit, grand));
+    // Flat enums are ordered by discriminant
+    assert List.of(Enumeration.TROIS, Enumeration.UN, Enumeration.DEUX).stream()
+      .sorted(Enumeration.discriminantOrder())
+      .collect(Collectors.toList())
+      .equals(List.of(Enumeration.UN, Enumeration.DEUX, Enumeration.TROIS));
+
     assert Rondpoint.copieCarte(Map.ofEntries(
       Map.entry("0", new EnumerationAvecDonnees.Zero()),
       Map.entry("1", new EnumerationAvecDonnees.Un(1)),
@@ -24,6 +98,15 @@ public class TestRondpoint {
       Map.entry("1", new EnumerationAvecDonnees.Un(1)),
       Map.entry("2", new EnumerationAvecDonnees.Deux(2, "deux")))
     );
+    // Empty collections round trip as empty, not null, and a null one is refused before reaching Rust
+    var emptyCarte = Rondpoint.copieCarte(Map.of());
+    assert emptyCarte != null && emptyCarte.isEmpty();
+    try {
+      Rondpoint.copieCarte(null);
+      throw new RuntimeException("Should have refused a null map");
+    } catch (NullPointerException e) {
+      assert e.getMessage().contains("can't be null") : e.getMessage();
+    }
 
     var var1 = new EnumerationAvecDonnees.Zero();
     var var2 = new EnumerationAvecDonnees.Un(1);
@@ -33,6 +116,10 @@ public class TestRondpoint {
     assert var1.equals(new EnumerationAvecDonnees.Zero());
     assert !var1.equals(new EnumerationAvecDonnees.Un(1));
     assert var2.equals(new EnumerationAvecDonnees.Un(1));
+    // different variants are never equal, even if their shared fields have the same values
+    var var4 = new EnumerationAvecDonnees.Deux(1, "deux");
+    assert !var2.equals(var4);
+    assert !var4.equals(var2);
 
     assert Rondpoint.switcheroo(false);
 
@@ -42,6 +129,17 @@ public class TestRondpoint {
     //      lowering from Rust and lifting into Java.
     var rt = new Retourneur();
 
+    // `enforce_thread_affinity` keeps other threads from calling it
+    var otherThread = java.util.concurrent.CompletableFuture.supplyAsync(() -> {
+      try {
+        rt.identiqueBoolean(true);
+        return false;
+      } catch (IllegalStateException e) {
+        return e.getMessage().contains("the thread that created it");
+      }
+    });
+    assert otherThread.get();
+
     // Booleans
     affirmAllerRetour(List.of(true, false), rt::identiqueBoolean);
 
@@ -67,6 +165,20 @@ public class TestRondpoint {
     // Doubles
     affirmAllerRetour(List.of(0.0, 1.0, Double.MIN_VALUE, Double.MAX_VALUE), rt::identiqueDouble);
 
+    // NaN, infinities and negative zero keep their exact bits, both as arguments and inside buffers
+    for (float v : new float[] { Float.NaN, Float.intBitsToFloat(0x7fc00001), Float.POSITIVE_INFINITY, Float.NEGATIVE_INFINITY, -0.0F }) {
+      assert Float.floatToRawIntBits(rt.identiqueFloat(v)) == Float.floatToRawIntBits(v) : v;
+      var buf = java.nio.ByteBuffer.allocate(4);
+      FfiConverterFloat.INSTANCE.write(v, buf);
+      assert Float.floatToRawIntBits(FfiConverterFloat.INSTANCE.read(buf.flip())) == Float.floatToRawIntBits(v) : v;
+    }
+    for (double v : new double[] { Double.NaN, Double.longBitsToDouble(0x7ff8000000000001L), Double.POSITIVE_INFINITY, Double.NEGATIVE_INFINITY, -0.0 }) {
+      assert Double.doubleToRawLongBits(rt.identiqueDouble(v)) == Double.doubleToRawLongBits(v) : v;
+      var buf = java.nio.ByteBuffer.allocate(8);
+      FfiConverterDouble.INSTANCE.write(v, buf);
+      assert Double.doubleToRawLongBits(FfiConverterDouble.INSTANCE.read(buf.flip())) == Double.doubleToRawLongBits(v) : v;
+    }
+
     // Strings
     affirmAllerRetour(List.of("", "abc", "null\u0000byte", "été", "ښي لاس ته لوستلو لوستل", "😻emoji 👨‍👧‍👦multi-emoji, 🇨🇭a flag, a canal, panama"), rt::identiqueString);
 
@@ -96,6 +208,10 @@ public class TestRondpoint {
     var wellKnown = st.wellKnownString("java");
     assert "uniffi 💚 java!".equals(wellKnown) : MessageFormat.format("wellKnownString 'uniffi 💚 java!' == '{0}'", wellKnown);
 
+    // With `string_params_as_charsequence`, any `CharSequence` can be passed where Rust takes a string.
+    var builtWellKnown = st.wellKnownString(new StringBuilder("ja").append("va"));
+    assert "uniffi 💚 java!".equals(builtWellKnown) : MessageFormat.format("wellKnownString(StringBuilder) 'uniffi 💚 java!' == '{0}'", builtWellKnown);
+
     // Booleans
     affirmEnchaine(List.of(true, false), st::toStringBoolean, TestRondpoint::defaultStringyEquals);
   
@@ -129,22 +245,67 @@ public class TestRondpoint {
 
     st.close();
 
-    // Defaults aren't supported in Java, so we check that our Java `None` equivalent goes across the barrier correctly
-    // as an option and comes back instead. See Kotlin's rondpoint tests for reference default behavior if you want to
-    // PR defaults as a feature.
-    // Step 1: call the methods without arguments, check that Option works.
-    var op = new Optionneur();  
-  
+    // Step 1: call the methods without arguments, the overloads pass the defaults.
+    var op = new Optionneur();
+
+    assert op.sinonString().equals("default");
+    assert op.sinonBoolean() == false;
+    assert op.sinonSequence().isEmpty();
+
     // Optionals
+    assert op.sinonNull() == null;
+    assert op.sinonZero() == 0;
     assert op.sinonNull(null) == null;
     assert op.sinonZero(null) == null;
 
+    // Integers, unsigned ones keep their bits in the signed type
+    assert op.sinonU8Dec() == (byte) 42;
+    assert op.sinonI8Dec() == (byte) -42;
+    assert op.sinonU8Hex() == (byte) 0xff;
+    assert op.sinonU64Hex() == 0xffffffffffffffffL;
+    assert op.sinonU32Oct() == 493;
+
+    // Floats
+    assert op.sinonF32() == 42.0f;
+    assert op.sinonF64() == 42.1;
+
+    // Enums
+    assert op.sinonEnum() == Enumeration.TROIS;
+
         // Step 2. Convince ourselves that if we pass something else, then that changes the output.
     //         We have shown something coming out of the sinon methods, but without eyeballing the Rust
     //         we can't be sure that the arguments will change the return value.
     affirmAllerRetour(List.of("foo", "bar"), op::sinonString);
     affirmAllerRetour(List.of(true, false), op::sinonBoolean);
     affirmAllerRetour(List.of(List.of("a", "b"), List.of()), op::sinonSequence);
+    // `RustBufferReader` reads a buffer by hand the way the converters do
+    var strings = List.of("a", "été");
+    var rbuf = FfiConverterSequenceString.INSTANCE.lower(strings);
+    var reader = new RustBufferReader(rbuf);
+    assert reader.readInt() == 2;
+    assert reader.readString().equals("a");
+    assert reader.readString().equals("été");
+    assert !reader.hasRemaining();
+    // Lifting frees the buffer, the reader didn't move the converter's position
+    assert FfiConverterSequenceString.INSTANCE.lift(rbuf).equals(strings);
+
+    var emptySequence = op.sinonSequence(List.of());
+    assert emptySequence != null && emptySequence.isEmpty();
+    try {
+      op.sinonSequence((List<String>) null);
+      throw new RuntimeException("Should have refused a null list");
+    } catch (NullPointerException e) {
+      assert e.getMessage().contains("can't be null") : e.getMessage();
+    }
+
+    // A value too big for a RustBuffer's `int`-indexed ByteBuffer fails before anything is allocated,
+    // here about 3GB of pessimistically sized strings that all share one 1MB string.
+    try {
+      op.sinonSequence(java.util.Collections.nCopies(1000, "a".repeat(1 << 20)));
+      throw new RuntimeException("Should have thrown");
+    } catch (IllegalArgumentException e) {
+      assert e.getMessage().contains("a RustBuffer can be written with") : e.getMessage();
+    }
 
     // Optionals
     affirmAllerRetour(List.of("0", "1"), op::sinonNull);
@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.rondpoint.*;
+
+import java.beans.ConstructorProperties;
+import java.util.Arrays;
+import java.util.List;
+
+public class TestRondpointBeans {
+  public static void main(String[] args) throws Exception {
+    // `mapstruct` is set in uniffi-extras.toml
+    var dico = new Dictionnaire(Enumeration.DEUX, true, (byte) 0, 123456789L);
+    assert dico.getPetitNombre() == (byte) 0;
+    assert dico.getGrosNombre() == 123456789L;
+    assert Dictionnaire.Fields.PETIT_NOMBRE.equals("petitNombre");
+
+    // The builder methods are named like the properties
+    var modifie = dico.toBuilder().petitNombre((byte) 1).build();
+    assert modifie.equals(new Dictionnaire(Enumeration.DEUX, true, (byte) 1, 123456789L));
+
+    // The all-args constructor lists the Java names of the fields
+    var properties = Arrays.stream(Dictionnaire.class.getConstructors())
+        .map(constructor -> constructor.getAnnotation(ConstructorProperties.class))
+        .filter(annotation -> annotation != null)
+        .findFirst()
+        .orElseThrow();
+    assert List.of(properties.value()).equals(List.of("un", "deux", "petitNombre", "grosNombre"));
+  }
+}
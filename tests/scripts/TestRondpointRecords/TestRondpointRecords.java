@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.rondpoint.*;
+
+import java.beans.ConstructorProperties;
+import java.util.Arrays;
+import java.util.List;
+
+public class TestRondpointRecords {
+  public static void main(String[] args) throws Exception {
+    // `generate_immutable_records` is set in uniffi-extras.toml
+    var dico = new Dictionnaire(Enumeration.DEUX, true, (byte) 0, 123456789L);
+    assert Dictionnaire.class.isRecord();
+    assert Rondpoint.copieDictionnaire(dico).equals(dico);
+
+    // `constructor_properties` annotates the canonical constructor with the UniFFI names
+    var properties = Arrays.stream(Dictionnaire.class.getConstructors())
+        .map(constructor -> constructor.getAnnotation(ConstructorProperties.class))
+        .filter(annotation -> annotation != null)
+        .findFirst()
+        .orElseThrow();
+    assert List.of(properties.value()).equals(List.of("un", "deux", "petit_nombre", "gros_nombre"));
+  }
+}
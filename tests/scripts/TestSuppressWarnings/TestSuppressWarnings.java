@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
+import javax.tools.ToolProvider;
+
+public class TestSuppressWarnings {
+  public static void main(String[] args) throws Exception {
+    // The generated sources are next to the package directory on the classpath
+    var namespace = Path.of(ClassLoader.getSystemResource("uniffi/coverall/Coverall.java").toURI());
+    var root = namespace.getParent().getParent().getParent();
+    List<String> sources;
+    try (var files = Files.walk(root)) {
+      sources = files.map(Path::toString).filter(path -> path.endsWith(".java")).toList();
+    }
+
+    // With `suppress_warnings` set in uniffi-extras.toml, they compile with those warnings as errors
+    var options = new ArrayList<>(List.of(
+        "-Xlint:unchecked,deprecation,rawtypes",
+        "-Werror",
+        "-classpath", System.getProperty("java.class.path"),
+        "-d", Files.createTempDirectory("suppress-warnings").toString()));
+    options.addAll(sources);
+    var status = ToolProvider.getSystemJavaCompiler().run(null, null, null, options.toArray(String[]::new));
+    assert status == 0 : "the bindings don't compile with -Xlint and -Werror";
+  }
+}
@@ -0,0 +1,36 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import java.lang.reflect.Method;
+import java.util.ArrayList;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+public class TestValueTests {
+  public static void main(String[] args) throws Exception {
+    // Records, flat enums and enums with fields are covered, and pass
+    List<String> ran = runTests("uniffi.rondpoint.RondpointValueTest");
+    assert ran.contains("dictionnaireHasValueSemantics") : ran;
+    assert ran.contains("enumerationHasValueSemantics") : ran;
+    assert ran.contains("enumerationAvecDonneesHasValueSemantics") : ran;
+  }
+
+  // Run the `@Test` methods of `className` like JUnit would, returning their names. A failing
+  // assertion fails the script.
+  static List<String> runTests(String className) throws Exception {
+    Class<?> tests = Class.forName(className);
+    var constructor = tests.getDeclaredConstructor();
+    constructor.setAccessible(true);
+    Object instance = constructor.newInstance();
+    List<String> ran = new ArrayList<>();
+    for (Method method : tests.getDeclaredMethods()) {
+      if (method.isAnnotationPresent(Test.class)) {
+        method.setAccessible(true);
+        method.invoke(instance);
+        ran.add(method.getName());
+      }
+    }
+    return ran;
+  }
+}
@@ -12,8 +12,9 @@ use std::io::{Read, Write};
 use std::process::Command;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::{env, fs};
-use uniffi_bindgen::library_mode::generate_bindings;
-use uniffi_bindgen_java::JavaBindingGenerator;
+use uniffi_bindgen::BindingGenerator;
+use uniffi_bindgen::library_mode::{find_components, generate_bindings};
+use uniffi_bindgen_java::{JavaBindingGenerator, generate_type_report, required_ffi_symbols};
 use uniffi_testing::UniFFITestHelper;
 
 /// Run the test fixtures from UniFFI
@@ -88,6 +89,10 @@ fn run_test(fixture_name: &str, test_file: &str) -> Result<()> {
     let cdylib_dest = cdylib_java_resource_path.join(cdylib_path.file_name().unwrap());
     fs::copy(&cdylib_path, &cdylib_dest)?;
 
+    // Java sources in a `src` directory next to the test are compiled with the bindings, like
+    // stand-ins for annotations that aren't on the classpath.
+    copy_java_sources(&test_path.with_file_name("src"), &out_dir)?;
+
     // compile generated bindings and form jar
     let jar_file = build_jar(&fixture_name, &out_dir)?;
 
@@ -160,6 +165,17 @@ fn find_uniffi_toml(name: &str) -> Result<Option<Utf8PathBuf>> {
     Ok(maybe_uniffi_toml)
 }
 
+/// Copy the Java sources under `from` to the same paths under `to`, if there are any.
+fn copy_java_sources(from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    for source in glob::glob(from.join("**/*.java").as_str())?.flatten() {
+        let source = Utf8PathBuf::try_from(source)?;
+        let dest = to.join(source.strip_prefix(from)?);
+        fs::create_dir_all(dest.parent().unwrap())?;
+        fs::copy(&source, &dest)?;
+    }
+    Ok(())
+}
+
 /// Generate java bindings for the given namespace, then use the Java
 /// command-line tools to compile them into a .jar file.
 fn build_jar(fixture_name: &str, out_dir: &Utf8PathBuf) -> Result<Utf8PathBuf> {
@@ -240,6 +256,471 @@ fn write_file_contents(path: &Utf8PathBuf, contents: &str) -> Result<()> {
     Ok(())
 }
 
+/// Generate the bindings for `fixture_name` with only `config_toml` as config and return the
+/// contents of the generated `java_file`, relative to the output directory.
+fn generate_java_source(
+    fixture_name: &str,
+    config_name: &str,
+    config_toml: &str,
+    java_file: &str,
+) -> Result<String> {
+    let out_dir = generate_java_sources(fixture_name, config_name, config_toml)?;
+    read_file_contents(out_dir.join(java_file))
+        .with_context(|| format!("{java_file} wasn't generated"))
+}
+
+/// Generate the bindings of `fixture_name` with `config_toml`, returning the directory they're in.
+fn generate_java_sources(
+    fixture_name: &str,
+    config_name: &str,
+    config_toml: &str,
+) -> Result<Utf8PathBuf> {
+    let test_helper = UniFFITestHelper::new(fixture_name)?;
+    let out_dir = test_helper.create_out_dir(
+        env!("CARGO_TARGET_TMPDIR"),
+        Utf8Path::new(".").join("tests").join(config_name),
+    )?;
+    let cdylib_path = test_helper.cdylib_path()?;
+    let config_file = out_dir.with_file_name(format!("{}-{}.toml", fixture_name, config_name));
+    write_file_contents(&config_file, config_toml)?;
+
+    let config_supplier = {
+        use uniffi_bindgen::cargo_metadata::CrateConfigSupplier;
+        let metadata = MetadataCommand::new().exec()?;
+        CrateConfigSupplier::from(metadata)
+    };
+    generate_bindings(
+        &cdylib_path,
+        None,
+        &JavaBindingGenerator,
+        &config_supplier,
+        Some(config_file.as_path()),
+        &out_dir,
+        true,
+    )?;
+    Ok(out_dir)
+}
+
+#[test]
+fn test_required_ffi_symbols() -> Result<()> {
+    let cdylib_path = UniFFITestHelper::new("uniffi-example-arithmetic")?.cdylib_path()?;
+    let config_supplier = {
+        use uniffi_bindgen::cargo_metadata::CrateConfigSupplier;
+        let metadata = MetadataCommand::new().exec()?;
+        CrateConfigSupplier::from(metadata)
+    };
+    let components = find_components(&cdylib_path, &config_supplier)?;
+    let ci = &components
+        .iter()
+        .find(|component| component.ci.namespace() == "arithmetic")
+        .context("arithmetic component not found")?
+        .ci;
+
+    let symbols = required_ffi_symbols(ci);
+    assert!(symbols.contains(&ci.ffi_uniffi_contract_version().name().to_string()));
+    assert!(symbols.contains(&ci.ffi_rustbuffer_alloc().name().to_string()));
+    assert!(symbols.contains(&ci.ffi_rustbuffer_free().name().to_string()));
+    for func in ci.function_definitions() {
+        assert!(symbols.contains(&func.ffi_func().name().to_string()));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_type_report() -> Result<()> {
+    let cdylib_path = UniFFITestHelper::new("uniffi-example-arithmetic")?.cdylib_path()?;
+    let config_supplier = {
+        use uniffi_bindgen::cargo_metadata::CrateConfigSupplier;
+        let metadata = MetadataCommand::new().exec()?;
+        CrateConfigSupplier::from(metadata)
+    };
+    let components = find_components(&cdylib_path, &config_supplier)?;
+    let component = components
+        .iter()
+        .find(|component| component.ci.namespace() == "arithmetic")
+        .context("arithmetic component not found")?;
+    let config = JavaBindingGenerator.new_config(&toml::Value::Table(component.config.clone()))?;
+
+    let report = generate_type_report(&config, &component.ci);
+    assert!(report.starts_with("# Types of the arithmetic bindings in uniffi\n"));
+    assert!(report.contains("\nTypeArithmeticError\n"));
+    assert!(report.contains("  type_label: ArithmeticException\n"));
+    assert!(report.contains("  ffi_converter_name: FfiConverterTypeArithmeticError\n"));
+    assert!(report.contains("  ffi_converter_instance: FfiConverterLong.INSTANCE\n"));
+    Ok(())
+}
+
+/// An imported class sharing its simple name with a generated class is used fully-qualified.
+#[test]
+fn test_clashing_imports_are_fully_qualified() -> Result<()> {
+    let source = generate_java_source(
+        "uniffi-example-custom-types",
+        "import-clash",
+        r#"
+[bindings.java]
+package_name = "clash"
+
+[bindings.java.custom_types.Url]
+type_name = "Url"
+imports = ["java.net.URI", "com.example.Url"]
+lift = "new com.example.Url({})"
+lower = "{}.toString()"
+"#,
+        "clash/Url.java",
+    )?;
+
+    assert!(source.contains("import java.net.URI;"));
+    assert!(!source.contains("import com.example.Url;"));
+    assert!(source.contains("public record Url(\n  com.example.Url value\n)"));
+    Ok(())
+}
+
+/// A `converter_class` is referenced in the generated code, so it has to be a class name.
+#[test]
+fn test_converter_class_must_be_a_class_name() {
+    let invalid = generate_java_source(
+        "uniffi-example-custom-types",
+        "converter-class-invalid",
+        "[bindings.java]\npackage_name = \"converter_class_invalid\"\n\n[bindings.java.custom_types.Handle]\nconverter_class = \"com.example.new\"\n",
+        "converter_class_invalid/CustomTypesDemo.java",
+    );
+    assert!(format!("{:?}", invalid.unwrap_err()).contains("isn't a valid Java class name"));
+}
+
+/// `total` in the fixture has a default without a Java literal.
+#[test]
+fn test_on_unsupported() -> Result<()> {
+    let config = |mode: &str| {
+        format!(
+            "[bindings.java]\npackage_name = \"on_unsupported_{mode}\"\non_unsupported = \"{mode}\"\n"
+        )
+    };
+
+    let error = generate_java_source(
+        "uniffi-fixture-java",
+        "on-unsupported-error",
+        &config("error"),
+        "on_unsupported_error/JavaFixture.java",
+    );
+    assert!(
+        format!("{:?}", error.unwrap_err()).contains(
+            "on_unsupported: the default of `counts` in `total` can't be written in Java"
+        )
+    );
+
+    // Only the overload taking every argument is generated.
+    let skip = generate_java_source(
+        "uniffi-fixture-java",
+        "on-unsupported-skip",
+        &config("skip"),
+        "on_unsupported_skip/JavaFixture.java",
+    )?;
+    assert_eq!(skip.matches("public static Integer total(").count(), 1);
+    assert!(!skip.contains("UnsupportedOperationException"));
+
+    // The overload leaving the default out is generated too, and throws.
+    let stub = generate_java_source(
+        "uniffi-fixture-java",
+        "on-unsupported-stub",
+        &config("stub"),
+        "on_unsupported_stub/JavaFixture.java",
+    )?;
+    assert_eq!(stub.matches("public static Integer total(").count(), 2);
+    assert!(stub.contains(
+        "throw new UnsupportedOperationException(\"the default of `counts` can't be written in Java\");"
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_strict() -> Result<()> {
+    // `total` and `EntryCounter.largest` in the fixture have defaults without a Java literal.
+    let defaults = generate_java_source(
+        "uniffi-fixture-java",
+        "strict-defaults",
+        "[bindings.java]\npackage_name = \"strict_defaults\"\nstrict = true\n",
+        "strict_defaults/JavaFixture.java",
+    );
+    let message = format!("{:?}", defaults.unwrap_err());
+    assert!(message.contains("strict: 2 item(s) can't be generated"));
+    assert!(message.contains("the default of `counts` in `total` can't be written in Java"));
+    assert!(
+        message
+            .contains("the default of `counts` in `EntryCounter.largest` can't be written in Java")
+    );
+
+    // Stubs count as generated.
+    generate_java_source(
+        "uniffi-fixture-java",
+        "strict-stub",
+        "[bindings.java]\npackage_name = \"strict_stub\"\nstrict = true\non_unsupported = \"stub\"\n",
+        "strict_stub/JavaFixture.java",
+    )?;
+
+    // ext-types has functions throwing errors from uniffi-one.
+    let external = generate_java_source(
+        "uniffi-fixture-ext-types",
+        "strict-external",
+        "[bindings.java]\npackage_name = \"strict_external\"\nstrict = true\n",
+        "strict_external/ImportedTypesLib.java",
+    );
+    assert!(format!("{:?}", external.unwrap_err()).contains("throws an error from another crate"));
+
+    // Everything in rondpoint can be generated, so `strict` generates the same bindings.
+    let strict = generate_java_source(
+        "uniffi-example-rondpoint",
+        "strict-rondpoint",
+        "[bindings.java]\npackage_name = \"strict_rondpoint\"\nstrict = true\n",
+        "strict_rondpoint/Optionneur.java",
+    )?;
+    let lenient = generate_java_source(
+        "uniffi-example-rondpoint",
+        "lenient-rondpoint",
+        "[bindings.java]\npackage_name = \"strict_rondpoint\"\n",
+        "strict_rondpoint/Optionneur.java",
+    )?;
+    assert_eq!(strict, lenient);
+    Ok(())
+}
+
+#[test]
+fn test_java_version_report() -> Result<()> {
+    let report = generate_java_source(
+        "uniffi-example-arithmetic",
+        "java-version",
+        "[bindings.java]\npackage_name = \"java_version\"\nemit_java_version_report = true\n",
+        "META-INF/java_version.java-version.properties",
+    )?;
+    let versions = report
+        .lines()
+        .filter_map(|line| line.strip_prefix("feature."))
+        .map(|line| line.split_once('=').unwrap().1.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let minimum = versions
+        .into_iter()
+        .max()
+        .expect("the bindings use newer language features");
+    assert!(report.starts_with(&format!("minimum-java-version={minimum}\n")));
+
+    // The same bindings validate against the version they need, and fail against an older one.
+    generate_java_source(
+        "uniffi-example-arithmetic",
+        "java-version-enough",
+        &format!(
+            "[bindings.java]\npackage_name = \"java_version_enough\"\njava_version = {minimum}\n"
+        ),
+        "java_version_enough/Arithmetic.java",
+    )?;
+    let too_old = generate_java_source(
+        "uniffi-example-arithmetic",
+        "java-version-too-old",
+        "[bindings.java]\npackage_name = \"java_version_too_old\"\njava_version = 11\n",
+        "java_version_too_old/Arithmetic.java",
+    );
+    assert!(format!("{:?}", too_old.unwrap_err()).contains(&format!("need Java {minimum}")));
+    Ok(())
+}
+
+/// Leaving out a type that's still used fails, rather than generating code that doesn't compile.
+#[test]
+fn test_excluded_items_must_be_unused() {
+    let used = generate_java_source(
+        "uniffi-example-arithmetic",
+        "exclude-used-items",
+        "[bindings.java]\npackage_name = \"exclude_used_items\"\nexclude_items = [\"ArithmeticError\"]\n",
+        "exclude_used_items/Arithmetic.java",
+    );
+    assert!(
+        format!("{:?}", used.unwrap_err())
+            .contains("`ArithmeticError` is left out by include_items or exclude_items, but `")
+    );
+}
+
+/// Functions can't take the names of the methods generated on the namespace class.
+#[test]
+fn test_generated_names_must_not_clash() {
+    let self_test = generate_java_source(
+        "uniffi-example-arithmetic",
+        "clashing-self-test",
+        "[bindings.java]\npackage_name = \"clashing_self_test\"\nfunction_renames = { add = \"uniffiSelfTest\" }\n",
+        "clashing_self_test/Arithmetic.java",
+    );
+    assert!(
+        format!("{:?}", self_test.unwrap_err()).contains(
+            "the function `add` clashes with the generated `Arithmetic.uniffiSelfTest()`"
+        )
+    );
+
+    let entry_point = generate_java_source(
+        "uniffi-example-arithmetic",
+        "clashing-entry-point",
+        "[bindings.java]\npackage_name = \"clashing_entry_point\"\ninitialization_entry_point = \"sub\"\n",
+        "clashing_entry_point/Arithmetic.java",
+    );
+    assert!(
+        format!("{:?}", entry_point.unwrap_err())
+            .contains("the function `sub` clashes with the generated `Arithmetic.sub()`")
+    );
+}
+
+#[test]
+fn test_comparable_needs_ordered_fields() {
+    // Records have no natural order, so a record holding one can't be ordered by it.
+    let result = generate_java_source(
+        "uniffi-example-geometry",
+        "comparable",
+        "[bindings.java]\npackage_name = \"comparable\"\ncomparable = { Line = [\"start\"] }\n",
+        "comparable/Line.java",
+    );
+    assert!(
+        format!("{:?}", result.unwrap_err())
+            .contains("comparable: field `start` of `Line` has no natural order")
+    );
+}
+
+#[test]
+fn test_buffer_growth_factor_must_grow() {
+    let result = generate_java_source(
+        "uniffi-example-arithmetic",
+        "buffer-growth-factor",
+        "[bindings.java]\npackage_name = \"buffer_growth_factor\"\nbuffer_growth_factor = 1.0\n",
+        "buffer_growth_factor/Arithmetic.java",
+    );
+    assert!(
+        format!("{:?}", result.unwrap_err())
+            .contains("buffer_growth_factor: has to be a number greater than 1")
+    );
+}
+
+/// There's no Scala compiler on the test classpath, so by default this only checks what's
+/// generated. `test_scala_interop_compiles` compiles it, with `scalac` on the `PATH`.
+#[test]
+fn test_scala_interop() -> Result<()> {
+    let source = generate_java_source(
+        "uniffi-example-rondpoint",
+        "scala-interop",
+        "[bindings.java]\npackage_name = \"scala_interop\"\nscala_interop = true\n",
+        "scala/scala_interop/scaladsl/rondpoint.scala",
+    )?;
+    assert!(source.starts_with("package scala_interop.scaladsl\n"));
+    assert!(source.contains(
+        "final case class Dictionnaire(un: _root_.scala_interop.Enumeration, deux: Boolean, petitNombre: Byte, grosNombre: Long) {"
+    ));
+    assert!(source.contains(
+        "def fromJava(value: _root_.scala_interop.Dictionnaire): Dictionnaire = Dictionnaire(value.un(), (value.deux(): Boolean), (value.petitNombre(): Byte), (value.grosNombre(): Long))"
+    ));
+    assert!(source.contains("sealed trait EnumerationAvecDonnees {"));
+    assert!(source.contains("case object Zero extends EnumerationAvecDonnees {"));
+    assert!(source.contains(
+        "case variant: _root_.scala_interop.EnumerationAvecDonnees.Un => Un((variant.premier(): Int))"
+    ));
+    Ok(())
+}
+
+/// The Scala wrappers compile against the compiled bindings. This needs `scalac` on the `PATH`.
+#[cfg(feature = "test_scala")]
+#[test]
+fn test_scala_interop_compiles() -> Result<()> {
+    let fixture_name = "uniffi-example-rondpoint";
+    let out_dir = generate_java_sources(
+        fixture_name,
+        "scala-interop-compiles",
+        "[bindings.java]\npackage_name = \"scala_interop\"\nscala_interop = true\n",
+    )?;
+    let jar_file = build_jar(fixture_name, &out_dir)?;
+    let classes_dir = out_dir.join("scala-classes");
+    fs::create_dir_all(&classes_dir)?;
+
+    let status = Command::new("scalac")
+        .arg("-Werror")
+        .arg("-classpath")
+        .arg(calc_classpath(vec![&jar_file]))
+        .arg("-d")
+        .arg(&classes_dir)
+        .args(
+            glob::glob(&out_dir.join("scala/**/*.scala").into_string())?
+                .flatten()
+                .map(|p| String::from(p.to_string_lossy())),
+        )
+        .spawn()
+        .context("Failed to spawn `scalac` to compile the Scala wrappers")?
+        .wait()
+        .context("Failed to wait for `scalac` when compiling the Scala wrappers")?;
+    if !status.success() {
+        bail!("running `scalac` failed when compiling the Scala wrappers")
+    }
+    Ok(())
+}
+
+#[test]
+fn test_callback_exception_mapping_needs_error_variant() {
+    let result = generate_java_source(
+        "uniffi-fixture-futures",
+        "callback-exception-mapping",
+        "[bindings.java]\npackage_name = \"callback_exception_mapping\"\ncallback_exception_mapping = [{ exception = \"java.lang.NumberFormatException\", error = \"ParserError.NotANumber\" }]\n",
+        "callback_exception_mapping/Futures.java",
+    );
+    assert!(format!("{:?}", result.unwrap_err()).contains(
+        "callback_exception_mapping: `java.lang.NumberFormatException` maps to `ParserError.NotANumber`, which isn't a variant of an error"
+    ));
+}
+
+/// Classes in a named package can't refer to a runtime in the default package.
+#[test]
+fn test_default_runtime_package_needs_default_package() {
+    let result = generate_java_source(
+        "uniffi-example-arithmetic",
+        "default-runtime-package",
+        "[bindings.java]\nruntime_package = \"\"\n",
+        "uniffi/arithmetic/Arithmetic.java",
+    );
+
+    assert!(format!("{:?}", result.unwrap_err()).contains("runtime_package"));
+}
+
+/// Field docstrings document the components of Java records with `@param`s.
+#[test]
+fn test_field_docstrings() -> Result<()> {
+    let source = generate_java_source(
+        "uniffi-fixture-docstring",
+        "field-docstrings",
+        "[bindings.java]\npackage_name = \"field_docstrings\"\n",
+        "field_docstrings/RecordTest.java",
+    )?;
+
+    let javadoc = &source[..source.find("public record RecordTest(").unwrap()];
+    assert!(
+        javadoc.contains(" * <docstring-record>\n *\n * @param test <docstring-record-field>\n */")
+    );
+    Ok(())
+}
+
+/// `string_charset` has to be a name `Charset.forName` accepts.
+#[test]
+fn test_string_charset_must_be_a_charset_name() {
+    let result = generate_java_source(
+        "uniffi-example-arithmetic",
+        "string-charset-invalid",
+        "[bindings.java]\npackage_name = \"string_charset_invalid\"\nstring_charset = \"UTF 8\"\n",
+        "string_charset_invalid/Arithmetic.java",
+    );
+
+    assert!(format!("{:?}", result.unwrap_err()).contains("string_charset"));
+}
+
+/// No fixture has an `on_next`-shaped callback interface, so this checks other shapes are refused.
+#[test]
+fn test_flow_publishers_shape() -> Result<()> {
+    let refused = generate_java_source(
+        "uniffi-fixture-coverall",
+        "flow-publishers",
+        "[bindings.java]\npackage_name = \"flow_publishers\"\nflow_publishers = [\"Getters\"]\n",
+        "flow_publishers/GettersPublisher.java",
+    );
+    assert!(format!("{:?}", refused.unwrap_err()).contains("flow_publishers: Getters"));
+    Ok(())
+}
+
 macro_rules! fixture_tests {
     {
         $(($test_name:ident, $fixture_name:expr, $test_script:expr),)*
@@ -255,15 +736,35 @@ macro_rules! fixture_tests {
 
 fixture_tests! {
     (test_arithmetic, "uniffi-example-arithmetic", "scripts/TestArithmetic.java"),
-    (test_geometry, "uniffi-example-geometry", "scripts/TestGeometry.java"),
-    (test_rondpoint, "uniffi-example-rondpoint", "scripts/TestRondpoint.java"),
+    (test_arithmetic_options, "uniffi-example-arithmetic", "scripts/TestArithmeticOptions/TestArithmeticOptions.java"),
+    (test_default_package, "uniffi-example-arithmetic", "scripts/TestDefaultPackage/TestDefaultPackage.java"),
+    (test_isolation, "uniffi-example-arithmetic", "scripts/TestIsolation/TestIsolation.java"),
+    (test_jna_options, "uniffi-example-arithmetic", "scripts/TestJnaOptions/TestJnaOptions.java"),
+    (test_deprecated, "uniffi-example-arithmetic", "scripts/TestDeprecated/TestDeprecated.java"),
+    (test_osgi_manifest, "uniffi-example-arithmetic", "scripts/TestOsgiManifest/TestOsgiManifest.java"),
+    (test_api_since, "uniffi-example-arithmetic", "scripts/TestApiSince/TestApiSince.java"),
+    (test_geometry, "uniffi-example-geometry", "scripts/TestGeometry/TestGeometry.java"),
+    (test_rondpoint, "uniffi-example-rondpoint", "scripts/TestRondpoint/TestRondpoint.java"),
+    (test_rondpoint_beans, "uniffi-example-rondpoint", "scripts/TestRondpointBeans/TestRondpointBeans.java"),
+    (test_rondpoint_records, "uniffi-example-rondpoint", "scripts/TestRondpointRecords/TestRondpointRecords.java"),
+    (test_quarkus_producers, "uniffi-example-rondpoint", "scripts/TestQuarkusProducers/TestQuarkusProducers.java"),
+    (test_android_parcelable, "uniffi-example-rondpoint", "scripts/TestAndroidParcelable/TestAndroidParcelable.java"),
+    (test_value_tests, "uniffi-example-rondpoint", "scripts/TestValueTests/TestValueTests.java"),
+    (test_primitive_optionals, "uniffi-example-rondpoint", "scripts/TestPrimitiveOptionals/TestPrimitiveOptionals.java"),
     // (test_todolist, "uniffi-example-todolist", "scripts/test_todolist.java"),
     // (test_sprites, "uniffi-example-sprites", "scripts/test_sprites.java"),
-    (test_coverall, "uniffi-fixture-coverall", "scripts/TestFixtureCoverall.java"),
+    (test_coverall, "uniffi-fixture-coverall", "scripts/TestFixtureCoverall/TestFixtureCoverall.java"),
+    (test_coverall_options, "uniffi-fixture-coverall", "scripts/TestCoverallOptions/TestCoverallOptions.java"),
+    (test_android_parcelable_objects, "uniffi-fixture-coverall", "scripts/TestAndroidParcelable/TestAndroidParcelableObjects.java"),
+    (test_value_tests_bytes, "uniffi-fixture-coverall", "scripts/TestValueTests/TestValueTestsBytes.java"),
+    (test_suppress_warnings, "uniffi-fixture-coverall", "scripts/TestSuppressWarnings/TestSuppressWarnings.java"),
+    (test_bytes_type, "uniffi-fixture-coverall", "scripts/TestBytesType/TestBytesType.java"),
     (test_chronological, "uniffi-fixture-time", "scripts/TestChronological.java"),
     (test_custom_types, "uniffi-example-custom-types", "scripts/TestCustomTypes/TestCustomTypes.java"),
+    (test_custom_type_converter, "uniffi-example-custom-types", "scripts/TestCustomTypeConverter/TestCustomTypeConverter.java"),
     // (test_callbacks, "uniffi-fixture-callbacks", "scripts/test_callbacks.java"),
     (test_external_types, "uniffi-fixture-ext-types", "scripts/TestImportedTypes/TestImportedTypes.java"),
     (test_futures, "uniffi-example-futures", "scripts/TestFutures.java"),
     (test_futures_fixtures, "uniffi-fixture-futures", "scripts/TestFixtureFutures/TestFixtureFutures.java"),
+    (test_java_fixture, "uniffi-fixture-java", "scripts/TestJavaFixture/TestJavaFixture.java"),
 }